@@ -19,7 +19,7 @@ fn main() -> Result<()> {
         ),
     ).into();
 
-    let mut ctn_client = CatenisClient::new_with_options(
+    let ctn_client = CatenisClient::new_with_options(
         Some(device_credentials),
         &[
             ClientOptions::Environment(Environment::Sandbox),
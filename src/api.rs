@@ -535,7 +535,7 @@ impl ToString for MessageReadStateOption {
 }
 
 /// Options for filtering messages.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct ListMessagesOptions {
     /// Message action.
     pub action: Option<MessageActionOption>,
@@ -693,7 +693,8 @@ pub struct NonFungibleAssetIssuanceEventEntry {
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum AssetIssuanceEventEntry {
-    /// Entry for regular (fungible) assets.
+    /// Entry for regular (fungible) assets. The only kind of entry returned by versions of the
+    /// Catenis API prior to 0.12.
     Regular(RegularAssetIssuanceEventEntry),
     /// Entry for non-fungible assets.
     NonFungible(NonFungibleAssetIssuanceEventEntry),
@@ -1320,7 +1321,7 @@ pub struct AssetMigrationEntry {
 }
 
 /// Options for filtering asset exports.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct ListExportedAssetsOptions {
     /// The ID of the exported asset.
     pub asset_id: Option<String>,
@@ -1349,7 +1350,7 @@ pub struct ListExportedAssetsOptions {
 }
 
 /// Options for filtering asset migrations.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct ListAssetMigrationsOptions {
     /// The ID of the asset the amount of which has been migrated.
     pub asset_id: Option<String>,
@@ -1859,6 +1860,9 @@ pub struct RetrieveAssetInfoResult {
     /// Description about the asset.
     pub description: String,
     /// Indicates whether this is a non-fungible asset.
+    ///
+    /// > **Note**: not returned by versions of the Catenis API prior to 0.12, in which case it is
+    /// > set to **`false`**.
     pub is_non_fungible: bool,
     /// Indicates whether more units of this asset can be issued.
     pub can_reissue: bool,
@@ -2535,6 +2539,75 @@ pub(crate) struct RetrieveNFTokenTransferProgressResponse {
     pub data: RetrieveNFTokenTransferProgressResult,
 }
 
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GenericResponse<T> {
+    pub status: String,
+    pub data: T,
+}
+
+// Response data structures of versions of the Catenis API prior to 0.12 (which introduced
+//  non-fungible assets)
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RetrieveAssetInfoResultApi0_11 {
+    pub asset_id: String,
+    pub name: String,
+    pub description: String,
+    pub can_reissue: bool,
+    pub decimal_places: u8,
+    pub issuer: DeviceInfo,
+    pub total_existent_balance: f64,
+}
+
+impl From<RetrieveAssetInfoResultApi0_11> for RetrieveAssetInfoResult {
+    fn from(result: RetrieveAssetInfoResultApi0_11) -> Self {
+        RetrieveAssetInfoResult {
+            asset_id: result.asset_id,
+            name: result.name,
+            description: result.description,
+            is_non_fungible: false,
+            can_reissue: result.can_reissue,
+            decimal_places: result.decimal_places,
+            issuer: result.issuer,
+            total_existent_balance: result.total_existent_balance,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RetrieveAssetInfoResponseApi0_11 {
+    pub status: String,
+    pub data: RetrieveAssetInfoResultApi0_11,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RetrieveAssetIssuanceHistoryResultApi0_11 {
+    pub issuance_events: Vec<RegularAssetIssuanceEventEntry>,
+    pub has_more: bool,
+}
+
+impl From<RetrieveAssetIssuanceHistoryResultApi0_11> for RetrieveAssetIssuanceHistoryResult {
+    fn from(result: RetrieveAssetIssuanceHistoryResultApi0_11) -> Self {
+        RetrieveAssetIssuanceHistoryResult {
+            issuance_events: result.issuance_events.into_iter()
+                .map(AssetIssuanceEventEntry::Regular)
+                .collect(),
+            has_more: result.has_more,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RetrieveAssetIssuanceHistoryResponseApi0_11 {
+    pub status: String,
+    pub data: RetrieveAssetIssuanceHistoryResultApi0_11,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4359,6 +4432,37 @@ mod tests {
         });
     }
 
+    #[test]
+    fn it_deserialize_retrieve_asset_info_result_api_0_11() {
+        let json = r#"{"assetId":"aQjlzShmrnEZeeYBZihc","name":"TestAsset_1","description":"First asset issued for test","canReissue":false,"decimalPlaces":2,"issuer":{"deviceId":"drc3XdxNtzoucpw9xiRp"},"totalExistentBalance":123.25}"#;
+
+        let retrieve_asset_info_result: RetrieveAssetInfoResultApi0_11 = serde_json::from_str(json).unwrap();
+
+        assert_eq!(RetrieveAssetInfoResult::from(retrieve_asset_info_result), RetrieveAssetInfoResult {
+            asset_id: String::from("aQjlzShmrnEZeeYBZihc"),
+            name: String::from("TestAsset_1"),
+            description: String::from("First asset issued for test"),
+            is_non_fungible: false,
+            can_reissue: false,
+            decimal_places: 2,
+            issuer: DeviceInfo {
+                device_id: String::from("drc3XdxNtzoucpw9xiRp"),
+                name: None,
+                prod_unique_id: None,
+            },
+            total_existent_balance: 123.25,
+        });
+    }
+
+    #[test]
+    fn it_deserialize_retrieve_asset_info_result_missing_non_fungible() {
+        let json = r#"{"assetId":"aQjlzShmrnEZeeYBZihc","name":"TestAsset_1","description":"First asset issued for test","canReissue":false,"decimalPlaces":2,"issuer":{"deviceId":"drc3XdxNtzoucpw9xiRp"},"totalExistentBalance":123.25}"#;
+
+        let result: serde_json::Result<RetrieveAssetInfoResult> = serde_json::from_str(json);
+
+        assert!(result.is_err(), "Accepted API 0.12 result missing the isNonFungible field");
+    }
+
     #[test]
     fn it_deserialize_get_asset_balance_result() {
         let json = r#"{"total":123.25,"unconfirmed":0}"#;
@@ -4455,6 +4559,37 @@ mod tests {
         });
     }
 
+    #[test]
+    fn it_deserialize_retrieve_asset_issuance_history_result_api_0_11() {
+        let json = r#"{"issuanceEvents":[{"amount":123,"holdingDevice":{"deviceId":"drc3XdxNtzoucpw9xiRp","name":"TstDev1"},"date":"2020-12-23T10:51:45.935Z"}],"hasMore":true}"#;
+
+        let retrieve_asset_issuance_history_result: RetrieveAssetIssuanceHistoryResultApi0_11 = serde_json::from_str(json).unwrap();
+
+        assert_eq!(RetrieveAssetIssuanceHistoryResult::from(retrieve_asset_issuance_history_result), RetrieveAssetIssuanceHistoryResult {
+            issuance_events: vec![
+                AssetIssuanceEventEntry::Regular(RegularAssetIssuanceEventEntry {
+                    amount: 123.0,
+                    holding_device: DeviceInfo {
+                        device_id: String::from("drc3XdxNtzoucpw9xiRp"),
+                        name: Some(String::from("TstDev1")),
+                        prod_unique_id: None,
+                    },
+                    date: "2020-12-23T10:51:45.935Z".into(),
+                }),
+            ],
+            has_more: true,
+        });
+    }
+
+    #[test]
+    fn it_reject_non_fungible_asset_issuance_event_api_0_11() {
+        let json = r#"{"issuanceEvents":[{"nfTokenIds":["tQyJrga3ke65RR23iyr2"],"holdingDevices":[{"deviceId":"d8YpQ7jgPBJEkBrnvp58"}],"date":"2020-12-24T13:27:02.010Z"}],"hasMore":false}"#;
+
+        let result: serde_json::Result<RetrieveAssetIssuanceHistoryResultApi0_11> = serde_json::from_str(json);
+
+        assert!(result.is_err(), "Accepted non-fungible asset issuance event from API 0.11");
+    }
+
     #[test]
     fn it_deserialize_list_asset_holders_result() {
         let json = r#"{"assetHolders":[{"holder":{"deviceId":"drc3XdxNtzoucpw9xiRp"},"balance":{"total":123.25,"unconfirmed":0}},{"holder":{"deviceId":"d8YpQ7jgPBJEkBrnvp58"},"balance":{"total":150,"unconfirmed":0}},{"migrated":true,"balance":{"total":34.75,"unconfirmed":0}}],"hasMore":false}"#;
@@ -0,0 +1,53 @@
+use crate::{
+    Result,
+};
+use super::CatenisClient;
+
+/// Builder of an asynchronous Catenis API client object.
+///
+/// Refer to [`crate::CatenisClientBuilder`] for details.
+pub type CatenisClientBuilder<'a> = crate::CatenisClientBuilder<'a, CatenisClient>;
+
+impl<'a> CatenisClientBuilder<'a> {
+    /// Instantiate the asynchronous Catenis API client object.
+    ///
+    /// The resolved base URL of the Catenis API can be retrieved via the client's
+    /// [`base_api_url()`](CatenisClient::base_api_url) method.
+    pub fn build(self) -> Result<CatenisClient> {
+        let (device_credentials, opts) = self.into_options()?;
+
+        CatenisClient::new_with_options(device_credentials, opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ApiVersion, Environment,
+    };
+    use super::*;
+
+    #[test]
+    fn it_resolve_environment_regardless_of_order() {
+        let ctn_client = CatenisClient::builder()
+            .environment(Environment::Sandbox)
+            .host("localhost:3000")
+            .secure(false)
+            .version(ApiVersion(0, 10))
+            .build()
+            .unwrap();
+
+        assert_eq!(ctn_client.base_api_url(), "http://sandbox.localhost:3000/api/0.10/");
+    }
+
+    #[test]
+    fn it_fail_build_conflicting_options() {
+        let result = CatenisClient::builder()
+            .use_compression(false)
+            .compress_threshold(2048)
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: compression threshold set while compression is disabled");
+    }
+}
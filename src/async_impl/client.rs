@@ -1,10 +1,7 @@
 use std::{
     borrow::Borrow,
-};
-use bitcoin_hashes::{
-    Hash, HashEngine, hex::ToHex, Hmac,
-    HmacEngine,
-    sha256,
+    collections::HashMap,
+    sync::Arc,
 };
 use reqwest::{
     Client as HttpClient,
@@ -12,67 +9,66 @@ use reqwest::{
     Request, Response,
     header::{
         ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, CONTENT_ENCODING,
-        HeaderMap, HeaderName, HeaderValue, HOST,
+        HeaderMap, HeaderValue, HOST,
     },
-    Url,
+    Method, StatusCode, Url,
 };
 use async_compression::{
     Level,
-    tokio::bufread::ZlibEncoder,
+    tokio::bufread::{
+        GzipEncoder, ZlibEncoder,
+    },
 };
 use tokio::io::AsyncReadExt;
-use time::{
-    Date,
-};
 use serde::de::DeserializeOwned;
 
 use crate::*;
 use crate::api::*;
 use base_client::BaseCatenisClient;
-use super::notification::WsNotifyChannel;
+use retry::SendAttempt;
+use rate_limit::RateLimiter;
+use circuit_breaker::CircuitBreaker;
+use failover::Endpoints;
+use clock_skew::ClockSkew;
+use signing_state::SigningState;
+use connection::ConnectionSettings;
+use proxy::ProxySettings;
+use pinning::{
+    self,
+    PinSet,
+};
+use interceptor::InterceptorChain;
+use metrics::MetricsRecorder;
+use trace::{
+    CallTrace, TracedResponse,
+};
+use versioning::ApiMethod;
+use super::{
+    CatenisClientBuilder,
+    notification::WsNotifyChannel,
+};
 
 /// Represents an asynchronous Catenis API client.
 #[derive(Debug, Clone)]
 pub struct CatenisClient {
-    device_credentials: Option<DeviceCredentials>,
+    signing: SigningState,
     base_api_url: Url,
-    is_secure: bool,
+    pub(crate) endpoints: Endpoints,
+    api_version: ApiVersion,
     use_compression: bool,
+    compression: CompressionOptions,
     compress_threshold: usize,
-    sign_date: Option<Date>,
-    signing_key: Option<[u8; 32]>,
+    retry_policy: RetryPolicy,
+    rate_limiter: RateLimiter,
+    circuit_breaker: CircuitBreaker,
+    clock_skew: ClockSkew,
+    pub(crate) connection_settings: ConnectionSettings,
+    interceptors: InterceptorChain,
+    pub(crate) metrics: MetricsRecorder,
     http_client: HttpClient,
 }
 
-impl BaseCatenisClient for CatenisClient {
-    fn get_device_id_ref(&self) -> Result<&String> {
-        if let Some(credentials) = &self.device_credentials {
-            Ok(&credentials.device_id)
-        } else {
-            Err(Error::new_client_error(Some("Missing virtual device credentials"), None::<GenericError>))
-        }
-    }
-
-    fn get_api_access_secret_ref(&self) -> Result<&String> {
-        if let Some(credentials) = &self.device_credentials {
-            Ok(&credentials.api_access_secret)
-        } else {
-            Err(Error::new_client_error(Some("Missing virtual device credentials"), None::<GenericError>))
-        }
-    }
-
-    fn get_sign_date_ref(&self) -> &Option<Date> {
-        &self.sign_date
-    }
-
-    fn get_sign_date_mut_ref(&mut self) -> &mut Option<Date> {
-        &mut self.sign_date
-    }
-
-    fn get_signing_key_mut_ref(&mut self) -> &mut Option<[u8; 32]> {
-        &mut self.signing_key
-    }
-}
+impl BaseCatenisClient for CatenisClient {}
 
 impl CatenisClient {
     // Definition of public methods
@@ -85,19 +81,33 @@ impl CatenisClient {
     {
         let base_url = Url::parse(DEFAULT_BASE_URL)?;
         let api_version = DEFAULT_API_VERSION;
-        let is_secure = true;
         let use_compression = true;
+        let compression = CompressionOptions::default();
         let compress_threshold: usize = 1024;
+        let retry_policy = RetryPolicy::no_retry();
+        let rate_limiter = RateLimiter::default();
+        let circuit_breaker = CircuitBreaker::default();
+        let connection_settings = ConnectionSettings::default();
+
+        let base_api_url = base_url.join(&Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]))?;
+        let endpoints = Endpoints::new(vec![base_api_url.clone()], None);
 
         Ok(CatenisClient {
-            device_credentials,
-            base_api_url: base_url.join(&Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]))?,
-            is_secure,
+            signing: SigningState::new(device_credentials),
+            base_api_url,
+            endpoints,
+            api_version,
             use_compression,
+            compression,
             compress_threshold,
-            sign_date: None,
-            signing_key: None,
-            http_client: Self::new_http_client(use_compression)?,
+            retry_policy,
+            rate_limiter,
+            circuit_breaker,
+            clock_skew: ClockSkew::default(),
+            http_client: Self::new_http_client(use_compression, &compression, &connection_settings)?,
+            connection_settings,
+            interceptors: InterceptorChain::default(),
+            metrics: MetricsRecorder::default(),
         })
     }
 
@@ -112,12 +122,20 @@ impl CatenisClient {
     {
         let mut base_url = Url::parse(DEFAULT_BASE_URL)?;
         let mut api_version = DEFAULT_API_VERSION;
-        let mut is_secure = true;
         let mut use_compression = true;
+        let mut compression = CompressionOptions::default();
         let mut compress_threshold: usize = 1024;
+        let mut retry_policy = RetryPolicy::no_retry();
+        let mut rate_limiter = RateLimiter::default();
+        let mut circuit_breaker = CircuitBreaker::default();
+        let mut connection_settings = ConnectionSettings::default();
+        let mut failover = None;
 
         for opt in opts.into_iter() {
             match opt.borrow() {
+                ClientOptions::BaseUrl(url) => {
+                    base_url = Self::parse_base_url(url)?;
+                }
                 ClientOptions::Host(host) => {
                     match Self::parse_host_with_port(host) {
                         (Some(host), port) => {
@@ -147,13 +165,9 @@ impl CatenisClient {
                     }
                 }
                 ClientOptions::Secure(secure) => {
-                    is_secure = *secure;
-
-                    if !is_secure {
-                        // Replace scheme
-                        if let Err(_) = base_url.set_scheme("http") {
-                            return Err(Error::new_client_error(Some("Error resetting URL scheme"), None::<GenericError>));
-                        }
+                    // Replace scheme
+                    if base_url.set_scheme(if *secure {"https"} else {"http"}).is_err() {
+                        return Err(Error::new_client_error(Some("Error resetting URL scheme"), None::<GenericError>));
                     }
                 }
                 ClientOptions::Version(version) => {
@@ -162,24 +176,207 @@ impl CatenisClient {
                 ClientOptions::UseCompression(compress) => {
                     use_compression = *compress;
                 }
+                ClientOptions::Compression(compression_opts) => {
+                    compression = *compression_opts;
+                }
                 ClientOptions::CompressThreshold(threshold) => {
                     compress_threshold = *threshold;
                 }
+                ClientOptions::Retry(policy) => {
+                    retry_policy = *policy;
+                }
+                ClientOptions::RateLimit(limit) => {
+                    rate_limiter = RateLimiter::new(limit)?;
+                }
+                ClientOptions::CircuitBreaker(policy) => {
+                    circuit_breaker = CircuitBreaker::new(policy)?;
+                }
+                ClientOptions::Timeout(timeout) => {
+                    connection_settings.request_timeout = Some(*timeout);
+                }
+                ClientOptions::ConnectTimeout(timeout) => {
+                    connection_settings.connect_timeout = Some(*timeout);
+                }
+                ClientOptions::WsHandshakeTimeout(timeout) => {
+                    connection_settings.ws_handshake_timeout = Some(*timeout);
+                }
+                ClientOptions::Proxy(proxy_opts) => {
+                    connection_settings.proxy = Some(ProxySettings::from_options(proxy_opts)?);
+                }
+                ClientOptions::TlsRootCertificates(pem) => {
+                    connection_settings.tls.add_root_certificates(pem)?;
+                }
+                ClientOptions::TlsBuiltInRoots(use_built_in_roots) => {
+                    connection_settings.tls.use_built_in_roots = *use_built_in_roots;
+                }
+                ClientOptions::TlsClientIdentity(identity) => {
+                    connection_settings.tls.set_client_identity(identity)?;
+                }
+                ClientOptions::PinnedPublicKeys(pins) => {
+                    connection_settings.pinned_keys = Some(PinSet::from_pins(pins)?);
+                }
+                ClientOptions::Failover(failover_opts) => {
+                    failover = Some(*failover_opts);
+                }
+            }
+        }
+
+        let api_path = Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]);
+        let base_api_url = base_url.join(&api_path)?;
+        let mut base_api_urls = vec![base_api_url.clone()];
+
+        if let Some(failover_opts) = &failover {
+            for url in failover_opts.base_urls {
+                base_api_urls.push(Self::parse_base_url(url)?.join(&api_path)?);
             }
         }
 
+        let endpoints = Endpoints::new(base_api_urls, failover.as_ref());
+
         Ok(CatenisClient {
-            device_credentials,
-            base_api_url: base_url.join(&Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]))?,
-            is_secure,
+            signing: SigningState::new(device_credentials),
+            base_api_url,
+            endpoints,
+            api_version,
             use_compression,
+            compression,
             compress_threshold,
-            sign_date: None,
-            signing_key: None,
-            http_client: Self::new_http_client(use_compression)?,
+            retry_policy,
+            rate_limiter,
+            circuit_breaker,
+            clock_skew: ClockSkew::default(),
+            http_client: Self::new_http_client(use_compression, &compression, &connection_settings)?,
+            connection_settings,
+            interceptors: InterceptorChain::default(),
+            metrics: MetricsRecorder::default(),
         })
     }
 
+    /// Instantiate a new builder of an asynchronous Catenis API client object, whose option settings are
+    /// resolved in a fixed way, regardless of the order in which they are set, and checked for
+    /// conflicts.
+    pub fn builder<'a>() -> CatenisClientBuilder<'a> {
+        CatenisClientBuilder::new()
+    }
+
+    /// Instantiate a new Catenis API client object from a configuration (e.g. loaded from a
+    /// file).
+    pub fn from_config(config: &ClientConfig) -> Result<Self> {
+        config.build::<Self, _>(|builder| builder.build())
+    }
+
+    /// Instantiate a new Catenis API client object configured from environment variables.
+    ///
+    /// Refer to [`ClientConfig::from_env()`] for the names of the environment variables that are
+    /// read.
+    pub fn from_env() -> Result<Self> {
+        Self::from_config(&ClientConfig::from_env()?)
+    }
+
+    /// Retrieve the base URL of the Catenis API, as resolved from the client's option settings.
+    pub fn base_api_url(&self) -> &str {
+        self.base_api_url.as_str()
+    }
+
+    /// Retrieve the version of the Catenis API targeted by the client.
+    ///
+    /// Use its [`supports_method()`](ApiVersion::supports_method) method to check whether a given
+    /// method is available in that version.
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Get a clone of this client that compresses (or not) request bodies regardless of its
+    /// `UseCompression` option setting, to be used for individual calls (e.g. to skip compressing
+    /// a message whose contents are already compressed).
+    ///
+    /// Everything else, including the underlying HTTP client and its connection pool, is shared
+    /// with this client.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     async_impl,
+    /// #     Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> Result<()> {
+    /// # let ctn_client = async_impl::CatenisClient::new(None)?;
+    /// #
+    /// let result = ctn_client.with_request_compression(false).log_message(
+    ///     Message::Whole(String::from("My message")),
+    ///     None,
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_request_compression(&self, compress: bool) -> Self {
+        CatenisClient {
+            use_compression: compress,
+            ..self.clone()
+        }
+    }
+
+    /// Register an interceptor whose hooks should be called around every HTTP request sent to the
+    /// Catenis API server.
+    ///
+    /// Interceptors are called in the order that they have been registered. Refer to
+    /// [`Interceptor`] for an example.
+    pub fn add_interceptor<I: Interceptor + 'static>(&mut self, interceptor: I) {
+        self.interceptors.add(Arc::new(interceptor));
+    }
+
+    /// Set the sink to which metrics about the API method calls, and the activity of the WebSocket
+    /// notification channels created from this client, should be reported.
+    ///
+    /// To share the same sink among several clients, pass an `Arc` of it. Refer to
+    /// [`MetricsSink`] for an example.
+    pub fn set_metrics_sink<S: MetricsSink + 'static>(&mut self, sink: S) {
+        self.metrics.set(Arc::new(sink));
+    }
+
+    /// Set the provider from which the virtual device credentials should be retrieved, replacing
+    /// any credentials passed when instantiating the client.
+    ///
+    /// The credentials are loaded right away, and then again whenever a new signing key needs to
+    /// be generated. The provider is shared by all clones of the client, and the notification
+    /// channels created from them. Refer to [`CredentialProvider`] for an example.
+    pub fn set_credential_provider<P: CredentialProvider + 'static>(&self, provider: P) -> Result<()> {
+        self.signing.credentials().set_provider(Arc::new(provider))
+    }
+
+    /// Reload the virtual device credentials from the client's credential provider.
+    ///
+    /// If the credentials have changed, the new credentials are used for signing all subsequent
+    /// requests from this client, its clones, and the notification channels created from them,
+    /// without the need to instantiate them again.
+    pub fn rotate_credentials(&self) -> Result<()> {
+        self.signing.credentials().refresh()
+    }
+
+    /// Retrieve the current state of the client's circuit breaker, or `None` if the client has
+    /// not been set up to use a circuit breaker (via [`ClientOptions::CircuitBreaker`]).
+    ///
+    /// The circuit breaker is shared by all clones of the client, so this can be used, for
+    /// instance, to report the availability of the Catenis API server from a health endpoint.
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker.state()
+    }
+
+    /// Retrieve the currently measured skew, in seconds, between the Catenis API server's clock and
+    /// the local clock. A positive value means that the local clock is behind.
+    ///
+    /// The skew is learned from the `Date` HTTP header of the responses received from the server,
+    /// and it is compensated for when signing requests. If a request is rejected due to a failed
+    /// authentication after the skew has been found to change, it is signed anew and sent again
+    /// once.
+    pub fn clock_skew_secs(&self) -> i64 {
+        self.clock_skew.secs()
+    }
+
     /// Instantiate a new asynchronous WebSocket notification channel object for a given Catenis
     /// notification event.
     ///
@@ -231,7 +428,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -271,7 +468,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -321,7 +518,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn log_message(&mut self, message: Message, options: Option<LogMessageOptions>) -> Result<LogMessageResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn log_message(&self, message: Message, options: Option<LogMessageOptions>) -> Result<LogMessageResult> {
         let body = LogMessageRequest {
             message,
             options
@@ -334,9 +532,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::LogMessage, req).await?;
 
-        Ok(Self::parse_response::<LogMessageResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<LogMessageResponse>(res).await?.data)
     }
 
     /// Call *Send Message* API method.
@@ -354,7 +552,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -399,7 +597,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -454,7 +652,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn send_message(&mut self, message: Message, target_device: DeviceId, options: Option<SendMessageOptions>) -> Result<SendMessageResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn send_message(&self, message: Message, target_device: DeviceId, options: Option<SendMessageOptions>) -> Result<SendMessageResult> {
         let body = SendMessageRequest {
             message,
             target_device,
@@ -468,9 +667,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::SendMessage, req).await?;
 
-        Ok(Self::parse_response::<SendMessageResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<SendMessageResponse>(res).await?.data)
     }
 
     /// Call *Read Message* API method.
@@ -486,7 +685,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -513,7 +712,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn read_message(&mut self, message_id: &str, options: Option<ReadMessageOptions>) -> Result<ReadMessageResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn read_message(&self, message_id: &str, options: Option<ReadMessageOptions>) -> Result<ReadMessageResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let encoding;
@@ -560,9 +760,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ReadMessage, req).await?;
 
-        Ok(Self::parse_response::<ReadMessageResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ReadMessageResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Message Container* API method.
@@ -578,7 +778,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -609,7 +809,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_message_container(&mut self, message_id: &str) -> Result<RetrieveMessageContainerResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_message_container(&self, message_id: &str) -> Result<RetrieveMessageContainerResult> {
         let req = self.get_request(
             "messages/:message_id/container",
             Some(&[
@@ -618,9 +819,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveMessageContainer, req).await?;
 
-        Ok(Self::parse_response::<RetrieveMessageContainerResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrieveMessageContainerResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Message Origin* API method.
@@ -638,7 +839,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     None,
     /// #     &[
     /// #         ClientOptions::Environment(Environment::Sandbox),
@@ -664,6 +865,7 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn retrieve_message_origin(&self, message_id: &str, msg_to_sign: Option<&str>) -> Result<RetrieveMessageOriginResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
@@ -685,9 +887,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.send_request(req).await?;
+        let res = self.send_request(ApiMethod::RetrieveMessageOrigin, req).await?;
 
-        Ok(Self::parse_response::<RetrieveMessageOriginResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrieveMessageOriginResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Message Progress* API method.
@@ -706,7 +908,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -741,7 +943,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_message_progress(&mut self, message_id: &str) -> Result<RetrieveMessageProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_message_progress(&self, message_id: &str) -> Result<RetrieveMessageProgressResult> {
         let req = self.get_request(
             "messages/:message_id/progress",
             Some(&[
@@ -750,9 +953,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveMessageProgress, req).await?;
 
-        Ok(Self::parse_response::<RetrieveMessageProgressResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrieveMessageProgressResponse>(res).await?.data)
     }
 
     /// Call *List Messages* API method.
@@ -768,7 +971,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -805,7 +1008,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_messages(&mut self, options: Option<ListMessagesOptions>) -> Result<ListMessagesResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_messages(&self, options: Option<ListMessagesOptions>) -> Result<ListMessagesResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let action;
@@ -929,9 +1133,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListMessages, req).await?;
 
-        Ok(Self::parse_response::<ListMessagesResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListMessagesResponse>(res).await?.data)
     }
 
     /// Call *Issue Asset* API method.
@@ -947,7 +1151,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -975,7 +1179,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn issue_asset(&mut self, asset_info: NewAssetInfo, amount: f64, holding_device: Option<DeviceId>) -> Result<IssueAssetResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn issue_asset(&self, asset_info: NewAssetInfo, amount: f64, holding_device: Option<DeviceId>) -> Result<IssueAssetResult> {
         let body = IssueAssetRequest {
             asset_info,
             amount,
@@ -989,9 +1194,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::IssueAsset, req).await?;
 
-        Ok(Self::parse_response::<IssueAssetResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<IssueAssetResponse>(res).await?.data)
     }
 
     /// Call *Reissue Asset* API method.
@@ -1007,7 +1212,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1033,7 +1238,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn reissue_asset(&mut self, asset_id: &str, amount: f64, holding_device: Option<DeviceId>) -> Result<ReissueAssetResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn reissue_asset(&self, asset_id: &str, amount: f64, holding_device: Option<DeviceId>) -> Result<ReissueAssetResult> {
         let body = ReissueAssetRequest {
             amount,
             holding_device
@@ -1048,9 +1254,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ReissueAsset, req).await?;
 
-        Ok(Self::parse_response::<ReissueAssetResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ReissueAssetResponse>(res).await?.data)
     }
 
     /// Call *Transfer Asset* API method.
@@ -1066,7 +1272,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1092,7 +1298,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn transfer_asset(&mut self, asset_id: &str, amount: f64, receiving_device: DeviceId) -> Result<TransferAssetResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn transfer_asset(&self, asset_id: &str, amount: f64, receiving_device: DeviceId) -> Result<TransferAssetResult> {
         let body = TransferAssetRequest {
             amount,
             receiving_device
@@ -1107,9 +1314,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::TransferAsset, req).await?;
 
-        Ok(Self::parse_response::<TransferAssetResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<TransferAssetResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Asset Info* API method.
@@ -1125,7 +1332,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1146,7 +1353,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_asset_info(&mut self, asset_id: &str) -> Result<RetrieveAssetInfoResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_asset_info(&self, asset_id: &str) -> Result<RetrieveAssetInfoResult> {
         let req = self.get_request(
             "assets/:asset_id",
             Some(&[
@@ -1155,9 +1363,14 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveAssetInfo, req).await?;
 
-        Ok(Self::parse_response::<RetrieveAssetInfoResponse>(res).await?.data)
+        // Note: responses of older versions of the Catenis API have a different shape
+        if versioning::has_non_fungible_assets(self.api_version) {
+            Ok(Self::parse_api_response::<RetrieveAssetInfoResponse>(res).await?.data)
+        } else {
+            Ok(Self::parse_api_response::<RetrieveAssetInfoResponseApi0_11>(res).await?.data.into())
+        }
     }
 
     /// Call *Get Asset Balance* API method.
@@ -1173,7 +1386,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1195,7 +1408,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_asset_balance(&mut self, asset_id: &str) -> Result<GetAssetBalanceResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn get_asset_balance(&self, asset_id: &str) -> Result<GetAssetBalanceResult> {
         let req = self.get_request(
             "assets/:asset_id/balance",
             Some(&[
@@ -1204,9 +1418,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::GetAssetBalance, req).await?;
 
-        Ok(Self::parse_response::<GetAssetBalanceResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<GetAssetBalanceResponse>(res).await?.data)
     }
 
     /// Call *List Owned Assets* API method.
@@ -1222,7 +1436,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1252,7 +1466,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_owned_assets(&mut self, limit: Option<u16>, skip: Option<usize>) -> Result<ListOwnedAssetsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_owned_assets(&self, limit: Option<u16>, skip: Option<usize>) -> Result<ListOwnedAssetsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let limit_str;
@@ -1281,9 +1496,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListOwnedAssets, req).await?;
 
-        Ok(Self::parse_response::<ListOwnedAssetsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListOwnedAssetsResponse>(res).await?.data)
     }
 
     /// Call *List Issued Assets* API method.
@@ -1299,7 +1514,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1328,7 +1543,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_issued_assets(&mut self, limit: Option<u16>, skip: Option<usize>) -> Result<ListIssuedAssetsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_issued_assets(&self, limit: Option<u16>, skip: Option<usize>) -> Result<ListIssuedAssetsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let limit_str;
@@ -1357,9 +1573,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListIssuedAssets, req).await?;
 
-        Ok(Self::parse_response::<ListIssuedAssetsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListIssuedAssetsResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Asset Issuance History* API method.
@@ -1375,7 +1591,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1423,8 +1639,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn retrieve_asset_issuance_history(
-        &mut self,
+        &self,
         asset_id: &str,
         start_date: Option<UtcDateTime>,
         end_date: Option<UtcDateTime>,
@@ -1475,9 +1692,14 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveAssetIssuanceHistory, req).await?;
 
-        Ok(Self::parse_response::<RetrieveAssetIssuanceHistoryResponse>(res).await?.data)
+        // Note: responses of older versions of the Catenis API have a different shape
+        if versioning::has_non_fungible_assets(self.api_version) {
+            Ok(Self::parse_api_response::<RetrieveAssetIssuanceHistoryResponse>(res).await?.data)
+        } else {
+            Ok(Self::parse_api_response::<RetrieveAssetIssuanceHistoryResponseApi0_11>(res).await?.data.into())
+        }
     }
 
     /// Call *List Asset Holders* API method.
@@ -1493,7 +1715,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1534,7 +1756,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_asset_holders(&mut self, asset_id: &str, limit: Option<u16>, skip: Option<usize>) -> Result<ListAssetHoldersResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_asset_holders(&self, asset_id: &str, limit: Option<u16>, skip: Option<usize>) -> Result<ListAssetHoldersResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let limit_str;
@@ -1565,9 +1788,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListAssetHolders, req).await?;
 
-        Ok(Self::parse_response::<ListAssetHoldersResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListAssetHoldersResponse>(res).await?.data)
     }
 
     /// Call *Export Asset* API method.
@@ -1585,7 +1808,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1630,7 +1853,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1659,8 +1882,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn export_asset(
-        &mut self,
+        &self,
         asset_id: &str,
         foreign_blockchain: ForeignBlockchain,
         token: NewForeignTokenInfo,
@@ -1681,9 +1905,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ExportAsset, req).await?;
 
-        Ok(Self::parse_response::<ExportAssetResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ExportAssetResponse>(res).await?.data)
     }
 
     /// Call *Migrate Asset* API method.
@@ -1701,7 +1925,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1747,7 +1971,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1789,7 +2013,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1815,8 +2039,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn migrate_asset(
-        &mut self,
+        &self,
         asset_id: &str,
         foreign_blockchain: ForeignBlockchain,
         migration: AssetMigration,
@@ -1837,9 +2062,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::MigrateAsset, req).await?;
 
-        Ok(Self::parse_response::<MigrateAssetResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<MigrateAssetResponse>(res).await?.data)
     }
 
     /// Call *Asset Export Outcome* API method.
@@ -1855,7 +2080,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1892,8 +2117,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn asset_export_outcome(
-        &mut self,
+        &self,
         asset_id: &str,
         foreign_blockchain: ForeignBlockchain,
     ) -> Result<AssetExportOutcomeResult> {
@@ -1906,9 +2132,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::AssetExportOutcome, req).await?;
 
-        Ok(Self::parse_response::<AssetExportOutcomeResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<AssetExportOutcomeResponse>(res).await?.data)
     }
 
     /// Call *Asset Migration Outcome* API method.
@@ -1924,7 +2150,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1963,8 +2189,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn asset_migration_outcome(
-        &mut self,
+        &self,
         migration_id: &str,
     ) -> Result<AssetMigrationOutcomeResult> {
         let req = self.get_request(
@@ -1975,9 +2202,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::AssetMigrationOutcome, req).await?;
 
-        Ok(Self::parse_response::<AssetMigrationOutcomeResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<AssetMigrationOutcomeResponse>(res).await?.data)
     }
 
     /// Call *List Exported Assets* API method.
@@ -1993,7 +2220,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2030,7 +2257,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_exported_assets(&mut self, options: Option<ListExportedAssetsOptions>) -> Result<ListExportedAssetsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_exported_assets(&self, options: Option<ListExportedAssetsOptions>) -> Result<ListExportedAssetsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let asset_id;
@@ -2118,9 +2346,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListExportedAssets, req).await?;
 
-        Ok(Self::parse_response::<ListExportedAssetsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListExportedAssetsResponse>(res).await?.data)
     }
 
     /// Call *List Asset Migrations* API method.
@@ -2136,7 +2364,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2176,7 +2404,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_asset_migrations(&mut self, options: Option<ListAssetMigrationsOptions>) -> Result<ListAssetMigrationsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_asset_migrations(&self, options: Option<ListAssetMigrationsOptions>) -> Result<ListAssetMigrationsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let asset_id;
@@ -2264,9 +2493,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListAssetMigrations, req).await?;
 
-        Ok(Self::parse_response::<ListAssetMigrationsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListAssetMigrationsResponse>(res).await?.data)
     }
 
     /// Call *List Permission Events* API method.
@@ -2282,7 +2511,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2303,16 +2532,17 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_permission_events(&mut self) -> Result<ListPermissionEventsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_permission_events(&self) -> Result<ListPermissionEventsResult> {
         let req = self.get_request(
             "permission/events",
             None::<KVList>,
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListPermissionEvents, req).await?;
 
-        Ok(Self::parse_response::<ListPermissionEventsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListPermissionEventsResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Permission Rights* API method.
@@ -2328,7 +2558,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2385,7 +2615,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_permission_rights(&mut self, event: PermissionEvent) -> Result<RetrievePermissionRightsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_permission_rights(&self, event: PermissionEvent) -> Result<RetrievePermissionRightsResult> {
         let req = self.get_request(
             "permission/events/:event_name/rights",
             Some(&[
@@ -2394,9 +2625,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrievePermissionRights, req).await?;
 
-        Ok(Self::parse_response::<RetrievePermissionRightsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrievePermissionRightsResponse>(res).await?.data)
     }
 
     /// Call *Set Permission Rights* API method.
@@ -2412,7 +2643,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2465,7 +2696,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn set_permission_rights(&mut self, event: PermissionEvent, rights: AllPermissionRightsUpdate) -> Result<SetPermissionRightsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn set_permission_rights(&self, event: PermissionEvent, rights: AllPermissionRightsUpdate) -> Result<SetPermissionRightsResult> {
         let body_json = serde_json::to_string(&rights)?;
         let req = self.post_request(
             "permission/events/:event_name/rights",
@@ -2476,9 +2708,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::SetPermissionRights, req).await?;
 
-        Ok(Self::parse_response::<SetPermissionRightsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<SetPermissionRightsResponse>(res).await?.data)
     }
 
     /// Call *Check Effective Permission Right* API method.
@@ -2494,7 +2726,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2521,7 +2753,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn check_effective_permission_right(&mut self, event: PermissionEvent, device: DeviceId) -> Result<CheckEffectivePermissionRightResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn check_effective_permission_right(&self, event: PermissionEvent, device: DeviceId) -> Result<CheckEffectivePermissionRightResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let is_prod_unique_id;
@@ -2546,9 +2779,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::CheckEffectivePermissionRight, req).await?;
 
-        Ok(Self::parse_response::<CheckEffectivePermissionRightResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<CheckEffectivePermissionRightResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Device Identification Info* API method.
@@ -2564,7 +2797,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2590,7 +2823,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_device_identification_info(&mut self, device: DeviceId) -> Result<RetrieveDeviceIdentificationInfoResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_device_identification_info(&self, device: DeviceId) -> Result<RetrieveDeviceIdentificationInfoResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let is_prod_unique_id;
@@ -2614,9 +2848,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveDeviceIdentificationInfo, req).await?;
 
-        Ok(Self::parse_response::<RetrieveDeviceIdentificationInfoResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrieveDeviceIdentificationInfoResponse>(res).await?.data)
     }
 
     /// Call *List Notification Events* API method.
@@ -2632,7 +2866,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2653,16 +2887,17 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_notification_events(&mut self) -> Result<ListNotificationEventsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn list_notification_events(&self) -> Result<ListNotificationEventsResult> {
         let req = self.get_request(
             "notification/events",
             None::<KVList>,
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ListNotificationEvents, req).await?;
 
-        Ok(Self::parse_response::<ListNotificationEventsResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ListNotificationEventsResponse>(res).await?.data)
     }
 
     /// Call *Issue Non-Fungible Asset* API method.
@@ -2678,7 +2913,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2734,8 +2969,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn issue_non_fungible_asset(
-        &mut self,
+        &self,
         issuance_info_or_cont_token: NFAssetIssuanceInfoOrContToken,
         non_fungible_tokens: Option<Vec<Option<NewNonFungibleTokenInfo>>>,
         is_final: Option<bool>
@@ -2772,9 +3008,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::IssueNonFungibleAsset, req).await?;
 
-        Ok(Self::parse_response::<IssueNonFungibleAssetResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<IssueNonFungibleAssetResponse>(res).await?.data)
     }
 
     /// Call *Reissue Non-Fungible Asset* API method.
@@ -2790,7 +3026,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2841,8 +3077,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub async fn reissue_non_fungible_asset(
-        &mut self,
+        &self,
         asset_id: &str,
         reissuance_info_or_cont_token: NFAssetReissuanceInfoOrContToken,
         non_fungible_tokens: Option<Vec<Option<NewNonFungibleTokenInfo>>>,
@@ -2880,9 +3117,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::ReissueNonFungibleAsset, req).await?;
 
-        Ok(Self::parse_response::<ReissueNonFungibleAssetResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<ReissueNonFungibleAssetResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Non-Fungible Asset Issuance Progress* API method.
@@ -2898,7 +3135,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2943,7 +3180,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_non_fungible_asset_issuance_progress(&mut self, issuance_id: &str) -> Result<RetrieveNFAssetIssuanceProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_non_fungible_asset_issuance_progress(&self, issuance_id: &str) -> Result<RetrieveNFAssetIssuanceProgressResult> {
         let req = self.get_request(
             "assets/non-fungible/issuance/:issuance_id",
             Some(&[
@@ -2952,9 +3190,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleAssetIssuanceProgress, req).await?;
 
-        Ok(Self::parse_response::<RetrieveNFAssetIssuanceProgressResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrieveNFAssetIssuanceProgressResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Non-Fungible Token* API method.
@@ -2977,7 +3215,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -3049,7 +3287,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_non_fungible_token(&mut self, token_id: &str, options: Option<RetrieveNonFungibleTokenOptions>) -> Result<RetrieveNonFungibleTokenResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_non_fungible_token(&self, token_id: &str, options: Option<RetrieveNonFungibleTokenOptions>) -> Result<RetrieveNonFungibleTokenResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let retrieve_contents;
@@ -3110,9 +3349,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleToken, req).await?;
 
-        Ok(Self::parse_response::<RetrieveNonFungibleTokenResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrieveNonFungibleTokenResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Non-Fungible Token Retrieval Progress* API method.
@@ -3128,7 +3367,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -3177,7 +3416,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_non_fungible_token_retrieval_progress(&mut self, token_id: &str, retrieval_id: &str) -> Result<RetrieveNFTokenRetrievalProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_non_fungible_token_retrieval_progress(&self, token_id: &str, retrieval_id: &str) -> Result<RetrieveNFTokenRetrievalProgressResult> {
         let req = self.get_request(
             "assets/non-fungible/tokens/:token_id/retrieval/:retrieval_id",
             Some(&[
@@ -3187,9 +3427,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleTokenRetrievalProgress, req).await?;
 
-        Ok(Self::parse_response::<RetrieveNFTokenRetrievalProgressResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<RetrieveNFTokenRetrievalProgressResponse>(res).await?.data)
     }
 
     /// Call *Transfer Non-Fungible Token* API method.
@@ -3205,7 +3445,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -3231,7 +3471,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn transfer_non_fungible_token(&mut self, token_id: &str, receiving_device: DeviceId, async_: Option<bool>) -> Result<TransferNonFungibleTokenResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn transfer_non_fungible_token(&self, token_id: &str, receiving_device: DeviceId, async_: Option<bool>) -> Result<TransferNonFungibleTokenResult> {
         let body = TransferNonFungibleTokenRequest {
             receiving_device,
             async_,
@@ -3246,9 +3487,9 @@ impl CatenisClient {
             None::<KVList>,
         ).await?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::TransferNonFungibleToken, req).await?;
 
-        Ok(Self::parse_response::<TransferNonFungibleTokenResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<TransferNonFungibleTokenResponse>(res).await?.data)
     }
 
     /// Call *Retrieve Non-Fungible Token Transfer Progress* API method.
@@ -3264,7 +3505,7 @@ impl CatenisClient {
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
-    /// # let mut ctn_client = async_impl::CatenisClient::new_with_options(
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -3300,7 +3541,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn retrieve_non_fungible_token_transfer_progress(&mut self, token_id: &str, transfer_id: &str) -> Result<RetrieveNFTokenTransferProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn retrieve_non_fungible_token_transfer_progress(&self, token_id: &str, transfer_id: &str) -> Result<RetrieveNFTokenTransferProgressResult> {
         let req = self.get_request(
             "assets/non-fungible/tokens/:token_id/transfer/:transfer_id",
             Some(&[
@@ -3310,31 +3552,232 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req).await?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleTokenTransferProgress, req).await?;
+
+        Ok(Self::parse_api_response::<RetrieveNFTokenTransferProgressResponse>(res).await?.data)
+    }
+
+    /// Call an arbitrary Catenis API method.
+    ///
+    /// This can be used to call API methods that are not (yet) directly supported by this
+    /// library. The request is signed, compressed and retried, and errors are handled, the same way
+    /// as for the other API methods.
+    ///
+    /// The `endpoint_url_path` is relative to the API's base URL (e.g. `"messages/:message_id"`),
+    /// and may contain `:param` placeholders that are replaced with the values of the
+    /// corresponding `url_params`. The `data` property of the response is deserialized into the
+    /// requested type, which can be [`JsonValue`] to get it as raw JSON.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     async_impl, ClientOptions, Environment,
+    ///     JsonValue, Method, Result,
+    /// };
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> Result<()> {
+    /// # let ctn_client = async_impl::CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// let data: JsonValue = ctn_client.call_api(
+    ///     Method::GET,
+    ///     "messages/:message_id",
+    ///     &[("message_id", "o3muoTnnD6cXYyarYY38")],
+    ///     &[("encoding", "utf8")],
+    ///     None,
+    /// ).await?;
+    ///
+    /// println!("Read message: {}", data["msgData"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub async fn call_api<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint_url_path: &str,
+        url_params: &[(&str, &str)],
+        query_params: &[(&str, &str)],
+        body: Option<&JsonValue>,
+    ) -> Result<T> {
+        let body_json = match body {
+            Some(body) => serde_json::to_string(body)?,
+            None => String::new(),
+        };
+        let req = self.request_with_body(
+            method,
+            endpoint_url_path,
+            body_json,
+            Some(url_params),
+            if query_params.is_empty() { None } else { Some(query_params) },
+        ).await?;
+
+        let res = self.sign_and_send_request(ApiMethod::CallApi, req).await?;
 
-        Ok(Self::parse_response::<RetrieveNFTokenTransferProgressResponse>(res).await?.data)
+        Ok(Self::parse_api_response::<GenericResponse<T>>(res).await?.data)
     }
 
     // Definition of private methods
 
-    async fn send_request(&self, req: Request) -> Result<Response> {
-        let res = self.http_client
-            .execute(req)
-            .await
-            .map_err::<Error, _>(Into::into)?;
+    /// Clone of this client that signs requests on behalf of another virtual device.
+    ///
+    /// Everything but the credentials (and the cached signing key) is shared with this client,
+    /// including the underlying HTTP client and its connection pool.
+    pub(crate) fn for_device(&self, device_credentials: DeviceCredentials) -> Self {
+        CatenisClient {
+            signing: SigningState::new(Some(device_credentials)),
+            ..self.clone()
+        }
+    }
+
+    async fn send_request(&self, api_method: ApiMethod, req: Request) -> Result<TracedResponse<Response>> {
+        versioning::check_method_support(api_method, self.api_version)?;
+
+        let mut attempt = 1;
+        let mut annotations = HashMap::new();
+        let mut call_trace = CallTrace::start(api_method.name(), &req, &self.metrics);
+        let mut failovers = 0;
+
+        loop {
+            self.circuit_breaker.check()?;
+
+            if let Some(delay) = self.rate_limiter.reserve() {
+                tokio::time::sleep(delay).await;
+            }
+
+            let mut attempt_req = Self::clone_request(&req)?;
+            let endpoint = self.endpoints.select(attempt_req.url_mut());
+            self.interceptors.before_sign(&mut attempt_req, attempt, &mut annotations)?;
+            self.interceptors.after_sign(&mut attempt_req, attempt, &mut annotations)?;
+            call_trace.attempt(&attempt_req, attempt);
+
+            // Note: fail over at most once per alternative endpoint
+            let failover_endpoint = if failovers + 1 < self.endpoints.len() { Some(endpoint) } else { None };
+
+            match self.send_request_attempt(attempt_req, attempt, false, failover_endpoint, &annotations, &mut call_trace).await? {
+                SendAttempt::Done(res) => return Ok(TracedResponse::new(res, call_trace)),
+                SendAttempt::Retry(delay) => tokio::time::sleep(delay).await,
+                SendAttempt::Resign => (),
+                SendAttempt::Failover => failovers += 1,
+            }
+
+            attempt += 1;
+        }
+    }
+
+    async fn sign_and_send_request(&self, api_method: ApiMethod, req: Request) -> Result<TracedResponse<Response>> {
+        versioning::check_method_support(api_method, self.api_version)?;
+
+        let mut attempt = 1;
+        let mut annotations = HashMap::new();
+        let mut call_trace = CallTrace::start(api_method.name(), &req, &self.metrics);
+        let mut failovers = 0;
+        let mut resigned = false;
+
+        loop {
+            self.circuit_breaker.check()?;
+
+            // Note: wait for the rate limiter before signing, so the signature's timestamp is
+            //  not left behind
+            if let Some(delay) = self.rate_limiter.reserve() {
+                tokio::time::sleep(delay).await;
+            }
+
+            // Note: every attempt needs to be signed anew since its timestamp changes
+            let mut attempt_req = Self::clone_request(&req)?;
+            let endpoint = self.endpoints.select(attempt_req.url_mut());
+            self.interceptors.before_sign(&mut attempt_req, attempt, &mut annotations)?;
+            self.sign_request(&mut attempt_req).await?;
+            self.interceptors.after_sign(&mut attempt_req, attempt, &mut annotations)?;
+            call_trace.attempt(&attempt_req, attempt);
+
+            // Note: fail over at most once per alternative endpoint
+            let failover_endpoint = if failovers + 1 < self.endpoints.len() { Some(endpoint) } else { None };
+
+            match self.send_request_attempt(attempt_req, attempt, !resigned, failover_endpoint, &annotations, &mut call_trace).await? {
+                SendAttempt::Done(res) => return Ok(TracedResponse::new(res, call_trace)),
+                SendAttempt::Retry(delay) => tokio::time::sleep(delay).await,
+                SendAttempt::Resign => resigned = true,
+                SendAttempt::Failover => failovers += 1,
+            }
+
+            attempt += 1;
+        }
+    }
+
+    async fn send_request_attempt(&self, req: Request, attempt: u32, resign_on_skew: bool, failover_endpoint: Option<usize>, annotations: &HashMap<String, String>, call_trace: &mut CallTrace) -> Result<SendAttempt<Response>> {
+        let method = req.method().clone();
+        let res = match self.http_client.execute(req).await {
+            Ok(res) => res,
+            Err(err) => {
+                self.circuit_breaker.record_failure();
+
+                if pinning::is_pin_mismatch_cause(&err) {
+                    // Note: never retry nor fail over a connection rejected for not matching the pins
+                    return Err(Error::new_pin_mismatch_error(None));
+                }
+
+                if let Some(endpoint) = failover_endpoint {
+                    if Endpoints::fails_over_on_error(&err) && self.endpoints.fail_over(endpoint) {
+                        return Ok(SendAttempt::Failover);
+                    }
+                }
+
+                return if let Some(delay) = self.retry_policy.delay_for_error(attempt, &err) {
+                    Ok(SendAttempt::Retry(delay))
+                } else {
+                    Err(err.into())
+                };
+            }
+        };
+
+        call_trace.response(res.status());
+
+        let skew_changed = self.clock_skew.update(res.headers());
+
+        if res.status() == StatusCode::TOO_MANY_REQUESTS {
+            self.rate_limiter.throttle(RetryPolicy::parse_retry_after(res.headers()));
+        } else if res.status().is_success() {
+            self.rate_limiter.recover();
+        }
+
+        if res.status().is_server_error() {
+            self.circuit_breaker.record_failure();
+        } else {
+            self.circuit_breaker.record_success();
+        }
+
+        self.interceptors.after_response(res.status(), res.url(), res.headers(), attempt, annotations)?;
 
         if res.status().is_success() {
-            Ok(res)
+            Ok(SendAttempt::Done(res))
+        } else if failover_endpoint.filter(|&endpoint| self.endpoints.fails_over_on_status(res.status())
+                && self.retry_policy.may_resend(&method, res.status(), res.headers())
+                && self.endpoints.fail_over(endpoint)).is_some() {
+            Ok(SendAttempt::Failover)
+        } else if res.status() == StatusCode::UNAUTHORIZED && skew_changed && resign_on_skew {
+            // Authentication probably failed due to the local clock being off. Sign request
+            //  anew with the corrected time
+            Ok(SendAttempt::Resign)
+        } else if let Some(delay) = self.retry_policy.delay_for_response(attempt, &method, res.status(), res.headers()) {
+            Ok(SendAttempt::Retry(delay))
         } else {
             Err(Error::from_http_response_async(res).await)
         }
     }
 
-    async fn sign_and_send_request(&mut self, mut req: Request) -> Result<Response> {
-        self.sign_request(&mut req)?;
-        self.send_request(req).await
-    }
-
     fn get_request<I, K, V, I2, K2, V2>(&self, endpoint_url_path: &str, url_params: Option<I>, query_params: Option<I2>) -> Result<Request>
         where
             I: IntoIterator,
@@ -3373,6 +3816,20 @@ impl CatenisClient {
             K2: AsRef<str>,
             V2: AsRef<str>,
             <I2 as IntoIterator>::Item: Borrow<(K2, V2)>,
+    {
+        self.request_with_body(Method::POST, endpoint_url_path, body, url_params, query_params).await
+    }
+
+    async fn request_with_body<I, K, V, I2, K2, V2>(&self, method: Method, endpoint_url_path: &str, body: String, url_params: Option<I>, query_params: Option<I2>) -> Result<Request>
+        where
+            I: IntoIterator,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            <I as IntoIterator>::Item: Borrow<(K, V)>,
+            I2: IntoIterator,
+            K2: AsRef<str>,
+            V2: AsRef<str>,
+            <I2 as IntoIterator>::Item: Borrow<(K2, V2)>,
     {
         let mut endpoint_url_path = String::from(endpoint_url_path);
 
@@ -3381,7 +3838,7 @@ impl CatenisClient {
         }
 
         let mut req_builder = self.http_client
-            .post(self.base_api_url.join(&endpoint_url_path)?);
+            .request(method, self.base_api_url.join(&endpoint_url_path)?);
 
         if body.len() > 0 {
             // Prepare to add body to request
@@ -3389,8 +3846,8 @@ impl CatenisClient {
 
             if self.use_compression && body.len() >= self.compress_threshold {
                 // Add compressed body
-                req_builder = req_builder.body(Self::compress_body(body).await?)
-                    .header(CONTENT_ENCODING, HeaderValue::from_static("deflate"));
+                req_builder = req_builder.body(Self::compress_body(body, self.compression.request).await?)
+                    .header(CONTENT_ENCODING, HeaderValue::from_static(self.compression.request.content_encoding()));
             } else {
                 // Add plain body
                 req_builder = req_builder.body(body)
@@ -3405,7 +3862,9 @@ impl CatenisClient {
             .map_err(Into::into)
     }
 
-    pub(crate) fn get_ws_request<I, K, V>(&self, endpoint_url_path: &str, url_params: Option<I>) -> Result<Request>
+    /// Assemble the request used to open a WebSocket notification channel, against the endpoint
+    /// in use, returning it along with the index of that endpoint.
+    pub(crate) fn get_ws_request<I, K, V>(&self, endpoint_url_path: &str, url_params: Option<I>) -> Result<(Request, usize)>
         where
             I: IntoIterator,
             K: AsRef<str>,
@@ -3413,141 +3872,163 @@ impl CatenisClient {
             <I as IntoIterator>::Item: Borrow<(K, V)>,
     {
         let mut req = self.get_request(endpoint_url_path, url_params, None::<KVList>)?;
+        let endpoint = self.endpoints.select(req.url_mut());
 
         // Replace URL scheme as appropriate
-        if let Err(_) = req.url_mut().set_scheme(if self.is_secure {"wss"} else {"ws"}) {
+        let ws_scheme = if req.url().scheme() == "https" {"wss"} else {"ws"};
+
+        if req.url_mut().set_scheme(ws_scheme).is_err() {
             return Err(Error::new_client_error(Some("Error resetting URL scheme"), None::<GenericError>));
         }
 
-        Ok(req)
+        Ok((req, endpoint))
     }
 
-    pub(crate) fn sign_request(&mut self, req: &mut Request) -> Result<()> {
-        let mut new_headers = HeaderMap::new();
-        let now;
-        let timestamp;
-
-        // Identify new headers that need to be added to HTTP request
-        {
-            let headers = req.headers();
-
-            if !headers.contains_key(HOST) {
-                // Prepare to add missing 'host' header to HTTP request
-                if let Some(host) = Self::get_host_with_port(req.url()) {
-                    new_headers.insert(HOST, host.parse()?);
-                } else {
-                    return Err(Error::new_client_error(Some("Inconsistent HTTP request: URL missing host"), None::<GenericError>));
-                }
-            }
-
-            // Prepare to add custom 'x-bcot-timestamp' header to HTTP request
-            now = now!();
-            timestamp = now.format("%Y%m%dT%H%M%SZ");
-            new_headers.insert(X_BCOT_TIMESTAMP, timestamp.parse()?);
-        }
-
-        // Add headers to HTTP request
-        {
-            for (key, value) in new_headers.iter() {
-                let val = value.clone();
-
-                req.headers_mut().insert(key, val);
-            }
-        }
-
-        // Prepare to sign HTTP request
-
-        // 1. Assemble conformed request
-
-        // 1.1. Add HTTP verb
-        let mut conformed_request: String = req.method().to_string() + "\n";
-
-        // 1.2. Add URL path
-        conformed_request = conformed_request + &Self::get_url_path_with_query(req.url()) + "\n";
-
-        // 1.3. Assemble and add essential headers
-        {
-            let essential_headers_list = [
-                HOST,
-                HeaderName::from_static(X_BCOT_TIMESTAMP)
-            ];
-            let mut essential_headers = String::from("");
-            let headers = req.headers();
-
-            for header_name in essential_headers_list.iter() {
-                essential_headers = essential_headers + header_name.as_str() + ":" + headers.get(header_name).unwrap().to_str()? + "\n";
+    pub(crate) async fn sign_request(&self, req: &mut Request) -> Result<()> {
+        if !req.headers().contains_key(HOST) {
+            // Add missing 'host' header to HTTP request
+            if let Some(host) = Self::get_host_with_port(req.url()) {
+                req.headers_mut().insert(HOST, host.parse()?);
+            } else {
+                return Err(Error::new_client_error(Some("Inconsistent HTTP request: URL missing host"), None::<GenericError>));
             }
-
-            conformed_request = conformed_request + &essential_headers + "\n";
         }
 
-        // 1.4. Hash HTTP request payload and add it
+        let host = String::from(req.headers().get(HOST).unwrap().to_str()?);
+        let method = req.method().to_string();
+        let path_with_query = Self::get_url_path_with_query(req.url());
         let payload = if let Some(body) = req.body_mut() {
             body.as_bytes().expect("Unable to access request body; body not buffered")
         } else {
             b""
         };
 
-        conformed_request = conformed_request + &sha256::Hash::hash(payload).to_hex() + "\n";
+        let (timestamp, authorization) = self.signing.sign_async(&method, &path_with_query, &host, payload, &self.clock_skew.now()).await?;
 
-        // 2. Update sign date and signing key
-        self.check_update_sign_date_and_key(&now)?;
+        // Add 'x-bcot-timestamp' and 'authorization' headers to HTTP request
+        req.headers_mut().insert(X_BCOT_TIMESTAMP, timestamp.parse()?);
+        req.headers_mut().insert(AUTHORIZATION, authorization.parse()?);
 
-        // 3. Assemble string to sign
-        let scope = self.sign_date.unwrap().format("%Y%m%d") + "/ctn1_request";
-        let string_to_sign = String::from("CTN1-HMAC-SHA256\n") + &timestamp + "\n"
-            + &scope + "\n"
-            + &sha256::Hash::hash(conformed_request.as_bytes()).to_hex() + "\n";
+        Ok(())
+    }
 
-        // 4. Generate signature
-        let mut hmac_engine = HmacEngine::<sha256::Hash>::new(&self.signing_key.unwrap());
-        hmac_engine.input(string_to_sign.as_bytes());
-        let signature = Hmac::<sha256::Hash>::from_engine(hmac_engine).to_hex();
+    // Definition of private associated ("static") functions
 
-        // Add 'authorization' header to HTTP request
-        let value = String::from("CTN1-HMAC-SHA256 Credential=") + self.get_device_id_ref()?.as_str() + "/"
-            + &scope + ",Signature=" + &signature;
+    async fn parse_api_response<T: DeserializeOwned>(res: TracedResponse<Response>) -> Result<T> {
+        let (res, mut call_trace) = res.into_parts();
+        let parsed = Self::parse_response(res).await?;
 
-        req.headers_mut().insert(AUTHORIZATION, value.parse()?);
+        // Note: the call is only deemed successful once its response has been parsed
+        call_trace.succeeded();
 
-        Ok(())
+        Ok(parsed)
     }
 
-    // Definition of private associated ("static") functions
-
     async fn parse_response<T: DeserializeOwned>(res: Response) -> Result<T> {
         let body = res.text().await
-            .map_err::<Error, _>(|e| Error::new_client_error(Some("Inconsistent Catenis API response"), Some(e)))?;
+            .map_err::<Error, _>(|e| if e.is_timeout() {
+                e.into()
+            } else {
+                Error::new_client_error(Some("Inconsistent Catenis API response"), Some(e))
+            })?;
+
+        trace::record_response_size(body.len());
 
         serde_json::from_str(&body)
             .map_err::<Error, _>(|e| Error::new_client_error(Some("Inconsistent Catenis API response"), Some(e)))
     }
 
-    fn new_http_client(use_compression: bool) -> reqwest::Result<HttpClient> {
+    fn new_http_client(use_compression: bool, compression: &CompressionOptions, connection_settings: &ConnectionSettings) -> Result<HttpClient> {
         let mut client_builder = HttpClientBuilder::new();
 
         // Prepare to add default HTTP headers
         let mut headers = HeaderMap::new();
 
         if use_compression {
-            headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+            if let Some(accept_encoding) = compression.accept_encoding() {
+                headers.insert(ACCEPT_ENCODING, accept_encoding);
+            }
 
             // Make sure that compressed response body is automatically decompressed
-            client_builder = client_builder.gzip(true);
+            client_builder = client_builder
+                .gzip(compression.accept_gzip)
+                .deflate(compression.accept_deflate)
+                .brotli(compression.accept_brotli);
         } else {
             // Make sure that response is not compressed
-            client_builder = client_builder.gzip(false);
+            client_builder = client_builder
+                .gzip(false)
+                .deflate(false)
+                .brotli(false);
+        }
+
+        if let Some(timeout) = connection_settings.request_timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = connection_settings.connect_timeout {
+            client_builder = client_builder.connect_timeout(timeout);
         }
 
-        client_builder
+        if let Some(proxy) = &connection_settings.proxy {
+            client_builder = client_builder.proxy(proxy.to_http_proxy());
+        }
+
+        // Set up TLS
+        #[cfg(any(feature = "native-tls", feature = "rustls"))]
+        {
+            match &connection_settings.pinned_keys {
+                // Note: public keys can only be pinned with the `rustls` feature. The server's
+                //  certificate chain is checked against the pins during the TLS handshake, so no
+                //  request is ever sent to a server that does not match them
+                #[cfg(feature = "rustls")]
+                Some(pinned_keys) => {
+                    client_builder = client_builder.use_preconfigured_tls(connection_settings.tls.http_rustls_config(pinned_keys)?);
+                }
+                _ => {
+                    #[cfg(feature = "rustls")]
+                    {
+                        client_builder = client_builder.use_rustls_tls();
+                    }
+
+                    for cert in connection_settings.tls.http_root_certificates()? {
+                        client_builder = client_builder.add_root_certificate(cert);
+                    }
+
+                    if let Some(identity) = connection_settings.tls.http_identity()? {
+                        client_builder = client_builder.identity(identity);
+                    }
+
+                    client_builder = client_builder.tls_built_in_root_certs(connection_settings.tls.use_built_in_roots);
+                }
+            }
+        }
+        #[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+        {
+            connection_settings.tls.check_tls_not_required()?;
+        }
+
+        Ok(client_builder
             .default_headers(headers)
-            .build()
+            .build()?)
     }
 
-    async fn compress_body(body: String) -> Result<Vec<u8>> {
-        let mut enc = ZlibEncoder::with_quality(body.as_bytes(), Level::Default);
+    fn clone_request(req: &Request) -> Result<Request> {
+        req.try_clone()
+            .ok_or_else(|| Error::new_client_error(Some("Unable to clone HTTP request; body not buffered"), None::<GenericError>))
+    }
+
+    async fn compress_body(body: String, compression: RequestCompression) -> Result<Vec<u8>> {
         let mut enc_body = Vec::new();
-        enc.read_to_end(&mut enc_body).await?;
+
+        match compression {
+            RequestCompression::Deflate => {
+                ZlibEncoder::with_quality(body.as_bytes(), Level::Default).read_to_end(&mut enc_body).await?
+            },
+            RequestCompression::Gzip => {
+                GzipEncoder::with_quality(body.as_bytes(), Level::Default).read_to_end(&mut enc_body).await?
+            },
+        };
 
         Ok(enc_body)
     }
@@ -3555,6 +4036,7 @@ impl CatenisClient {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Read;
     use super::*;
     use crate::{
         test_helper::{
@@ -3564,6 +4046,8 @@ mod tests {
             },
         },
     };
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    use crate::test_helper::http_server::TEST_CA_CERT;
     use super::CatenisClient;
 
     #[tokio::test]
@@ -3583,7 +4067,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                  "d8YpQ7jgPBJEkBrnvp58",
                  "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -3643,7 +4127,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3712,7 +4196,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3781,7 +4265,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3854,7 +4338,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3928,7 +4412,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4002,7 +4486,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4083,7 +4567,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "d8YpQ7jgPBJEkBrnvp58",
                 "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -4154,7 +4638,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "d8YpQ7jgPBJEkBrnvp58",
                 "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -4227,7 +4711,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "d8YpQ7jgPBJEkBrnvp58",
                 "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -4301,7 +4785,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4367,7 +4851,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4438,7 +4922,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4883,7 +5367,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4959,7 +5443,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5062,7 +5546,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5183,7 +5667,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5250,7 +5734,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5315,7 +5799,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5386,7 +5870,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5453,7 +5937,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5532,7 +6016,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5625,7 +6109,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5719,7 +6203,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5768,6 +6252,79 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    async fn it_retrieve_asset_issuance_history_api_0_11() {
+        // Simulate successful 'Retrieve Asset Issuance History' API method response of API version 0.11
+
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "issuanceEvents": [
+      {
+        "amount": 1500,
+        "holdingDevice": {
+          "deviceId": "drc3XdxNtzoucpw9xiRp",
+          "name": "TstDev1"
+        },
+        "date": "2020-12-23T10:51:45.935Z"
+      }
+    ],
+    "hasMore": false
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("GET")),
+            path: Some(String::from("/api/0.11/assets/aBy2ovnucyWaSB6Tro9x/issuance")),
+            headers: None,
+            body: None,
+        });
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Version(ApiVersion(0, 11)),
+            ],
+        ).unwrap();
+
+        let result = ctn_client.retrieve_asset_issuance_history(
+            "aBy2ovnucyWaSB6Tro9x",
+            None,
+            None,
+            None,
+            None,
+        ).await.unwrap();
+
+        assert_eq!(result, RetrieveAssetIssuanceHistoryResult {
+            issuance_events: vec![
+                AssetIssuanceEventEntry::Regular(RegularAssetIssuanceEventEntry {
+                    amount: 1500.0,
+                    holding_device: DeviceInfo {
+                        device_id: String::from("drc3XdxNtzoucpw9xiRp"),
+                        name: Some(String::from("TstDev1")),
+                        prod_unique_id: None,
+                    },
+                    date: "2020-12-23T10:51:45.935Z".into(),
+                }),
+            ],
+            has_more: false,
+        });
+    }
+
     #[tokio::test]
     async fn it_list_asset_holders() {
         // Simulate successful 'List Asset Holders' API method response
@@ -5833,7 +6390,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5929,7 +6486,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6011,7 +6568,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6093,7 +6650,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6178,7 +6735,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6271,7 +6828,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6354,7 +6911,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6436,7 +6993,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6541,7 +7098,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6680,7 +7237,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6801,7 +7358,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6911,7 +7468,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7006,7 +7563,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7108,7 +7665,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7185,7 +7742,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7262,7 +7819,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7335,14 +7892,14 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.send_request(req).await;
+        let result = ctn_client.send_request(ApiMethod::ReadMessage, req).await;
 
         assert!(!result.is_err(), "Returned error from sending request");
 
         let res = result.unwrap();
 
         assert!(res.status().is_success(), "Unexpected HTTP response: not success");
-        assert_eq!(res.text().await.unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
+        assert_eq!(res.into_parts().0.text().await.unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
     }
 
     #[tokio::test]
@@ -7384,7 +7941,7 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.send_request(req).await;
+        let result = ctn_client.send_request(ApiMethod::ReadMessage, req).await;
 
         assert!(result.is_err(), "Returned success from sending request");
         assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [400] - Invalid message ID");
@@ -7406,7 +7963,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7428,14 +7985,14 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.sign_and_send_request(req).await;
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
 
         assert!(!result.is_err(), "Returned error from sending request");
 
         let res = result.unwrap();
 
         assert!(res.status().is_success(), "Unexpected HTTP response: not success");
-        assert_eq!(res.text().await.unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
+        assert_eq!(res.into_parts().0.text().await.unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
     }
 
     #[tokio::test]
@@ -7455,7 +8012,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7477,20 +8034,44 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.sign_and_send_request(req).await;
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
 
         assert!(result.is_err(), "Returned success from sending request");
         assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [400] - Invalid message ID");
     }
 
-    #[test]
-    fn it_assemble_get_request() {
+    #[tokio::test]
+    async fn it_sign_and_send_request_retry_success() {
+        // Simulate transient error followed by successful 'Read Message' API method response
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    503,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Service unavailable"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        ).with_response_header("Retry-After", "0");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
         // Instantiate Catenis API client
-        let ctn_client = CatenisClient::new(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy::default()),
+            ],
         ).unwrap();
 
         // Get request used for calling 'Read Message' API method
@@ -7504,37 +8085,34 @@ mod tests {
             ]),
         ).unwrap();
 
-        assert_eq!(req.method().to_string(), "GET");
-        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/oNXszDazhuq4utktSMMi?encoding=utf8", DEFAULT_API_VERSION.to_string()));
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
+
+        assert!(result.is_ok(), "Returned error from sending request");
+        assert!(result.unwrap().status().is_success(), "Unexpected HTTP response: not success");
     }
 
     #[tokio::test]
-    async fn it_assemble_post_request() {
-        // Instantiate Catenis API client
-        let ctn_client = CatenisClient::new(
-            Some((
-                "drc3XdxNtzoucpw9xiRp",
-                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
-            ).into()),
-        ).unwrap();
+    async fn it_sign_and_send_request_retry_exhausted() {
+        // Simulate persistent error 'Read Message' API method response
 
-        // Get request used for calling 'Log Message' API method
-        let req = ctn_client.post_request(
-            "messages/log",
-            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
-            None::<KVList>,
-            None::<KVList>,
-        ).await.unwrap();
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    503,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Service unavailable"}"#).unwrap()),
+                ),
+                HttpServerMode::Error(
+                    503,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Service still unavailable"}"#).unwrap()),
+                ),
+            ]),
+            "localhost"
+        );
+        http_server.start();
 
-        assert_eq!(req.method().to_string(), "POST");
-        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
-        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
-        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
-        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"{\"message\":\"Test message\",\"options\":{\"encoding\":\"utf8\"}}");
-    }
+        let server_port = http_server.get_port();
 
-    #[tokio::test]
-    async fn it_assemble_post_request_with_compression_not_done() {
         // Instantiate Catenis API client
         let ctn_client = CatenisClient::new_with_options(
             Some((
@@ -7542,28 +8120,54 @@ mod tests {
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
             &[
-                ClientOptions::UseCompression(true),
-                ClientOptions::CompressThreshold(57),
-            ]
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy {
+                    max_attempts: 2,
+                    initial_backoff: std::time::Duration::from_millis(10),
+                    ..RetryPolicy::default()
+                }),
+            ],
         ).unwrap();
 
-        // Get request used for calling 'Log Message' API method
-        let req = ctn_client.post_request(
-            "messages/log",
-            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
-            None::<KVList>,
-            None::<KVList>,
-        ).await.unwrap();
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            Some(&[
+                ("encoding", "utf8"),
+            ]),
+        ).unwrap();
 
-        assert_eq!(req.method().to_string(), "POST");
-        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
-        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
-        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
-        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"{\"message\":\"Test message\",\"options\":{\"encoding\":\"utf8\"}}");
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
+
+        assert!(result.is_err(), "Returned success from sending request");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [503] - Service still unavailable");
     }
 
     #[tokio::test]
-    async fn it_assemble_post_request_with_compression() {
+    async fn it_sign_and_send_request_no_retry_non_idempotent() {
+        // Simulate transient error 'Log Message' API method response
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    500,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Internal server error"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"messageId":"mdx8vuCGWdb2TFeWFZd6"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
         // Instantiate Catenis API client
         let ctn_client = CatenisClient::new_with_options(
             Some((
@@ -7571,46 +8175,1143 @@ mod tests {
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
             &[
-                ClientOptions::UseCompression(true),
-                ClientOptions::CompressThreshold(56),
-            ]
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy {
+                    initial_backoff: std::time::Duration::from_millis(10),
+                    ..RetryPolicy::default()
+                }),
+            ],
         ).unwrap();
 
         // Get request used for calling 'Log Message' API method
         let req = ctn_client.post_request(
             "messages/log",
-            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            String::from(r#"{"message":"Test message"}"#),
             None::<KVList>,
             None::<KVList>,
         ).await.unwrap();
 
-        assert_eq!(req.method().to_string(), "POST");
-        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
-        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
-        assert_eq!(req.headers().get(CONTENT_ENCODING).unwrap(), "deflate");
-        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"\x78\x9c\x01\x38\x00\xc7\xff\x7b\x22\x6d\x65\x73\x73\x61\x67\x65\x22\x3a\x22\x54\x65\x73\x74\x20\x6d\x65\x73\x73\x61\x67\x65\x22\x2c\x22\x6f\x70\x74\x69\x6f\x6e\x73\x22\x3a\x7b\x22\x65\x6e\x63\x6f\x64\x69\x6e\x67\x22\x3a\x22\x75\x74\x66\x38\x22\x7d\x7d\x2e\x4c\x13\x83");
+        let result = ctn_client.sign_and_send_request(ApiMethod::LogMessage, req).await;
+
+        assert!(result.is_err(), "Returned success from sending request");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [500] - Internal server error");
     }
 
-    #[test]
+    #[tokio::test]
+    async fn it_send_request_no_retry_client_error() {
+        // Simulate error 'Read Message' API method response
+
+        // Start HTTP server in error simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Error(
+                400,
+                Some(HttpBody::from_json(r#"{"status":"error","message":"Invalid message ID"}"#).unwrap()),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy::default()),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req).await;
+
+        assert!(result.is_err(), "Returned success from sending request");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [400] - Invalid message ID");
+    }
+
+    #[tokio::test]
+    async fn it_send_request_timeout() {
+        // Simulate slow 'Retrieve Message Origin' API method response
+
+        // Start HTTP server in success simulation mode delaying the response
+        let http_server = HttpServer::new(
+            HttpServerMode::Delay(
+                Duration::from_millis(500),
+                Box::new(HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+                )),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Timeout(Duration::from_millis(100)),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req).await;
+
+        assert!(result.is_err(), "Returned success from sending request");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_timeout(), "Returned error is not a timeout error");
+        assert!(err.to_string().starts_with("Catenis client error: Request to Catenis API server timed out"));
+    }
+
+    #[cfg(feature = "rustls")]
+    #[tokio::test]
+    async fn it_send_request_pinned_public_key() {
+        // Start HTTPS server in success simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+            ),
+            "localhost"
+        ).with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client pinning the server's public key
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::TlsRootCertificates(TEST_CA_CERT),
+                ClientOptions::PinnedPublicKeys(&["sha256//wkoiJEI3H1+FkhR2xwIdrQJbgRaMPty2Y+asZ3//BNs="]),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req).await;
+
+        assert!(result.is_ok(), "Failed to send request to server with pinned public key: {:?}", result.err());
+    }
+
+    #[cfg(feature = "rustls")]
+    #[tokio::test]
+    async fn it_send_request_pinned_root_public_key() {
+        // Start HTTPS server in success simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+            ),
+            "localhost"
+        ).with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client pinning the public key of the (root) CA that issued the
+        //  server's certificate, which is not presented by the server
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::TlsRootCertificates(TEST_CA_CERT),
+                ClientOptions::PinnedPublicKeys(&["WFXP2PS2PCUkPpP8EMsWdZwpzbROpAC0cO6zMn0ifT8="]),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req).await;
+
+        assert!(result.is_ok(), "Failed to send request to server with pinned root public key: {:?}", result.err());
+    }
+
+    #[cfg(feature = "rustls")]
+    #[tokio::test]
+    async fn it_send_request_pin_mismatch() {
+        // Start HTTPS server in success simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+            ),
+            "localhost"
+        ).with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client pinning a different public key
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::TlsRootCertificates(TEST_CA_CERT),
+                ClientOptions::PinnedPublicKeys(&["47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="]),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req).await;
+
+        assert!(result.is_err(), "Sent request to server not matching pinned public key");
+        assert!(result.err().unwrap().is_pin_mismatch(), "Returned error is not a pin mismatch error");
+    }
+
+    #[cfg(not(feature = "rustls"))]
+    #[tokio::test]
+    async fn it_fail_new_client_pinning_not_enabled() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::PinnedPublicKeys(&["47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="]),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with pinned public keys and no rustls support");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Public key pinning not enabled: the `rustls` feature must be enabled");
+    }
+
+    #[test]
+    fn it_set_connection_timeouts() {
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Timeout(Duration::from_secs(10)),
+                ClientOptions::ConnectTimeout(Duration::from_secs(2)),
+                ClientOptions::WsHandshakeTimeout(Duration::from_secs(5)),
+            ],
+        ).unwrap();
+
+        assert_eq!(ctn_client.connection_settings.request_timeout, Some(Duration::from_secs(10)));
+        assert_eq!(ctn_client.connection_settings.connect_timeout, Some(Duration::from_secs(2)));
+        assert_eq!(ctn_client.connection_settings.ws_handshake_timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[tokio::test]
+    async fn it_share_rate_limit_among_clones() {
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::RateLimit(RateLimit {
+                    requests_per_second: 1.0,
+                    burst: 1,
+                }),
+            ],
+        ).unwrap();
+        let ctn_client_clone = ctn_client.clone();
+
+        assert!(ctn_client.rate_limiter.reserve().is_none(), "Request throttled within burst");
+        assert!(ctn_client_clone.rate_limiter.reserve().is_some(), "Rate limit not shared among clones");
+    }
+
+    #[tokio::test]
+    async fn it_fail_instantiate_client_invalid_rate_limit() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::RateLimit(RateLimit {
+                    requests_per_second: -1.0,
+                    burst: 10,
+                }),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with invalid rate limit");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid rate limit");
+    }
+
+    #[tokio::test]
+    async fn it_call_api_raw_json() {
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "msgInfo": {
+      "action": "log"
+    },
+    "msgData": "Test message"
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("GET")),
+            path: Some(format!("/api/{}/messages/oNXszDazhuq4utktSMMi?encoding=utf8", DEFAULT_API_VERSION)),
+            headers: Some(
+                vec![
+                    String::from("x-bcot-timestamp"),
+                    String::from("authorization"),
+                ].into_iter().zip(vec![
+                    None,
+                    None,
+                ]).collect()
+            ),
+            body: None,
+        });
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let result: JsonValue = ctn_client.call_api(
+            Method::GET,
+            "messages/:message_id",
+            &[("message_id", "oNXszDazhuq4utktSMMi")],
+            &[("encoding", "utf8")],
+            None,
+        ).await.unwrap();
+
+        assert_eq!(result, serde_json::json!({
+            "msgInfo": {
+                "action": "log"
+            },
+            "msgData": "Test message"
+        }));
+    }
+
+    #[tokio::test]
+    async fn it_call_api_typed_result() {
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "messageId": "oX2mJHwFWp752beHbNDK"
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("POST")),
+            path: Some(format!("/api/{}/messages/log", DEFAULT_API_VERSION)),
+            headers: Some(
+                vec![
+                    String::from("x-bcot-timestamp"),
+                    String::from("authorization"),
+                    String::from("content-type"),
+                ].into_iter().zip(vec![
+                    None,
+                    None,
+                    Some(HttpHeader {
+                        field: String::from("content-type"),
+                        value: String::from("application/json; charset=utf-8"),
+                    }),
+                ]).collect()
+            ),
+            body: Some(String::from(r#"{"message":"Test message"}"#)),
+        });
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let result: LogMessageResult = ctn_client.call_api(
+            Method::POST,
+            "messages/log",
+            &[],
+            &[],
+            Some(&serde_json::json!({"message": "Test message"})),
+        ).await.unwrap();
+
+        assert_eq!(result, LogMessageResult {
+            continuation_token: None,
+            message_id: Some(String::from("oX2mJHwFWp752beHbNDK")),
+            provisional_message_id: None,
+        });
+    }
+
+    #[tokio::test]
+    async fn it_fail_fast_with_circuit_open() {
+        // Simulate server error response to 'Read Message' API method
+
+        // Start HTTP server in error simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Error(
+                503,
+                Some(HttpBody::from_json(r#"{"status":"error","message":"Service unavailable"}"#).unwrap()),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::CircuitBreaker(CircuitBreakerPolicy {
+                    failure_threshold: 1,
+                    ..CircuitBreakerPolicy::default()
+                }),
+            ],
+        ).unwrap();
+        let ctn_client_clone = ctn_client.clone();
+
+        assert_eq!(ctn_client.circuit_state(), Some(CircuitState::Closed));
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, CatenisClient::clone_request(&req).unwrap()).await;
+
+        assert!(result.is_err(), "Server error not returned");
+        assert!(result.err().unwrap().api_error_info().is_some(), "Unexpected error kind");
+        assert_eq!(ctn_client_clone.circuit_state(), Some(CircuitState::Open), "Circuit breaker not shared among clones");
+
+        // Next request should fail without being sent
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
+
+        assert!(result.is_err(), "Request sent while circuit breaker is open");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_circuit_open(), "Unexpected error kind: {}", err);
+        assert_eq!(err.to_string(), "Catenis client error: Circuit breaker open; request not sent");
+    }
+
+    #[tokio::test]
+    async fn it_fail_instantiate_client_invalid_circuit_breaker() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::CircuitBreaker(CircuitBreakerPolicy {
+                    failure_threshold: 0,
+                    ..CircuitBreakerPolicy::default()
+                }),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with invalid circuit breaker policy");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid circuit breaker policy");
+        assert_eq!(CatenisClient::new(None).unwrap().circuit_state(), None);
+    }
+
+    #[tokio::test]
+    async fn it_sign_and_send_request_compensate_clock_skew() {
+        // Simulate authentication failure due to clock skew followed by successful 'Read Message'
+        //  API method response
+
+        // Set custom "system" time one hour behind
+        let _custom_time = test_helper::time::CustomTime::set(&(time::OffsetDateTime::now_utc() - time::Duration::hours(1)));
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    401,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Authorization failed; timestamp not within acceptable time variation"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        assert_eq!(ctn_client.clock_skew_secs(), 0);
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
+
+        assert!(result.is_ok(), "Request not sent again after clock skew correction");
+        assert!((ctn_client.clock_skew_secs() - 3600).abs() <= 2, "Unexpected clock skew: {}", ctn_client.clock_skew_secs());
+    }
+
+    #[tokio::test]
+    async fn it_sign_and_send_request_throttled() {
+        // Simulate throttling followed by successful 'Read Message' API method response
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    429,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Too many requests"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        ).with_response_header("Retry-After", "0");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy::default()),
+                ClientOptions::RateLimit(RateLimit {
+                    requests_per_second: 5.0,
+                    burst: 1,
+                }),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let start = std::time::Instant::now();
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
+
+        assert!(result.is_ok(), "Returned error from sending request");
+
+        // Rate should have been halved (from 5 to 2.5 requests per second) after the 429 response,
+        //  so the retry must have waited for about 400 ms
+        assert!(start.elapsed() >= Duration::from_millis(350), "Request not throttled: {:?}", start.elapsed());
+    }
+
+    #[tokio::test]
+    async fn it_sign_and_send_request_with_interceptor() {
+        struct TraceInterceptor {
+            calls: std::sync::Mutex<Vec<String>>,
+        }
+
+        impl Interceptor for TraceInterceptor {
+            fn before_sign(&self, req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+                req.headers_mut().insert("x-trace-id", HeaderValue::from_static("a1b2c3d4"));
+                req.annotate("trace_id", "a1b2c3d4");
+
+                Ok(())
+            }
+
+            fn after_sign(&self, req: &InterceptedRequest) -> std::result::Result<(), Veto> {
+                self.calls.lock().unwrap().push(format!("after_sign signed: {}", req.headers().contains_key(AUTHORIZATION)));
+
+                Ok(())
+            }
+
+            fn after_response(&self, res: &InterceptedResponse) -> std::result::Result<(), Veto> {
+                self.calls.lock().unwrap().push(format!("after_response {} {:?}", res.status(), res.annotation("trace_id")));
+
+                Ok(())
+            }
+        }
+
+        // Start HTTP server in echo mode to retrieve HTTP request effectively sent
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let interceptor = Arc::new(TraceInterceptor {
+            calls: std::sync::Mutex::new(Vec::new()),
+        });
+        ctn_client.interceptors.add(interceptor.clone());
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req).await;
+
+        assert!(result.is_ok(), "Returned error from sending request");
+
+        // Parse returned HTTP request from response body
+        let res_body = result.unwrap().into_parts().0.text().await.unwrap();
+        let http_request = HttpRequest::from_json(&res_body).unwrap();
+
+        assert_eq!(http_request.headers.get("x-trace-id").unwrap().value, "a1b2c3d4");
+        assert_eq!(*interceptor.calls.lock().unwrap(), vec![
+            String::from("after_sign signed: true"),
+            String::from("after_response 200 OK Some(\"a1b2c3d4\")"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn it_send_request_vetoed_by_interceptor() {
+        struct ReadOnlyInterceptor;
+
+        impl Interceptor for ReadOnlyInterceptor {
+            fn before_sign(&self, req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+                if req.method() == reqwest::Method::POST {
+                    Err(Veto::new("Write operations not allowed"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host("localhost:1"),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        ctn_client.add_interceptor(ReadOnlyInterceptor);
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message"}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).await.unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::LogMessage, req).await;
+
+        assert!(result.is_err(), "Request not vetoed by interceptor");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_vetoed(), "Returned error is not a veto error");
+        assert_eq!(err.to_string(), "Catenis client error: Request vetoed by interceptor: Write operations not allowed");
+    }
+
+    #[tokio::test]
+    async fn it_report_api_call_metrics() {
+        #[derive(Default)]
+        struct RecordingSink {
+            calls: std::sync::Mutex<Vec<String>>,
+        }
+
+        impl MetricsSink for RecordingSink {
+            fn record_api_call(&self, metrics: &ApiCallMetrics) {
+                self.calls.lock().unwrap().push(format!("{} {:?} {} {}", metrics.api_method, metrics.status, metrics.success, metrics.retries));
+            }
+        }
+
+        // Start HTTP server in error simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Error(
+                400,
+                Some(HttpBody::from_json(r#"{"status":"error","message":"Invalid message ID"}"#).unwrap()),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let sink = Arc::new(RecordingSink::default());
+        ctn_client.set_metrics_sink(sink.clone());
+
+        let result = ctn_client.read_message("oNXszDazhuq4utktSMMi", None).await;
+
+        assert!(result.is_err(), "Returned success from calling API method");
+        assert_eq!(*sink.calls.lock().unwrap(), vec![
+            String::from("read_message Some(400) false 0"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn it_report_api_call_metrics_inconsistent_response() {
+        #[derive(Default)]
+        struct RecordingSink {
+            calls: std::sync::Mutex<Vec<String>>,
+        }
+
+        impl MetricsSink for RecordingSink {
+            fn record_api_call(&self, metrics: &ApiCallMetrics) {
+                self.calls.lock().unwrap().push(format!("{} {:?} {} {}", metrics.api_method, metrics.status, metrics.success, metrics.retries));
+            }
+        }
+
+        // Start HTTP server in success simulation mode, returning an invalid response body
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from(r#"{}"#),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let sink = Arc::new(RecordingSink::default());
+        ctn_client.set_metrics_sink(sink.clone());
+
+        let result = ctn_client.read_message("oNXszDazhuq4utktSMMi", None).await;
+
+        assert!(result.is_err(), "Returned success from calling API method");
+        assert_eq!(*sink.calls.lock().unwrap(), vec![
+            String::from("read_message Some(200) false 0"),
+        ]);
+    }
+
+    #[test]
+    fn it_assemble_get_request() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+        ).unwrap();
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            Some(&[
+                ("encoding", "utf8"),
+            ]),
+        ).unwrap();
+
+        assert_eq!(req.method().to_string(), "GET");
+        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/oNXszDazhuq4utktSMMi?encoding=utf8", DEFAULT_API_VERSION.to_string()));
+    }
+
+    #[tokio::test]
+    async fn it_assemble_post_request() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+        ).unwrap();
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).await.unwrap();
+
+        assert_eq!(req.method().to_string(), "POST");
+        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
+        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
+        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
+        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"{\"message\":\"Test message\",\"options\":{\"encoding\":\"utf8\"}}");
+    }
+
+    #[tokio::test]
+    async fn it_assemble_post_request_with_compression_not_done() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::UseCompression(true),
+                ClientOptions::CompressThreshold(57),
+            ]
+        ).unwrap();
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).await.unwrap();
+
+        assert_eq!(req.method().to_string(), "POST");
+        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
+        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
+        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
+        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"{\"message\":\"Test message\",\"options\":{\"encoding\":\"utf8\"}}");
+    }
+
+    #[tokio::test]
+    async fn it_assemble_post_request_with_compression() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::UseCompression(true),
+                ClientOptions::CompressThreshold(56),
+            ]
+        ).unwrap();
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).await.unwrap();
+
+        assert_eq!(req.method().to_string(), "POST");
+        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
+        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
+        assert_eq!(req.headers().get(CONTENT_ENCODING).unwrap(), "deflate");
+        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"\x78\x9c\x01\x38\x00\xc7\xff\x7b\x22\x6d\x65\x73\x73\x61\x67\x65\x22\x3a\x22\x54\x65\x73\x74\x20\x6d\x65\x73\x73\x61\x67\x65\x22\x2c\x22\x6f\x70\x74\x69\x6f\x6e\x73\x22\x3a\x7b\x22\x65\x6e\x63\x6f\x64\x69\x6e\x67\x22\x3a\x22\x75\x74\x66\x38\x22\x7d\x7d\x2e\x4c\x13\x83");
+    }
+
+    #[tokio::test]
+    async fn it_assemble_post_request_with_gzip_compression() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Compression(CompressionOptions {
+                    request: RequestCompression::Gzip,
+                    ..CompressionOptions::default()
+                }),
+                ClientOptions::CompressThreshold(56),
+            ]
+        ).unwrap();
+        let body = r#"{"message":"Test message","options":{"encoding":"utf8"}}"#;
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(body),
+            None::<KVList>,
+            None::<KVList>,
+        ).await.unwrap();
+
+        assert_eq!(req.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+
+        let mut dec_body = String::new();
+        flate2::read::GzDecoder::new(req.body().unwrap().as_bytes().unwrap()).read_to_string(&mut dec_body).unwrap();
+
+        assert_eq!(dec_body, body);
+
+        // Get same request with request compression turned off for this call only
+        let req = ctn_client.with_request_compression(false).post_request(
+            "messages/log",
+            String::from(body),
+            None::<KVList>,
+            None::<KVList>,
+        ).await.unwrap();
+
+        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
+        assert_eq!(req.body().unwrap().as_bytes().unwrap(), body.as_bytes());
+    }
+
+    #[test]
     fn it_assemble_get_ws_request() {
         // Instantiate Catenis API client
-        let ctn_client = CatenisClient::new(
+        let ctn_client = CatenisClient::new(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+        ).unwrap();
+
+        // Get request used to connect to WebSocket notification channel
+        let (req, _) = ctn_client.get_ws_request(
+            "notify/ws/:event_name",
+            Some(&[
+                ("event_name", "new-msg-received"),
+            ]),
+        ).unwrap();
+
+        assert_eq!(req.method().to_string(), "GET");
+        assert_eq!(req.url().to_string(), format!("wss://catenis.io/api/{}/notify/ws/new-msg-received", DEFAULT_API_VERSION.to_string()));
+    }
+
+    #[tokio::test]
+    async fn it_fail_call_unsupported_in_api_version() {
+        // Instantiate Catenis API client targeting an API version that predates non-fungible assets
+        //  Note: the host is unreachable, so any request actually sent would fail differently
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::BaseUrl("http://localhost:1/"),
+                ClientOptions::Version(ApiVersion(0, 11)),
+            ],
+        ).unwrap();
+
+        assert_eq!(ctn_client.api_version(), ApiVersion(0, 11));
+        assert!(ctn_client.api_version().supports_method(ApiMethod::ExportAsset));
+        assert!(!ctn_client.api_version().supports_method(ApiMethod::RetrieveNonFungibleToken));
+
+        let result = ctn_client.retrieve_non_fungible_token("tDGQpGy627J6uAw4grYq", None).await;
+
+        assert!(result.is_err(), "Called method unsupported in API version");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_unsupported_api_version(), "Returned error is not an unsupported API version error");
+        assert_eq!(err.to_string(), "Catenis client error: Method retrieve_non_fungible_token is unsupported in API version 0.11 (requires API version 0.12 or later); request not sent");
+    }
+
+    #[tokio::test]
+    async fn it_fail_over_to_next_endpoint() {
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "msgInfo": {
+      "action": "log"
+    },
+    "msgData": "Test message"
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("GET")),
+            path: Some(String::from("/gw/api/0.10/messages/oNXszDazhuq4utktSMMi")),
+            headers: None,
+            body: None,
+        });
+        http_server.start();
+
+        let alt_base_url = format!("http://localhost:{}/gw/", http_server.get_port());
+
+        // Instantiate Catenis API client whose primary endpoint is unreachable
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::BaseUrl("http://localhost:1/"),
+                ClientOptions::Version(ApiVersion(0, 10)),
+                ClientOptions::Failover(FailoverOptions {
+                    base_urls: &[&alt_base_url],
+                    ..FailoverOptions::default()
+                }),
+            ],
+        ).unwrap();
+
+        let result: JsonValue = ctn_client.call_api(
+            Method::GET,
+            "messages/:message_id",
+            &[("message_id", "oNXszDazhuq4utktSMMi")],
+            &[],
+            None,
+        ).await.unwrap();
+
+        assert_eq!(result["msgData"], "Test message");
+
+        // Endpoint in use is remembered
+        let (req, _) = ctn_client.get_ws_request(
+            "notify/ws/:event_name",
+            Some(&[
+                ("event_name", "new-msg-received"),
+            ]),
+        ).unwrap();
+
+        assert_eq!(req.url().to_string(), format!("ws://localhost:{}/gw/api/0.10/notify/ws/new-msg-received", http_server.get_port()));
+    }
+
+    #[test]
+    fn it_assemble_requests_with_base_url() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
+            &[
+                ClientOptions::BaseUrl("https://gw.internal/catenis"),
+                ClientOptions::Version(ApiVersion(0, 10)),
+            ],
         ).unwrap();
 
-        // Get request used to connect to WebSocket notification channel
-        let req = ctn_client.get_ws_request(
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        assert_eq!(req.url().to_string(), "https://gw.internal/catenis/api/0.10/messages/oNXszDazhuq4utktSMMi");
+
+        let (req, _) = ctn_client.get_ws_request(
             "notify/ws/:event_name",
             Some(&[
                 ("event_name", "new-msg-received"),
             ]),
         ).unwrap();
 
-        assert_eq!(req.method().to_string(), "GET");
-        assert_eq!(req.url().to_string(), format!("wss://catenis.io/api/{}/notify/ws/new-msg-received", DEFAULT_API_VERSION.to_string()));
+        assert_eq!(req.url().to_string(), "wss://gw.internal/catenis/api/0.10/notify/ws/new-msg-received");
+
+        // Instantiate Catenis API client with insecure base URL
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::BaseUrl("http://gw.internal:8080/catenis/"),
+                ClientOptions::Environment(Environment::Sandbox),
+                ClientOptions::Version(ApiVersion(0, 10)),
+            ],
+        ).unwrap();
+
+        let (req, _) = ctn_client.get_ws_request(
+            "notify/ws/:event_name",
+            Some(&[
+                ("event_name", "new-msg-received"),
+            ]),
+        ).unwrap();
+
+        assert_eq!(req.url().to_string(), "ws://sandbox.gw.internal:8080/catenis/api/0.10/notify/ws/new-msg-received");
     }
 
     #[tokio::test]
@@ -7619,7 +9320,7 @@ mod tests {
         let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7640,7 +9341,7 @@ mod tests {
         ).await.unwrap();
 
         // Sign request
-        ctn_client.sign_request(&mut req).unwrap();
+        ctn_client.sign_request(&mut req).await.unwrap();
 
         assert!(req.headers().contains_key(X_BCOT_TIMESTAMP), "Missing X-Bcot-Timestamp header");
         assert_eq!(req.headers().get(X_BCOT_TIMESTAMP).unwrap(), "20201201T060000Z");
@@ -7648,6 +9349,153 @@ mod tests {
         assert_eq!(req.headers().get(AUTHORIZATION).unwrap(), "CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4");
     }
 
+    #[tokio::test]
+    async fn it_sign_request_rotated_credentials() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let secret = Arc::new(std::sync::Mutex::new(String::from("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3")));
+        let provider_secret = secret.clone();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host("localhost:3000"),
+                ClientOptions::Secure(false),
+                ClientOptions::Version(ApiVersion(0, 10)),
+            ],
+        ).unwrap();
+
+        ctn_client.set_credential_provider(move || -> Result<DeviceCredentials> {
+            Ok(("drc3XdxNtzoucpw9xiRp", provider_secret.lock().unwrap().as_str()).into())
+        }).unwrap();
+
+        let ctn_client_clone = ctn_client.clone();
+
+        // Get request used for calling 'Log Message' API method
+        let mut req = ctn_client_clone.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).await.unwrap();
+        let mut req2 = CatenisClient::clone_request(&req).unwrap();
+
+        // Sign request
+        ctn_client_clone.sign_request(&mut req).await.unwrap();
+
+        assert_eq!(req.headers().get(AUTHORIZATION).unwrap(), "CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4");
+
+        // Rotate credentials, and sign request again from client's clone
+        *secret.lock().unwrap() = String::from("a8e6ab2b7af9fbe0ef13d3a8a6e5e2d1c94e0a9b81b3c5f5c0f4bbdbb2e36f8a92d8c1e6a5c3b7e0f1d2c4b6a8e0f2d4c6b8a0e2f4d6c8b0a2e4f6d8c0b2a4e6");
+        ctn_client.rotate_credentials().unwrap();
+
+        ctn_client_clone.sign_request(&mut req2).await.unwrap();
+
+        assert_ne!(req2.headers().get(AUTHORIZATION), req.headers().get(AUTHORIZATION), "Request not signed with rotated credentials");
+    }
+
+    #[tokio::test]
+    async fn it_fail_rotate_credentials_no_provider() {
+        let ctn_client = CatenisClient::new(None).unwrap();
+
+        let result = ctn_client.rotate_credentials();
+
+        assert!(result.is_err(), "Rotated credentials without a provider");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Missing credential provider");
+    }
+
+    #[tokio::test]
+    async fn it_share_client_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<CatenisClient>();
+
+        // Instantiate Catenis API client
+        let ctn_client = Arc::new(CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host("localhost:3000"),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap());
+
+        let handles: Vec<_> = (0..4).map(|_| {
+            let ctn_client = ctn_client.clone();
+
+            tokio::spawn(async move {
+                let mut req = ctn_client.get_request(
+                    "messages/:message_id",
+                    Some(&[
+                        ("message_id", "oNXszDazhuq4utktSMMi"),
+                    ]),
+                    None::<KVList>,
+                ).unwrap();
+                ctn_client.sign_request(&mut req).await.unwrap();
+
+                req
+            })
+        }).collect();
+
+        for handle in handles {
+            let req = handle.await.unwrap();
+
+            assert!(req.headers().get(AUTHORIZATION).unwrap().to_str().unwrap().starts_with("CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/"), "Request not properly signed");
+        }
+    }
+
+    #[tokio::test]
+    async fn it_redact_client_debug_output() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host("localhost:3000"),
+                ClientOptions::Secure(false),
+                ClientOptions::Proxy(ProxyOptions {
+                    url: "http://proxy.example.com:3128",
+                    credentials: Some(("proxy_user", "proxy_password")),
+                    no_proxy: &["*"],
+                }),
+            ],
+        ).unwrap();
+
+        // Make sure that a signing key is generated
+        let mut req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+        ctn_client.sign_request(&mut req).await.unwrap();
+
+        let signing_key = ctn_client.signing.signing_key().unwrap();
+        let signing_key = signing_key.as_bytes();
+        let debug_output = format!("{:?}", ctn_client);
+
+        assert!(!debug_output.contains("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0"), "API access secret leaked: {}", debug_output);
+        assert!(!debug_output.contains(&format!("{:?}", signing_key)), "Signing key leaked: {}", debug_output);
+        assert!(debug_output.contains("SigningKey([redacted])"), "Unexpected debug output: {}", debug_output);
+        assert!(!debug_output.contains("proxy_user") && !debug_output.contains("proxy_password"), "Proxy credentials leaked: {}", debug_output);
+
+        let debug_output = format!("{:?}", ctn_client.new_ws_notify_channel(NotificationEvent::NewMsgReceived));
+
+        assert!(!debug_output.contains("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0"), "API access secret leaked: {}", debug_output);
+        assert!(!debug_output.contains(&format!("{:?}", signing_key)), "Signing key leaked: {}", debug_output);
+        assert!(!debug_output.contains("proxy_password"), "Proxy credentials leaked: {}", debug_output);
+    }
+
     #[tokio::test]
     async fn it_parse_response() {
         // Simulate successful 'Read Message' API method response
@@ -7722,7 +9570,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Get HTTP client and generate an HTTP request
-        let http_client = CatenisClient::new_http_client(false).unwrap();
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
         let req = http_client.get(&format!("http://localhost:{}/messages", server_port)).build().unwrap();
 
         // Send HTTP request and get the response
@@ -7744,7 +9592,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Get HTTP client and generate an HTTP request
-        let http_client = CatenisClient::new_http_client(true).unwrap();
+        let http_client = CatenisClient::new_http_client(true, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
         let req = http_client.get(&format!("http://localhost:{}/messages", server_port)).build().unwrap();
 
         // Send HTTP request and get the response
@@ -7758,6 +9606,117 @@ mod tests {
         assert_eq!(http_request.headers.get("accept-encoding").unwrap().value, "gzip");
     }
 
+    #[tokio::test]
+    async fn it_get_new_http_client_with_response_encodings() {
+        // Start HTTP server in echo mode to retrieve HTTP request effectively sent
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client and generate an HTTP request
+        let compression = CompressionOptions {
+            accept_deflate: true,
+            accept_brotli: true,
+            ..CompressionOptions::default()
+        };
+        let http_client = CatenisClient::new_http_client(true, &compression, &ConnectionSettings::default()).unwrap();
+        let req = http_client.get(format!("http://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let res = http_client.execute(req).await.unwrap();
+
+        // Parse returned HTTP request from response body
+        let res_body = res.text().await.unwrap();
+        let http_request = HttpRequest::from_json(&res_body).unwrap();
+
+        assert_eq!(http_request.headers.get("accept-encoding").unwrap().value, "gzip, deflate, br");
+    }
+
+    #[tokio::test]
+    async fn it_get_new_http_client_with_proxy() {
+        // Start HTTP server in echo mode to act as proxy and retrieve HTTP request effectively sent
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client and generate an HTTP request
+        let connection_settings = ConnectionSettings {
+            proxy: Some(ProxySettings::from_options(&ProxyOptions {
+                url: &format!("http://localhost:{}", server_port),
+                credentials: Some(("user", "pass")),
+                no_proxy: &[],
+            }).unwrap()),
+            ..ConnectionSettings::default()
+        };
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &connection_settings).unwrap();
+        let req = http_client.get("http://catenis.test/messages").build().unwrap();
+
+        // Send HTTP request and get the response
+        let res = http_client.execute(req).await.unwrap();
+
+        // Parse returned HTTP request from response body
+        let res_body = res.text().await.unwrap();
+        let http_request = HttpRequest::from_json(&res_body).unwrap();
+
+        assert_eq!(http_request.headers.get("host").unwrap().value, "catenis.test");
+        assert_eq!(http_request.headers.get("proxy-authorization").unwrap().value, "Basic dXNlcjpwYXNz");
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    #[tokio::test]
+    async fn it_get_new_http_client_with_root_certificate() {
+        // Start HTTPS server in echo mode
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost").with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client trusting the test CA and generate an HTTP request
+        let mut connection_settings = ConnectionSettings::default();
+        connection_settings.tls.add_root_certificates(TEST_CA_CERT).unwrap();
+
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &connection_settings).unwrap();
+        let req = http_client.get(format!("https://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let result = http_client.execute(req).await;
+
+        assert!(result.is_ok(), "Failed to send request to server with trusted certificate: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn it_get_new_http_client_untrusted_certificate() {
+        // Start HTTPS server in echo mode
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost").with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client not trusting the test CA and generate an HTTP request
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
+        let req = http_client.get(format!("https://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let result = http_client.execute(req).await;
+
+        assert!(result.is_err(), "Sent request to server with untrusted certificate");
+    }
+
+    #[test]
+    fn it_fail_instantiate_client_invalid_root_certificate() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::TlsRootCertificates(b"not a certificate"),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with invalid root certificate");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid root certificates: no PEM encoded certificate found");
+    }
+
     #[tokio::test]
     async fn it_automatically_decompress_response() {
         // Start HTTP server in success simulation mode
@@ -7774,7 +9733,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Get HTTP client and generate an HTTP request
-        let http_client = CatenisClient::new_http_client(true).unwrap();
+        let http_client = CatenisClient::new_http_client(true, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
         let req = http_client.get(&format!("http://localhost:{}/messages", server_port)).build().unwrap();
 
         // Send HTTP request and get the response
@@ -7786,11 +9745,43 @@ mod tests {
         assert_eq!(res_body.as_ref(), body.as_bytes());
     }
 
+    #[tokio::test]
+    async fn it_automatically_decompress_deflate_response() {
+        // Start HTTP server in success simulation mode
+        let body = r#"{"status":"success","data":{"messageId":"mg9x9vCqYMg9YtKdDwQx"}}"#;
+
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(body).unwrap().with_content_encoding(ContentEncoding::Deflate),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client and generate an HTTP request
+        let compression = CompressionOptions {
+            accept_deflate: true,
+            ..CompressionOptions::default()
+        };
+        let http_client = CatenisClient::new_http_client(true, &compression, &ConnectionSettings::default()).unwrap();
+        let req = http_client.get(format!("http://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let res = http_client.execute(req).await.unwrap();
+
+        // Read response body
+        let res_body = res.bytes().await.unwrap();
+
+        assert_eq!(res_body.as_ref(), body.as_bytes());
+    }
+
     #[tokio::test]
     async fn it_compress_body() {
         let body = String::from("This is only a test");
 
-        let compressed_body = CatenisClient::compress_body(body).await.unwrap();
+        let compressed_body = CatenisClient::compress_body(body, RequestCompression::Deflate).await.unwrap();
 
         assert_eq!(compressed_body.as_slice(), b"\x78\x9c\x0b\xc9\xc8\x2c\x56\x00\xa2\xfc\xbc\x9c\x4a\x85\x44\x85\x92\xd4\xe2\x12\x00\x43\x81\x06\xd8");
     }
@@ -7842,7 +9833,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7945,7 +9936,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8036,7 +10027,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8117,7 +10108,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8206,7 +10197,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8284,7 +10275,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8355,7 +10346,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8423,7 +10414,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8489,7 +10480,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8558,7 +10549,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -0,0 +1,273 @@
+use std::{
+    future::Future,
+    time::Duration,
+};
+use tokio::{
+    io::{
+        AsyncReadExt, AsyncWriteExt,
+    },
+    net::TcpStream,
+    time::Instant,
+};
+use tokio_tungstenite::{
+    self,
+    tungstenite::{
+        client::uri_mode,
+        handshake::{
+            client::Request as WsRequest,
+        },
+        stream::Mode,
+    },
+    MaybeTlsStream, WebSocketStream,
+};
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+use tokio_tungstenite::Connector;
+
+use crate::{
+    Error, Result,
+    error::GenericError,
+    connection::ConnectionSettings,
+    proxy::{
+        self,
+        ProxySettings,
+    },
+};
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+use crate::pinning::PinCheck;
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+use crate::tls;
+
+/// Establish an asynchronous WebSocket connection honoring the connect and handshake timeouts.
+pub(crate) async fn connect_ws(req: WsRequest, settings: &ConnectionSettings) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+    let mode = uri_mode(req.uri())?;
+    let host = String::from(req.uri().host()
+        .ok_or_else(|| Error::new_client_error(Some("Inconsistent WebSocket URL: missing host"), None::<GenericError>))?);
+    let port = req.uri().port_u16().unwrap_or(match mode {
+        Mode::Plain => 80,
+        Mode::Tls => 443,
+    });
+
+    let proxy = settings.proxy.as_ref().filter(|proxy| !proxy.bypass(&host));
+
+    let mut stream = with_timeout(
+        settings.connect_timeout,
+        if let Some(proxy) = proxy {
+            TcpStream::connect((proxy.host(), proxy.port()))
+        } else {
+            TcpStream::connect((host.as_str(), port))
+        },
+        "Timed out connecting to WebSocket server"
+    ).await?
+        .map_err(|err| Error::new_client_error(Some("Failed to establish WebSocket connection"), Some(err)))?;
+
+    // Limit the overall time that the handshake (including opening a tunnel through the proxy and
+    //  the TLS handshake) may take
+    let deadline = settings.ws_handshake_timeout.map(|timeout| Instant::now() + timeout);
+
+    if let Some(proxy) = proxy {
+        with_deadline(
+            deadline,
+            tunnel(proxy, &mut stream, &host, port),
+            "Timed out opening tunnel through proxy"
+        ).await??;
+    }
+
+    with_deadline(
+        deadline,
+        ws_handshake(req, stream, settings),
+        "Timed out performing WebSocket handshake"
+    ).await?
+}
+
+/// Perform the WebSocket handshake, after setting up TLS if required.
+///
+/// > **Note**: the pinned public keys, if any, are checked during the TLS handshake, so the
+/// > WebSocket handshake request is never sent to a server that does not match the pins.
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+async fn ws_handshake(req: WsRequest, stream: TcpStream, settings: &ConnectionSettings) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+    let pin_check = PinCheck::default();
+    let (ws, _) = tokio_tungstenite::client_async_tls_with_config(req, stream, None, Some(ws_connector(settings, &pin_check)?)).await
+        .map_err(|err| if pin_check.is_mismatch() {
+            Error::new_pin_mismatch_error(None)
+        } else {
+            Error::new_client_error(Some("Failed to establish WebSocket connection"), Some(err))
+        })?;
+
+    Ok(ws)
+}
+
+/// Perform the WebSocket handshake.
+///
+/// > **Note**: since no TLS backend has been enabled, only plain connections are supported.
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+async fn ws_handshake(req: WsRequest, stream: TcpStream, _settings: &ConnectionSettings) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+    if let Mode::Tls = uri_mode(req.uri())? {
+        return Err(tls::tls_not_enabled_error());
+    }
+
+    let (ws, _) = tokio_tungstenite::client_async_with_config(req, MaybeTlsStream::Plain(stream), None).await
+        .map_err(|err| Error::new_client_error(Some("Failed to establish WebSocket connection"), Some(err)))?;
+
+    Ok(ws)
+}
+
+/// The connector used to set up TLS for WebSocket connections. A pin mismatch is recorded into
+/// the given pin check.
+#[cfg(feature = "rustls")]
+fn ws_connector(settings: &ConnectionSettings, pin_check: &PinCheck) -> Result<Connector> {
+    Ok(Connector::Rustls(settings.tls.rustls_config(settings.pinned_keys.as_ref(), pin_check)?))
+}
+
+/// The connector used to set up TLS for WebSocket connections.
+///
+/// > **Note**: public keys cannot be pinned without the `rustls` feature, so no pins are ever
+/// > set here.
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+fn ws_connector(settings: &ConnectionSettings, _pin_check: &PinCheck) -> Result<Connector> {
+    Ok(Connector::NativeTls(settings.tls.native_tls_connector()?))
+}
+
+/// Open a tunnel to the given target over an already established connection with the proxy.
+async fn tunnel(proxy: &ProxySettings, stream: &mut TcpStream, host: &str, port: u16) -> Result<()> {
+    stream.write_all(proxy.connect_request(host, port).as_bytes()).await
+        .map_err(proxy::tunnel_io_error)?;
+
+    // Read response header one byte at a time so nothing past it is consumed
+    let mut res = Vec::new();
+    let mut byte = [0_u8];
+
+    while !proxy::is_connect_response_complete(&res) {
+        if stream.read(&mut byte).await.map_err(proxy::tunnel_io_error)? == 0 {
+            return Err(Error::new_client_error(Some("Proxy closed connection unexpectedly"), None::<GenericError>));
+        }
+
+        res.push(byte[0]);
+    }
+
+    proxy::check_connect_response(&res)
+}
+
+/// Await a future, failing with a timeout error if it does not complete within the given time.
+pub(crate) async fn with_timeout<F: Future>(timeout: Option<Duration>, fut: F, message: &str) -> Result<F::Output> {
+    if let Some(timeout) = timeout {
+        tokio::time::timeout(timeout, fut).await
+            .map_err(|err| Error::new_timeout_error(Some(message), Some(err)))
+    } else {
+        Ok(fut.await)
+    }
+}
+
+/// Await a future, failing with a timeout error if it does not complete by the given deadline.
+pub(crate) async fn with_deadline<F: Future>(deadline: Option<Instant>, fut: F, message: &str) -> Result<F::Output> {
+    if let Some(deadline) = deadline {
+        tokio::time::timeout_at(deadline, fut).await
+            .map_err(|err| Error::new_timeout_error(Some(message), Some(err)))
+    } else {
+        Ok(fut.await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rustls")]
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+    #[cfg(feature = "rustls")]
+    use crate::{
+        pinning::PinSet,
+        test_helper::http_server::{
+            HttpServer, HttpServerMode, TEST_CA_CERT,
+        },
+    };
+    use super::*;
+
+    #[tokio::test]
+    async fn it_open_tunnel() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let server = std::thread::spawn(move || {
+            use std::io::{Read, Write};
+
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut req = Vec::new();
+            let mut byte = [0_u8];
+
+            while !req.ends_with(b"\r\n\r\n") {
+                stream.read_exact(&mut byte).unwrap();
+                req.push(byte[0]);
+            }
+
+            stream.write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n").unwrap();
+
+            String::from_utf8(req).unwrap()
+        });
+
+        let proxy = ProxySettings::from_options(&crate::ProxyOptions {
+            url: "http://proxy.example.com:3128",
+            credentials: None,
+            no_proxy: &[],
+        }).unwrap();
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+
+        let result = tunnel(&proxy, &mut stream, "catenis.io", 443).await;
+
+        assert!(result.is_err(), "Opened tunnel when proxy refused it");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Proxy refused to open tunnel: HTTP/1.1 407 Proxy Authentication Required");
+        assert_eq!(server.join().unwrap(), "CONNECT catenis.io:443 HTTP/1.1\r\nHost: catenis.io:443\r\n\r\n");
+    }
+
+    #[cfg(feature = "rustls")]
+    #[tokio::test]
+    async fn it_reject_ws_pin_mismatch() {
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost").with_tls();
+        http_server.start();
+
+        let req = format!("wss://localhost:{}/", http_server.get_port()).into_client_request().unwrap();
+        let mut settings = ConnectionSettings {
+            pinned_keys: Some(PinSet::from_pins(&["47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="]).unwrap()),
+            ..ConnectionSettings::default()
+        };
+        settings.tls.add_root_certificates(TEST_CA_CERT).unwrap();
+
+        let result = connect_ws(req, &settings).await;
+
+        assert!(result.is_err(), "WebSocket connection succeeded when server certificate does not match pins");
+        assert!(result.err().unwrap().is_pin_mismatch(), "Returned error is not a pin mismatch error");
+    }
+
+    #[tokio::test]
+    async fn it_complete_within_timeout() {
+        let result = with_timeout(Some(Duration::from_secs(5)), async { 10 }, "Timed out").await;
+
+        assert_eq!(result.unwrap(), 10);
+    }
+
+    #[tokio::test]
+    async fn it_fail_with_timeout() {
+        let result = with_timeout(
+            Some(Duration::from_millis(10)),
+            tokio::time::sleep(Duration::from_secs(5)),
+            "Sample timeout description"
+        ).await;
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_timeout());
+        assert_eq!(err.to_string(), "Catenis client error: Sample timeout description: deadline has elapsed");
+    }
+
+    #[tokio::test]
+    async fn it_fail_past_deadline() {
+        let deadline = Some(Instant::now() + Duration::from_millis(100));
+
+        let result = with_deadline(deadline, tokio::time::sleep(Duration::from_millis(60)), "Sample timeout description").await;
+
+        assert!(result.is_ok(), "Timed out before deadline");
+
+        // Note: the time already spent counts towards the deadline
+        let result = with_deadline(deadline, tokio::time::sleep(Duration::from_millis(60)), "Sample timeout description").await;
+
+        assert!(result.is_err(), "Completed past deadline");
+        assert!(result.err().unwrap().is_timeout(), "Returned error is not a timeout error");
+    }
+}
@@ -1,7 +1,12 @@
+mod builder;
 mod client;
+mod connection;
 mod error;
+mod pool;
 pub mod notification;
 
+pub use builder::CatenisClientBuilder;
 pub use client::CatenisClient;
+pub use pool::DeviceClientPool;
 #[doc(no_inline)]
 pub use notification::WsNotifyChannel;
\ No newline at end of file
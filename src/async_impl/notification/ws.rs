@@ -39,11 +39,13 @@ use crate::{
     api::{
         NotificationEvent,
     },
-    Result, Error, X_BCOT_TIMESTAMP,
+    Result, Error, NotifyChannelActivity, X_BCOT_TIMESTAMP,
     error::GenericError,
+    metrics::NotifyChannelMetrics,
     notification::*,
     async_impl::{
         client::CatenisClient,
+        connection,
     }
 };
 
@@ -149,54 +151,73 @@ impl WsNotifyChannel {
         where
             F: Fn(WsNotifyChannelEvent) + Send + 'static
     {
-        // Prepare to connect to Catenis WebSocket notification service
-        //  Note: this request is only used to assemble the URL for the notification service
-        //      and generate the required data for authentication with the notification service.
-        //      The actual request used to open a WebSocket connection is created below
-        //      (from this request's URL).
-        let mut auth_req = self.api_client.get_ws_request(
-            "notify/ws/:event_name",
-            Some(&[("event_name", self.event.to_string().as_str())])
-        )?;
-
-        self.api_client.sign_request(&mut auth_req)?;
-
-        let ws_notify_auth_msg_json = serde_json::to_string(
-            &WsNotifyChannelAuthentication {
-                x_bcot_timestamp: auth_req.headers()
-                    .get(X_BCOT_TIMESTAMP)
-                    .unwrap_or(&HeaderValue::from_static(""))
-                    .to_str()?
-                    .into(),
-                authorization: auth_req.headers()
-                    .get(AUTHORIZATION)
-                    .unwrap_or(&HeaderValue::from_static(""))
-                    .to_str()?
-                    .into()
+        // Note: fail over at most once per alternative endpoint
+        let mut failovers = 0;
+
+        let (ws_notify_auth_msg_json, mut ws) = loop {
+            // Prepare to connect to Catenis WebSocket notification service
+            //  Note: this request is only used to assemble the URL for the notification service
+            //      and generate the required data for authentication with the notification service.
+            //      The actual request used to open a WebSocket connection is created below
+            //      (from this request's URL).
+            let (mut auth_req, endpoint) = self.api_client.get_ws_request(
+                "notify/ws/:event_name",
+                Some(&[("event_name", self.event.to_string().as_str())])
+            )?;
+
+            self.api_client.sign_request(&mut auth_req).await?;
+
+            let ws_notify_auth_msg_json = serde_json::to_string(
+                &WsNotifyChannelAuthentication {
+                    x_bcot_timestamp: auth_req.headers()
+                        .get(X_BCOT_TIMESTAMP)
+                        .unwrap_or(&HeaderValue::from_static(""))
+                        .to_str()?
+                        .into(),
+                    authorization: auth_req.headers()
+                        .get(AUTHORIZATION)
+                        .unwrap_or(&HeaderValue::from_static(""))
+                        .to_str()?
+                        .into()
+                }
+            )?;
+
+            // Create request to open WebSocket connection
+            let mut req = auth_req.url().as_str().into_client_request()?;
+
+            // Add HTTP header specifying the expected WebSocket subprotocol
+            req.headers_mut().insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static(NOTIFY_WS_PROTOCOL));
+
+            // Try to establish WebSocket connection
+            match connection::connect_ws(req, &self.api_client.connection_settings).await {
+                Ok(ws) => {
+                    trace_event!(debug, url = %auth_req.url(), "WebSocket notification channel connection established");
+
+                    break (ws_notify_auth_msg_json, ws);
+                },
+                Err(err) => {
+                    if failovers + 1 < self.api_client.endpoints.len() && !err.is_pin_mismatch()
+                            && self.api_client.endpoints.fail_over(endpoint) {
+                        // Endpoint in use could not be reached. Try again with the next one
+                        failovers += 1;
+                    } else {
+                        return Err(err);
+                    }
+                }
             }
-        )?;
-
-        // Create request to open WebSocket connection
-        let mut req = auth_req.url().as_str().into_client_request()?;
-
-        // Add HTTP header specifying the expected WebSocket subprotocol
-        req.headers_mut().insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static(NOTIFY_WS_PROTOCOL));
-
-        // Try to establish WebSocket connection
-        let (mut ws, _) = tokio_tungstenite::connect_async(req)
-            .await
-            .map_err(|err| Error::new_client_error(
-                Some("Failed to establish WebSocket connection"),
-                Some(err)
-            ))?;
+        };
 
         // Prepare to async task to run WebSocket connection
         let (tx, mut rx) = mpsc::channel(128);
 
         // Save communication channel with WebSocket async task
-        self.tx = Some(tx);
+        let is_reopen = self.tx.replace(tx).is_some();
+
+        // Prepare to report metrics from WebSocket async task
+        //  Note: the closing of the notification channel is reported once the async task ends
+        let channel_metrics = NotifyChannelMetrics::new(self.api_client.metrics.clone(), self.event.clone());
 
-        Ok(tokio::spawn(async move {
+        let ws_task = async move {
             // Create notification event handler async task
             let (h_tx, mut h_rx) = mpsc::channel(1024);
 
@@ -251,6 +272,8 @@ impl WsNotifyChannel {
                 return;
             }
 
+            trace_event!(debug, "WebSocket notification channel authentication message sent");
+
             loop {
                 // Receive data from WebSocket connection
                 match tokio::time::timeout(std::time::Duration::from_millis(500),ws.next()).await {
@@ -263,6 +286,13 @@ impl WsNotifyChannel {
                                             Message::Text(text) => {
                                                 // A text message was received
                                                 if text == NOTIFY_WS_CHANNEL_OPEN {
+                                                    trace_event!(info, "WebSocket notification channel open");
+                                                    channel_metrics.record(if is_reopen {
+                                                        NotifyChannelActivity::Reopen
+                                                    } else {
+                                                        NotifyChannelActivity::Open
+                                                    });
+
                                                     // WebSocket notification channel open and ready to send
                                                     //  notification. Send open message to notification event
                                                     //  handler async task
@@ -275,6 +305,8 @@ impl WsNotifyChannel {
                                                     // Parse received message
                                                     match serde_json::from_str(text.as_str()) {
                                                         Ok(notify_message) => {
+                                                            channel_metrics.record(NotifyChannelActivity::Notification);
+
                                                             // Send notify message to notification event handler
                                                             //  async task
                                                             h_tx.send(
@@ -284,6 +316,8 @@ impl WsNotifyChannel {
                                                             ).await.unwrap_or(());
                                                         },
                                                         Err(_) => {
+                                                            trace_event!(warn, code = 4000, "Closing WebSocket notification channel: unexpected notification message received");
+
                                                             // Unexpected notification message. Force closing of
                                                             //  WebSocket notification channel reporting error
                                                             //  condition
@@ -320,6 +354,8 @@ impl WsNotifyChannel {
                                                 }
                                             },
                                             Message::Binary(bin) => {
+                                                trace_event!(warn, code = u16::from(CloseCode::Unsupported), "Closing WebSocket notification channel: unexpected binary message received");
+
                                                 // A binary message was received. This is unexpected, so
                                                 //  force closing of WebSocket notification channel reporting
                                                 //  the error condition
@@ -354,6 +390,8 @@ impl WsNotifyChannel {
                                             },
                                             Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => (),
                                             Message::Close(close_info) => {
+                                                trace_event!(debug, code = ?close_info.as_ref().map(|frame| u16::from(frame.code)), "WebSocket notification channel close frame received");
+
                                                 // WebSocket connection is being closed. Send close message
                                                 //  to notification event handler async task...
                                                 h_tx.send(
@@ -371,7 +409,10 @@ impl WsNotifyChannel {
                                     Err(err) => {
                                         if let tungstenite::error::Error::ConnectionClosed = err {
                                             // WebSocket connection has been closed
+                                            trace_event!(debug, "WebSocket notification channel connection closed");
                                         } else {
+                                            trace_event!(warn, error = %err, "WebSocket notification channel connection failed");
+
                                             // Any other error. Send error message to notification event
                                             //  handler async task
                                             h_tx.send(
@@ -393,6 +434,8 @@ impl WsNotifyChannel {
                                 }
                             },
                             None => {
+                                trace_event!(debug, "WebSocket notification channel connection closed");
+
                                 // Assume that WebSocket connection has been closed, and
                                 //  just exit current async task (requesting child async task to exit too)
                                 h_tx.send(NotifyEventHandlerMessage::Drop).await.unwrap_or(());
@@ -416,6 +459,8 @@ impl WsNotifyChannel {
                                 return;
                             },
                             WsNotifyChannelCommand::Close => {
+                                trace_event!(debug, code = u16::from(CloseCode::Normal), "Closing WebSocket notification channel");
+
                                 // Close WebSocket connection
                                 if let Err(err) = ws.close(Some(CloseFrame {
                                     code: CloseCode::Normal,
@@ -463,7 +508,15 @@ impl WsNotifyChannel {
                     },
                 }
             }
-        }))
+        };
+
+        #[cfg(feature = "tracing")]
+        let ws_task = tracing::Instrument::instrument(
+            ws_task,
+            tracing::info_span!("ws_notify_channel", event = %self.event.to_string())
+        );
+
+        Ok(tokio::spawn(ws_task))
     }
 
     /// Close the WebSocket notification channel.
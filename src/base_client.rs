@@ -1,8 +1,11 @@
 use std::{
     borrow::Borrow,
+    fmt::{
+        self, Debug, Formatter,
+    },
 };
 use bitcoin_hashes::{
-    Hash, HashEngine, Hmac,
+    Hash, HashEngine, hex::ToHex, Hmac,
     HmacEngine,
     sha256,
 };
@@ -12,23 +15,89 @@ use reqwest::{
 use time::{
     Date, OffsetDateTime, Duration,
 };
+use zeroize::Zeroizing;
 
-use crate::Result;
+use crate::{
+    Error, Result, X_BCOT_TIMESTAMP,
+    error::GenericError,
+};
 
-const SIGNATURE_VALIDITY_DAYS: u8 = 7;
-const TIME_VARIATION_SECS: u8 = 5;
+pub(crate) const SIGNATURE_VALIDITY_DAYS: u8 = 7;
+pub(crate) const TIME_VARIATION_SECS: u8 = 5;
 
-pub(crate) trait BaseCatenisClient {
+/// Key used for signing requests. Its contents are zeroed when it is dropped, and redacted when
+/// it is debug formatted.
+#[derive(Clone, Eq, PartialEq)]
+pub(crate) struct SigningKey(Zeroizing<[u8; 32]>);
+
+impl SigningKey {
+    pub(crate) fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Debug for SigningKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "SigningKey([redacted])")
+    }
+}
+
+/// Derive the key used to sign requests on a given date from a device's API access secret.
+pub(crate) fn derive_signing_key(api_access_secret: &str, sign_date: Date) -> SigningKey {
+    let inner_key = Zeroizing::new(String::from("CTN1") + api_access_secret);
+    let mut hmac_engine = HmacEngine::<sha256::Hash>::new(inner_key.as_bytes());
+    hmac_engine.input(sign_date.format("%Y%m%d").as_bytes());
+    let date_key = Zeroizing::new(Hmac::<sha256::Hash>::from_engine(hmac_engine).into_inner());
+
+    let mut hmac_engine = HmacEngine::<sha256::Hash>::new(&date_key[..]);
+    hmac_engine.input(b"ctn1_request");
+
+    SigningKey(Zeroizing::new(Hmac::<sha256::Hash>::from_engine(hmac_engine).into_inner()))
+}
+
+/// Compute the CTN1-HMAC-SHA256 signature (as a hex string) of an HTTP request.
+pub(crate) fn compute_signature(signing_key: &[u8; 32], scope: &str, timestamp: &str, method: &str, path_with_query: &str, host: &str, payload: &[u8]) -> String {
+    // 1. Assemble conformed request
+
+    // 1.1. Add HTTP verb
+    let mut conformed_request: String = String::from(method) + "\n";
+
+    // 1.2. Add URL path
+    conformed_request = conformed_request + path_with_query + "\n";
+
+    // 1.3. Assemble and add essential headers
+    conformed_request = conformed_request + "host:" + host + "\n"
+        + X_BCOT_TIMESTAMP + ":" + timestamp + "\n\n";
+
+    // 1.4. Hash HTTP request payload and add it
+    conformed_request = conformed_request + &sha256::Hash::hash(payload).to_hex() + "\n";
+
+    // 2. Assemble string to sign
+    let string_to_sign = String::from("CTN1-HMAC-SHA256\n") + timestamp + "\n"
+        + scope + "\n"
+        + &sha256::Hash::hash(conformed_request.as_bytes()).to_hex() + "\n";
+
+    // 3. Generate signature
+    let mut hmac_engine = HmacEngine::<sha256::Hash>::new(signing_key);
+    hmac_engine.input(string_to_sign.as_bytes());
+
+    Hmac::<sha256::Hash>::from_engine(hmac_engine).to_hex()
+}
+
+/// Holder of the virtual device credentials, and of the signing key derived from them, used to
+/// sign requests.
+pub(crate) trait SignRequest {
     fn get_device_id_ref(&self) -> Result<&String>;
-    fn get_api_access_secret_ref(&self) -> Result<&String>;
+    fn get_api_access_secret_ref(&self) -> Result<&str>;
     fn get_sign_date_ref(&self) -> &Option<Date>;
     fn get_sign_date_mut_ref(&mut self) -> &mut Option<Date>;
-    fn get_signing_key_mut_ref(&mut self) -> &mut Option<[u8; 32]>;
+    fn get_signing_key_ref(&self) -> &Option<SigningKey>;
+    fn get_signing_key_mut_ref(&mut self) -> &mut Option<SigningKey>;
 
-    fn check_update_sign_date_and_key(&mut self, now: &OffsetDateTime) -> Result<()> {
+    fn needs_new_signing_key(&self, now: &OffsetDateTime) -> bool {
         let sign_date = self.get_sign_date_ref();
 
-        let need_to_update = if let None = sign_date {
+        if sign_date.is_none() {
             true
         } else {
             let lower_bound_sign_date = (now.clone() + Duration::seconds(TIME_VARIATION_SECS as i64)).date() - Duration::days(SIGNATURE_VALIDITY_DAYS as i64);
@@ -38,26 +107,46 @@ pub(crate) trait BaseCatenisClient {
             } else {
                 false
             }
-        };
-
-        if need_to_update {
-            *self.get_sign_date_mut_ref() = Some(now.date());
+        }
+    }
 
+    fn check_update_sign_date_and_key(&mut self, now: &OffsetDateTime) -> Result<()> {
+        if self.needs_new_signing_key(now) {
             // Generate new signing key
-            let inner_key = String::from("CTN1") + self.get_api_access_secret_ref()?.as_str();
-            let mut hmac_engine = HmacEngine::<sha256::Hash>::new(inner_key.as_bytes());
-            hmac_engine.input(self.get_sign_date_ref().unwrap().format("%Y%m%d").as_bytes());
-            let date_key = &Hmac::<sha256::Hash>::from_engine(hmac_engine)[..];
-
-            let mut hmac_engine = HmacEngine::<sha256::Hash>::new(date_key);
-            hmac_engine.input(b"ctn1_request");
+            let signing_key = derive_signing_key(self.get_api_access_secret_ref()?, now.date());
 
-            *self.get_signing_key_mut_ref() = Some(*Hmac::<sha256::Hash>::from_engine(hmac_engine).as_inner());
+            *self.get_sign_date_mut_ref() = Some(now.date());
+            *self.get_signing_key_mut_ref() = Some(signing_key);
         }
 
         Ok(())
     }
 
+    /// Compute the CTN1-HMAC-SHA256 signature of an HTTP request, returning the values of the
+    /// `x-bcot-timestamp` and `authorization` HTTP headers that should be added to it.
+    fn sign_request_parts(&mut self, method: &str, path_with_query: &str, host: &str, payload: &[u8], now: &OffsetDateTime) -> Result<(String, String)> {
+        // Update sign date and signing key
+        self.check_update_sign_date_and_key(now)?;
+
+        self.sign_request_parts_current_key(method, path_with_query, host, payload, now)
+    }
+
+    /// Same as `sign_request_parts()`, but using the current signing key, which must be valid.
+    fn sign_request_parts_current_key(&self, method: &str, path_with_query: &str, host: &str, payload: &[u8], now: &OffsetDateTime) -> Result<(String, String)> {
+        let timestamp = now.format("%Y%m%dT%H%M%SZ");
+
+        let scope = self.get_sign_date_ref().unwrap().format("%Y%m%d") + "/ctn1_request";
+        let signature = compute_signature(self.get_signing_key_ref().as_ref().unwrap().as_bytes(), &scope, &timestamp, method, path_with_query, host, payload);
+
+        // Assemble 'authorization' header
+        let authorization = String::from("CTN1-HMAC-SHA256 Credential=") + self.get_device_id_ref()?.as_str() + "/"
+            + &scope + ",Signature=" + &signature;
+
+        Ok((timestamp, authorization))
+    }
+}
+
+pub(crate) trait BaseCatenisClient {
     fn merge_url_params<I, K, V>(url_path: &str, params: I) -> String
         where
             I: IntoIterator,
@@ -95,6 +184,24 @@ pub(crate) trait BaseCatenisClient {
         query_params_list
     }
 
+    fn parse_base_url(base_url: &str) -> Result<Url> {
+        let invalid_url = || Error::new_client_error(Some("Invalid base URL"), None::<GenericError>);
+        let mut url = Url::parse(base_url).map_err(|_| invalid_url())?;
+
+        if !(url.scheme() == "https" || url.scheme() == "http") || url.host_str().is_none()
+                || url.query().is_some() || url.fragment().is_some() {
+            return Err(invalid_url());
+        }
+
+        if !url.path().ends_with('/') {
+            // Make sure that path prefix is kept when joining the API path
+            let path = String::from(url.path()) + "/";
+            url.set_path(&path);
+        }
+
+        Ok(url)
+    }
+
     fn parse_host_with_port(host: &str) -> (Option<String>, Option<u16>) {
         if let Ok(url) = Url::parse(&(String::from("http://") + host)) {
             let host = if let Some(val) = url.host_str() {
@@ -145,15 +252,15 @@ mod tests {
         pub device_id: String,
         pub api_access_secret: String,
         pub sign_date: Option<Date>,
-        pub signing_key: Option<[u8; 32]>,
+        pub signing_key: Option<SigningKey>,
     }
 
-    impl BaseCatenisClient for TestSt {
+    impl SignRequest for TestSt {
         fn get_device_id_ref(&self) -> Result<&String> {
             Ok(&self.device_id)
         }
 
-        fn get_api_access_secret_ref(&self) -> Result<&String> {
+        fn get_api_access_secret_ref(&self) -> Result<&str> {
             Ok(&self.api_access_secret)
         }
 
@@ -165,18 +272,24 @@ mod tests {
             &mut self.sign_date
         }
 
-        fn get_signing_key_mut_ref(&mut self) -> &mut Option<[u8; 32]> {
+        fn get_signing_key_ref(&self) -> &Option<SigningKey> {
+            &self.signing_key
+        }
+
+        fn get_signing_key_mut_ref(&mut self) -> &mut Option<SigningKey> {
             &mut self.signing_key
         }
     }
 
+    impl BaseCatenisClient for TestSt {}
+
     #[test]
     fn it_access_fields() {
         let mut st = TestSt {
             device_id: String::from("dxyz"),
             api_access_secret: String::from("ABCDEFG"),
             sign_date: Some(time::date!(2020-12-11)),
-            signing_key: Some([1_u8; 32]),
+            signing_key: Some(SigningKey(Zeroizing::new([1_u8; 32]))),
         };
 
         assert_eq!(*st.get_device_id_ref().unwrap(), st.device_id);
@@ -189,9 +302,9 @@ mod tests {
         assert_eq!(st.sign_date, Some(time::date!(2020-12-25)));
 
         // Update signing key
-        *st.get_signing_key_mut_ref() = Some([2_u8; 32]);
+        *st.get_signing_key_mut_ref() = Some(SigningKey(Zeroizing::new([2_u8; 32])));
 
-        assert_eq!(st.signing_key, Some([2_u8; 32]));
+        assert_eq!(st.signing_key, Some(SigningKey(Zeroizing::new([2_u8; 32]))));
     }
 
     #[test]
@@ -217,7 +330,7 @@ mod tests {
             device_id: String::from("dxyz"),
             api_access_secret: String::from("ABCDEFG"),
             sign_date: Some(time::date!(2020-12-05)),
-            signing_key: Some([1_u8; 32]),
+            signing_key: Some(SigningKey(Zeroizing::new([1_u8; 32]))),
         };
         let orig_st = st.clone();
 
@@ -235,7 +348,7 @@ mod tests {
             device_id: String::from("dxyz"),
             api_access_secret: String::from("ABCDEFG"),
             sign_date: Some(time::date!(2020-12-04)),
-            signing_key: Some([1_u8; 32]),
+            signing_key: Some(SigningKey(Zeroizing::new([1_u8; 32]))),
         };
         let orig_st = st.clone();
 
@@ -269,6 +382,28 @@ mod tests {
         assert_eq!(query_params, vec![(String::from("par1"), String::from("val1")),(String::from("par2"), String::from("val2"))]);
     }
 
+    #[test]
+    fn it_parse_base_url() {
+        let url = TestSt::parse_base_url("https://gw.internal/catenis").unwrap();
+
+        assert_eq!(url.as_str(), "https://gw.internal/catenis/");
+        assert_eq!(url.join("api/0.12/").unwrap().as_str(), "https://gw.internal/catenis/api/0.12/");
+
+        let url = TestSt::parse_base_url("http://localhost:3000/").unwrap();
+
+        assert_eq!(url.as_str(), "http://localhost:3000/");
+    }
+
+    #[test]
+    fn it_parse_invalid_base_url() {
+        for base_url in &["gw.internal/catenis/", "ftp://gw.internal/catenis/", "https://gw.internal/catenis/?a=1"] {
+            let result = TestSt::parse_base_url(base_url);
+
+            assert!(result.is_err(), "Parsed invalid base URL: {}", base_url);
+            assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid base URL");
+        }
+    }
+
     #[test]
     fn it_parse_invalid_host_with_port() {
         let parsed_host = TestSt::parse_host_with_port("");
@@ -0,0 +1,409 @@
+use std::{
+    marker::PhantomData,
+    time::Duration,
+};
+
+use crate::{
+    ApiVersion, CatenisClient, CircuitBreakerPolicy, ClientIdentity, ClientOptions, CompressionOptions,
+    DeviceCredentials, Environment, Error, FailoverOptions, ProxyOptions, RateLimit, Result, RetryPolicy,
+    error::GenericError,
+};
+
+/// Builder of a Catenis API client object.
+///
+/// Unlike the option settings passed to `new_with_options()`, whose outcome may depend on the
+/// order in which they are listed, the settings of a builder are resolved in a fixed way: in
+/// particular, the environment is always applied to the configured host (or the default one),
+/// no matter the order in which the setters are called. Conflicting settings make
+/// [`build()`](CatenisClientBuilder::build) fail.
+///
+/// Use the `builder()` method of the client to instantiate it.
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     CatenisClient, Environment, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let ctn_client = CatenisClient::builder()
+///     .device_credentials((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into())
+///     .environment(Environment::Sandbox)
+///     .build()?;
+///
+/// assert_eq!(ctn_client.base_api_url(), "https://sandbox.catenis.io/api/0.12/");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CatenisClientBuilder<'a, C = CatenisClient> {
+    device_credentials: Option<DeviceCredentials>,
+    base_url: Option<&'a str>,
+    host: Option<&'a str>,
+    environment: Option<Environment>,
+    secure: Option<bool>,
+    version: Option<ApiVersion>,
+    use_compression: Option<bool>,
+    compression: Option<CompressionOptions>,
+    compress_threshold: Option<usize>,
+    retry: Option<RetryPolicy>,
+    rate_limit: Option<RateLimit>,
+    circuit_breaker: Option<CircuitBreakerPolicy>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    ws_handshake_timeout: Option<Duration>,
+    proxy: Option<ProxyOptions<'a>>,
+    tls_root_certificates: Vec<&'a [u8]>,
+    tls_built_in_roots: Option<bool>,
+    tls_client_identity: Option<ClientIdentity<'a>>,
+    pinned_public_keys: Option<&'a [&'a str]>,
+    failover: Option<FailoverOptions<'a>>,
+    _client: PhantomData<fn() -> C>,
+}
+
+impl<'a, C> CatenisClientBuilder<'a, C> {
+    pub(crate) fn new() -> Self {
+        CatenisClientBuilder {
+            device_credentials: None,
+            base_url: None,
+            host: None,
+            environment: None,
+            secure: None,
+            version: None,
+            use_compression: None,
+            compression: None,
+            compress_threshold: None,
+            retry: None,
+            rate_limit: None,
+            circuit_breaker: None,
+            timeout: None,
+            connect_timeout: None,
+            ws_handshake_timeout: None,
+            proxy: None,
+            tls_root_certificates: Vec::new(),
+            tls_built_in_roots: None,
+            tls_client_identity: None,
+            pinned_public_keys: None,
+            failover: None,
+            _client: PhantomData,
+        }
+    }
+
+    /// Virtual device credentials. If not set, the resulting client object should only be used
+    /// to call **public** API methods.
+    pub fn device_credentials(mut self, device_credentials: DeviceCredentials) -> Self {
+        self.device_credentials = Some(device_credentials);
+        self
+    }
+
+    /// Refer to [`ClientOptions::BaseUrl`]. The environment setting is applied to it, while the
+    /// secure connection setting, if any, must match its scheme.
+    pub fn base_url(mut self, base_url: &'a str) -> Self {
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Host`].
+    pub fn host(mut self, host: &'a str) -> Self {
+        self.host = Some(host);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Environment`]. It is applied to the configured host.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Secure`].
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = Some(secure);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Version`].
+    pub fn version(mut self, version: ApiVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Refer to [`ClientOptions::UseCompression`].
+    pub fn use_compression(mut self, use_compression: bool) -> Self {
+        self.use_compression = Some(use_compression);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Compression`].
+    pub fn compression(mut self, compression: CompressionOptions) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Refer to [`ClientOptions::CompressThreshold`].
+    pub fn compress_threshold(mut self, compress_threshold: usize) -> Self {
+        self.compress_threshold = Some(compress_threshold);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Retry`].
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Refer to [`ClientOptions::RateLimit`].
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = Some(limit);
+        self
+    }
+
+    /// Refer to [`ClientOptions::CircuitBreaker`].
+    pub fn circuit_breaker(mut self, policy: CircuitBreakerPolicy) -> Self {
+        self.circuit_breaker = Some(policy);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Timeout`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Refer to [`ClientOptions::ConnectTimeout`].
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Refer to [`ClientOptions::WsHandshakeTimeout`].
+    pub fn ws_handshake_timeout(mut self, timeout: Duration) -> Self {
+        self.ws_handshake_timeout = Some(timeout);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Proxy`].
+    pub fn proxy(mut self, proxy: ProxyOptions<'a>) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Refer to [`ClientOptions::TlsRootCertificates`]. It can be called multiple times to add
+    /// more root certificates.
+    pub fn tls_root_certificates(mut self, pem: &'a [u8]) -> Self {
+        self.tls_root_certificates.push(pem);
+        self
+    }
+
+    /// Refer to [`ClientOptions::TlsBuiltInRoots`].
+    pub fn tls_built_in_roots(mut self, use_built_in_roots: bool) -> Self {
+        self.tls_built_in_roots = Some(use_built_in_roots);
+        self
+    }
+
+    /// Refer to [`ClientOptions::TlsClientIdentity`].
+    pub fn tls_client_identity(mut self, identity: ClientIdentity<'a>) -> Self {
+        self.tls_client_identity = Some(identity);
+        self
+    }
+
+    /// Refer to [`ClientOptions::PinnedPublicKeys`].
+    pub fn pinned_public_keys(mut self, pins: &'a [&'a str]) -> Self {
+        self.pinned_public_keys = Some(pins);
+        self
+    }
+
+    /// Refer to [`ClientOptions::Failover`].
+    pub fn failover(mut self, failover: FailoverOptions<'a>) -> Self {
+        self.failover = Some(failover);
+        self
+    }
+
+    /// Validate the settings, and convert them into the equivalent list of option settings,
+    /// along with the virtual device credentials.
+    pub(crate) fn into_options(self) -> Result<(Option<DeviceCredentials>, Vec<ClientOptions<'a>>)> {
+        if self.secure == Some(false) && (!self.tls_root_certificates.is_empty() || self.tls_built_in_roots.is_some()
+                || self.tls_client_identity.is_some() || self.pinned_public_keys.is_some()) {
+            return Err(Self::conflict_error("TLS settings require a secure connection"));
+        }
+
+        if self.use_compression == Some(false) && self.compress_threshold.is_some() {
+            return Err(Self::conflict_error("compression threshold set while compression is disabled"));
+        }
+
+        if self.use_compression == Some(false) && self.compression.is_some() {
+            return Err(Self::conflict_error("compression settings set while compression is disabled"));
+        }
+
+        if self.tls_built_in_roots == Some(false) && self.tls_root_certificates.is_empty() {
+            return Err(Self::conflict_error("no root certificates to trust"));
+        }
+
+        if self.base_url.is_some() && self.host.is_some() {
+            return Err(Self::conflict_error("host set along with base URL"));
+        }
+
+        if let (Some(base_url), Some(secure)) = (self.base_url, self.secure) {
+            let scheme = base_url.split(':').next().unwrap_or_default();
+
+            if scheme.eq_ignore_ascii_case(if secure {"http"} else {"https"}) {
+                return Err(Self::conflict_error("secure connection setting does not match base URL scheme"));
+            }
+        }
+
+        if let (Some(Environment::Sandbox), Some(host)) = (self.environment, self.host) {
+            if host.starts_with("sandbox.") {
+                return Err(Self::conflict_error("host already targets the sandbox environment"));
+            }
+        }
+
+        let mut opts = Vec::new();
+
+        // Note: base URL and host must precede environment and secure, so the latter are applied to them
+        opts.extend(self.base_url.map(ClientOptions::BaseUrl));
+        opts.extend(self.host.map(ClientOptions::Host));
+        opts.extend(self.environment.map(ClientOptions::Environment));
+        opts.extend(self.secure.map(ClientOptions::Secure));
+        opts.extend(self.version.map(ClientOptions::Version));
+        opts.extend(self.use_compression.map(ClientOptions::UseCompression));
+        opts.extend(self.compression.map(ClientOptions::Compression));
+        opts.extend(self.compress_threshold.map(ClientOptions::CompressThreshold));
+        opts.extend(self.retry.map(ClientOptions::Retry));
+        opts.extend(self.rate_limit.map(ClientOptions::RateLimit));
+        opts.extend(self.circuit_breaker.map(ClientOptions::CircuitBreaker));
+        opts.extend(self.timeout.map(ClientOptions::Timeout));
+        opts.extend(self.connect_timeout.map(ClientOptions::ConnectTimeout));
+        opts.extend(self.ws_handshake_timeout.map(ClientOptions::WsHandshakeTimeout));
+        opts.extend(self.proxy.map(ClientOptions::Proxy));
+        opts.extend(self.tls_root_certificates.into_iter().map(ClientOptions::TlsRootCertificates));
+        opts.extend(self.tls_built_in_roots.map(ClientOptions::TlsBuiltInRoots));
+        opts.extend(self.tls_client_identity.map(ClientOptions::TlsClientIdentity));
+        opts.extend(self.pinned_public_keys.map(ClientOptions::PinnedPublicKeys));
+        opts.extend(self.failover.map(ClientOptions::Failover));
+
+        Ok((self.device_credentials, opts))
+    }
+
+    fn conflict_error(reason: &str) -> Error {
+        Error::new_client_error(Some(&format!("Conflicting client options: {}", reason)), None::<GenericError>)
+    }
+}
+
+impl<'a> CatenisClientBuilder<'a, CatenisClient> {
+    /// Instantiate the Catenis API client object.
+    ///
+    /// The resolved base URL of the Catenis API can be retrieved via the client's
+    /// [`base_api_url()`](CatenisClient::base_api_url) method.
+    pub fn build(self) -> Result<CatenisClient> {
+        let (device_credentials, opts) = self.into_options()?;
+
+        CatenisClient::new_with_options(device_credentials, opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_resolve_environment_regardless_of_order() {
+        let ctn_client = CatenisClient::builder()
+            .environment(Environment::Sandbox)
+            .host("localhost:3000")
+            .secure(false)
+            .version(ApiVersion(0, 10))
+            .build()
+            .unwrap();
+
+        assert_eq!(ctn_client.base_api_url(), "http://sandbox.localhost:3000/api/0.10/");
+
+        let ctn_client = CatenisClient::builder()
+            .host("localhost:3000")
+            .environment(Environment::Sandbox)
+            .secure(false)
+            .version(ApiVersion(0, 10))
+            .build()
+            .unwrap();
+
+        assert_eq!(ctn_client.base_api_url(), "http://sandbox.localhost:3000/api/0.10/");
+    }
+
+    #[test]
+    fn it_build_default_client() {
+        let ctn_client = CatenisClient::builder().build().unwrap();
+
+        assert_eq!(ctn_client.base_api_url(), "https://catenis.io/api/0.12/");
+    }
+
+    #[test]
+    fn it_fail_build_conflicting_options() {
+        let result = CatenisClient::builder()
+            .secure(false)
+            .pinned_public_keys(&["sha256//AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="])
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: TLS settings require a secure connection");
+
+        let result = CatenisClient::builder()
+            .use_compression(false)
+            .compress_threshold(2048)
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: compression threshold set while compression is disabled");
+
+        let result = CatenisClient::builder()
+            .use_compression(false)
+            .compression(CompressionOptions::default())
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: compression settings set while compression is disabled");
+
+        let result = CatenisClient::builder()
+            .tls_built_in_roots(false)
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: no root certificates to trust");
+
+        let result = CatenisClient::builder()
+            .host("sandbox.catenis.io")
+            .environment(Environment::Sandbox)
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: host already targets the sandbox environment");
+
+        let result = CatenisClient::builder()
+            .host("localhost:3000")
+            .base_url("https://gw.internal/catenis/")
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: host set along with base URL");
+
+        let result = CatenisClient::builder()
+            .secure(false)
+            .base_url("https://gw.internal/catenis/")
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: secure connection setting does not match base URL scheme");
+
+        let result = CatenisClient::builder()
+            .base_url("HTTP://localhost:3000/")
+            .secure(true)
+            .build();
+
+        assert!(result.is_err(), "Built client with conflicting options");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Conflicting client options: secure connection setting does not match base URL scheme");
+    }
+}
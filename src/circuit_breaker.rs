@@ -0,0 +1,371 @@
+use std::{
+    sync::{
+        Arc, Mutex, PoisonError,
+    },
+    time::{
+        Duration, Instant,
+    },
+};
+use serde::Deserialize;
+
+use crate::{
+    Error, Result, config,
+    error::GenericError,
+};
+
+/// Policy of a circuit breaker that stops requests from being sent to the Catenis API server
+/// while it is failing.
+///
+/// The circuit breaker starts **closed**, letting all requests through. After
+/// `failure_threshold` consecutive failed requests (requests that could not be sent, or that
+/// got a `5xx` HTTP status code in response), it **opens**: requests then fail immediately,
+/// without being sent, with an error for which [`Error::is_circuit_open`] returns `true`. Once
+/// `open_duration` has elapsed, it becomes **half-open**, and up to `half_open_probes` requests
+/// are let through to probe the server. If all of them succeed, the circuit breaker closes again;
+/// if any of them fails, it opens again.
+///
+/// The circuit breaker is shared by all clones of a client, and its current state can be
+/// retrieved via the client's `circuit_state()` method.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use catenis_api_client::{
+///     CatenisClient, ClientOptions, CircuitBreakerPolicy, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let ctn_client = CatenisClient::new_with_options(
+///     Some((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into()),
+///     &[
+///         ClientOptions::CircuitBreaker(CircuitBreakerPolicy {
+///             failure_threshold: 10,
+///             open_duration: Duration::from_secs(60),
+///             ..CircuitBreakerPolicy::default()
+///         }),
+///     ],
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CircuitBreakerPolicy {
+    /// Number of consecutive failed requests after which the circuit breaker opens. Default
+    /// value: **`5`**.
+    pub failure_threshold: u32,
+    /// Time during which the circuit breaker stays open before letting requests through to probe
+    /// the server. Default value: **`30 s`**.
+    #[serde(rename = "open_duration_ms", deserialize_with = "config::deserialize_millis")]
+    pub open_duration: Duration,
+    /// Number of successful probe requests required for the circuit breaker to close again.
+    /// Default value: **`1`**.
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerPolicy {
+    fn default() -> Self {
+        CircuitBreakerPolicy {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+/// State of a circuit breaker.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CircuitState {
+    /// Requests are let through.
+    Closed,
+    /// Requests fail immediately without being sent.
+    Open,
+    /// A limited number of requests are let through to probe the server.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum BreakerState {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        since: Instant,
+    },
+    HalfOpen {
+        since: Instant,
+        permits: u32,
+        successes: u32,
+    },
+}
+
+#[derive(Debug)]
+struct Breaker {
+    policy: CircuitBreakerPolicy,
+    state: BreakerState,
+}
+
+impl Breaker {
+    fn new(policy: &CircuitBreakerPolicy) -> Self {
+        Breaker {
+            policy: *policy,
+            state: BreakerState::Closed {
+                failures: 0,
+            },
+        }
+    }
+
+    fn state(&self, now: Instant) -> CircuitState {
+        match self.state {
+            BreakerState::Closed { .. } => CircuitState::Closed,
+            BreakerState::Open { since } if now.saturating_duration_since(since) < self.policy.open_duration => CircuitState::Open,
+            _ => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Indicates whether a request may be sent.
+    fn allow(&mut self, now: Instant) -> bool {
+        match &mut self.state {
+            BreakerState::Closed { .. } => true,
+            BreakerState::Open { since } => {
+                if now.saturating_duration_since(*since) >= self.policy.open_duration {
+                    self.state = BreakerState::HalfOpen {
+                        since: now,
+                        permits: self.policy.half_open_probes - 1,
+                        successes: 0,
+                    };
+
+                    true
+                } else {
+                    false
+                }
+            }
+            BreakerState::HalfOpen { since, permits, .. } => {
+                if *permits == 0 && now.saturating_duration_since(*since) >= self.policy.open_duration {
+                    // The outcome of the probes has not been reported in due time. Let new
+                    //  probes through
+                    *since = now;
+                    *permits = self.policy.half_open_probes;
+                }
+
+                if *permits > 0 {
+                    *permits -= 1;
+
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn record_success(&mut self) {
+        match &mut self.state {
+            BreakerState::Closed { failures } => *failures = 0,
+            BreakerState::Open { .. } => (),
+            BreakerState::HalfOpen { successes, .. } => {
+                *successes += 1;
+
+                if *successes >= self.policy.half_open_probes {
+                    self.state = BreakerState::Closed {
+                        failures: 0,
+                    };
+                }
+            }
+        }
+    }
+
+    fn record_failure(&mut self, now: Instant) {
+        match &mut self.state {
+            BreakerState::Closed { failures } => {
+                *failures += 1;
+
+                if *failures >= self.policy.failure_threshold {
+                    self.state = BreakerState::Open {
+                        since: now,
+                    };
+                }
+            }
+            BreakerState::Open { .. } => (),
+            BreakerState::HalfOpen { .. } => {
+                self.state = BreakerState::Open {
+                    since: now,
+                };
+            }
+        }
+    }
+}
+
+/// Optional circuit breaker of a client, shared by all its clones.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CircuitBreaker(Option<Arc<Mutex<Breaker>>>);
+
+impl CircuitBreaker {
+    pub(crate) fn new(policy: &CircuitBreakerPolicy) -> Result<Self> {
+        if policy.failure_threshold == 0 || policy.half_open_probes == 0 {
+            return Err(Error::new_client_error(Some("Invalid circuit breaker policy"), None::<GenericError>));
+        }
+
+        Ok(CircuitBreaker(Some(Arc::new(Mutex::new(Breaker::new(policy))))))
+    }
+
+    /// The current state of the circuit breaker, or `None` if no circuit breaker is used.
+    pub(crate) fn state(&self) -> Option<CircuitState> {
+        self.with_breaker(|breaker| breaker.state(Instant::now()))
+    }
+
+    /// Check whether a request may be sent, failing with a circuit open error if not.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.with_breaker(|breaker| breaker.allow(Instant::now())).unwrap_or(true) {
+            Ok(())
+        } else {
+            Err(Error::new_circuit_open_error())
+        }
+    }
+
+    pub(crate) fn record_success(&self) {
+        self.with_breaker(Breaker::record_success);
+    }
+
+    pub(crate) fn record_failure(&self) {
+        self.with_breaker(|breaker| breaker.record_failure(Instant::now()));
+    }
+
+    fn with_breaker<T, F: FnOnce(&mut Breaker) -> T>(&self, f: F) -> Option<T> {
+        self.0.as_ref()
+            .map(|breaker| f(&mut breaker.lock().unwrap_or_else(PoisonError::into_inner)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> CircuitBreakerPolicy {
+        CircuitBreakerPolicy {
+            failure_threshold: 2,
+            open_duration: Duration::from_secs(10),
+            half_open_probes: 2,
+        }
+    }
+
+    #[test]
+    fn it_open_after_consecutive_failures() {
+        let now = Instant::now();
+        let mut breaker = Breaker::new(&policy());
+
+        breaker.record_failure(now);
+        breaker.record_success();
+        breaker.record_failure(now);
+
+        assert_eq!(breaker.state(now), CircuitState::Closed);
+        assert!(breaker.allow(now));
+
+        breaker.record_failure(now);
+
+        assert_eq!(breaker.state(now), CircuitState::Open);
+        assert!(!breaker.allow(now + Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn it_close_after_successful_probes() {
+        let now = Instant::now();
+        let mut breaker = Breaker::new(&policy());
+
+        breaker.record_failure(now);
+        breaker.record_failure(now);
+
+        let later = now + Duration::from_secs(10);
+
+        assert_eq!(breaker.state(later), CircuitState::HalfOpen);
+        assert!(breaker.allow(later));
+        assert!(breaker.allow(later));
+        assert!(!breaker.allow(later), "Let more probes through than allowed");
+
+        breaker.record_success();
+
+        assert_eq!(breaker.state(later), CircuitState::HalfOpen);
+
+        breaker.record_success();
+
+        assert_eq!(breaker.state(later), CircuitState::Closed);
+    }
+
+    #[test]
+    fn it_reopen_after_failed_probe() {
+        let now = Instant::now();
+        let mut breaker = Breaker::new(&policy());
+
+        breaker.record_failure(now);
+        breaker.record_failure(now);
+
+        let later = now + Duration::from_secs(10);
+
+        assert!(breaker.allow(later));
+
+        breaker.record_failure(later);
+
+        assert_eq!(breaker.state(later), CircuitState::Open);
+        assert!(!breaker.allow(later + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn it_renew_lost_probes() {
+        let now = Instant::now();
+        let mut breaker = Breaker::new(&policy());
+
+        breaker.record_failure(now);
+        breaker.record_failure(now);
+
+        let later = now + Duration::from_secs(10);
+
+        assert!(breaker.allow(later));
+        assert!(breaker.allow(later));
+        assert!(!breaker.allow(later));
+
+        // Outcome of probes never reported
+        assert!(breaker.allow(later + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn it_fail_fast_when_open() {
+        let breaker = CircuitBreaker::new(&CircuitBreakerPolicy {
+            failure_threshold: 1,
+            ..CircuitBreakerPolicy::default()
+        }).unwrap();
+        let breaker_clone = breaker.clone();
+
+        assert!(breaker.check().is_ok());
+
+        breaker.record_failure();
+
+        assert_eq!(breaker_clone.state(), Some(CircuitState::Open));
+
+        let result = breaker_clone.check();
+
+        assert!(result.is_err(), "Request let through while circuit breaker is open");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_circuit_open());
+        assert_eq!(err.to_string(), "Catenis client error: Circuit breaker open; request not sent");
+    }
+
+    #[test]
+    fn it_skip_unset_circuit_breaker() {
+        let breaker = CircuitBreaker::default();
+
+        breaker.record_failure();
+
+        assert_eq!(breaker.state(), None);
+        assert!(breaker.check().is_ok());
+        assert!(CircuitBreaker::new(&CircuitBreakerPolicy { failure_threshold: 0, ..CircuitBreakerPolicy::default() }).is_err());
+    }
+}
@@ -1,11 +1,8 @@
 use std::{
     borrow::Borrow,
-    io::Read,
-};
-use bitcoin_hashes::{
-    Hash, HashEngine, hex::ToHex, Hmac,
-    HmacEngine,
-    sha256,
+    collections::HashMap,
+    sync::Arc,
+    thread,
 };
 use reqwest::{
     blocking::{
@@ -15,67 +12,56 @@ use reqwest::{
     },
     header::{
         ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, CONTENT_ENCODING,
-        HeaderMap, HeaderName, HeaderValue, HOST,
-    },
-    Url,
-};
-use flate2::{
-    Compression,
-    read::{
-        ZlibEncoder,
+        HeaderMap, HeaderValue, HOST,
     },
-};
-use time::{
-    Date,
+    Method, StatusCode, Url,
 };
 use serde::de::DeserializeOwned;
 
 use crate::*;
 use crate::api::*;
 use base_client::BaseCatenisClient;
+use retry::SendAttempt;
+use rate_limit::RateLimiter;
+use circuit_breaker::CircuitBreaker;
+use failover::Endpoints;
+use clock_skew::ClockSkew;
+use signing_state::SigningState;
+use connection::ConnectionSettings;
+use proxy::ProxySettings;
+use pinning::{
+    self,
+    PinSet,
+};
+use interceptor::InterceptorChain;
+use metrics::MetricsRecorder;
+use trace::{
+    CallTrace, TracedResponse,
+};
+use versioning::ApiMethod;
+use pagination::Paginator;
 
 /// Represents a Catenis API client.
 #[derive(Debug, Clone)]
 pub struct CatenisClient {
-    device_credentials: Option<DeviceCredentials>,
+    signing: SigningState,
     base_api_url: Url,
-    is_secure: bool,
+    pub(crate) endpoints: Endpoints,
+    api_version: ApiVersion,
     use_compression: bool,
+    compression: CompressionOptions,
     compress_threshold: usize,
-    sign_date: Option<Date>,
-    signing_key: Option<[u8; 32]>,
+    retry_policy: RetryPolicy,
+    rate_limiter: RateLimiter,
+    circuit_breaker: CircuitBreaker,
+    clock_skew: ClockSkew,
+    pub(crate) connection_settings: ConnectionSettings,
+    interceptors: InterceptorChain,
+    pub(crate) metrics: MetricsRecorder,
     http_client: HttpClient,
 }
 
-impl BaseCatenisClient for CatenisClient {
-    fn get_device_id_ref(&self) -> Result<&String> {
-        if let Some(credentials) = &self.device_credentials {
-            Ok(&credentials.device_id)
-        } else {
-            Err(Error::new_client_error(Some("Missing virtual device credentials"), None::<GenericError>))
-        }
-    }
-
-    fn get_api_access_secret_ref(&self) -> Result<&String> {
-        if let Some(credentials) = &self.device_credentials {
-            Ok(&credentials.api_access_secret)
-        } else {
-            Err(Error::new_client_error(Some("Missing virtual device credentials"), None::<GenericError>))
-        }
-    }
-
-    fn get_sign_date_ref(&self) -> &Option<Date> {
-        &self.sign_date
-    }
-
-    fn get_sign_date_mut_ref(&mut self) -> &mut Option<Date> {
-        &mut self.sign_date
-    }
-
-    fn get_signing_key_mut_ref(&mut self) -> &mut Option<[u8; 32]> {
-        &mut self.signing_key
-    }
-}
+impl BaseCatenisClient for CatenisClient {}
 
 impl CatenisClient {
     // Definition of public methods
@@ -88,19 +74,33 @@ impl CatenisClient {
     {
         let base_url = Url::parse(DEFAULT_BASE_URL)?;
         let api_version = DEFAULT_API_VERSION;
-        let is_secure = true;
         let use_compression = true;
+        let compression = CompressionOptions::default();
         let compress_threshold: usize = 1024;
+        let retry_policy = RetryPolicy::no_retry();
+        let rate_limiter = RateLimiter::default();
+        let circuit_breaker = CircuitBreaker::default();
+        let connection_settings = ConnectionSettings::default();
+
+        let base_api_url = base_url.join(&Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]))?;
+        let endpoints = Endpoints::new(vec![base_api_url.clone()], None);
 
         Ok(CatenisClient {
-            device_credentials,
-            base_api_url: base_url.join(&Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]))?,
-            is_secure,
+            signing: SigningState::new(device_credentials),
+            base_api_url,
+            endpoints,
+            api_version,
             use_compression,
+            compression,
             compress_threshold,
-            sign_date: None,
-            signing_key: None,
-            http_client: Self::new_http_client(use_compression)?,
+            retry_policy,
+            rate_limiter,
+            circuit_breaker,
+            clock_skew: ClockSkew::default(),
+            http_client: Self::new_http_client(use_compression, &compression, &connection_settings)?,
+            connection_settings,
+            interceptors: InterceptorChain::default(),
+            metrics: MetricsRecorder::default(),
         })
     }
 
@@ -115,12 +115,20 @@ impl CatenisClient {
     {
         let mut base_url = Url::parse(DEFAULT_BASE_URL)?;
         let mut api_version = DEFAULT_API_VERSION;
-        let mut is_secure = true;
         let mut use_compression = true;
+        let mut compression = CompressionOptions::default();
         let mut compress_threshold: usize = 1024;
+        let mut retry_policy = RetryPolicy::no_retry();
+        let mut rate_limiter = RateLimiter::default();
+        let mut circuit_breaker = CircuitBreaker::default();
+        let mut connection_settings = ConnectionSettings::default();
+        let mut failover = None;
 
         for opt in opts.into_iter() {
             match opt.borrow() {
+                ClientOptions::BaseUrl(url) => {
+                    base_url = Self::parse_base_url(url)?;
+                }
                 ClientOptions::Host(host) => {
                     match Self::parse_host_with_port(host) {
                         (Some(host), port) => {
@@ -150,13 +158,9 @@ impl CatenisClient {
                     }
                 }
                 ClientOptions::Secure(secure) => {
-                    is_secure = *secure;
-
-                    if !is_secure {
-                        // Replace scheme
-                        if let Err(_) = base_url.set_scheme("http") {
-                            return Err(Error::new_client_error(Some("Error resetting URL scheme"), None::<GenericError>));
-                        }
+                    // Replace scheme
+                    if base_url.set_scheme(if *secure {"https"} else {"http"}).is_err() {
+                        return Err(Error::new_client_error(Some("Error resetting URL scheme"), None::<GenericError>));
                     }
                 }
                 ClientOptions::Version(version) => {
@@ -165,24 +169,205 @@ impl CatenisClient {
                 ClientOptions::UseCompression(compress) => {
                     use_compression = *compress;
                 }
+                ClientOptions::Compression(compression_opts) => {
+                    compression = *compression_opts;
+                }
                 ClientOptions::CompressThreshold(threshold) => {
                     compress_threshold = *threshold;
                 }
+                ClientOptions::Retry(policy) => {
+                    retry_policy = *policy;
+                }
+                ClientOptions::RateLimit(limit) => {
+                    rate_limiter = RateLimiter::new(limit)?;
+                }
+                ClientOptions::CircuitBreaker(policy) => {
+                    circuit_breaker = CircuitBreaker::new(policy)?;
+                }
+                ClientOptions::Timeout(timeout) => {
+                    connection_settings.request_timeout = Some(*timeout);
+                }
+                ClientOptions::ConnectTimeout(timeout) => {
+                    connection_settings.connect_timeout = Some(*timeout);
+                }
+                ClientOptions::WsHandshakeTimeout(timeout) => {
+                    connection_settings.ws_handshake_timeout = Some(*timeout);
+                }
+                ClientOptions::Proxy(proxy_opts) => {
+                    connection_settings.proxy = Some(ProxySettings::from_options(proxy_opts)?);
+                }
+                ClientOptions::TlsRootCertificates(pem) => {
+                    connection_settings.tls.add_root_certificates(pem)?;
+                }
+                ClientOptions::TlsBuiltInRoots(use_built_in_roots) => {
+                    connection_settings.tls.use_built_in_roots = *use_built_in_roots;
+                }
+                ClientOptions::TlsClientIdentity(identity) => {
+                    connection_settings.tls.set_client_identity(identity)?;
+                }
+                ClientOptions::PinnedPublicKeys(pins) => {
+                    connection_settings.pinned_keys = Some(PinSet::from_pins(pins)?);
+                }
+                ClientOptions::Failover(failover_opts) => {
+                    failover = Some(*failover_opts);
+                }
+            }
+        }
+
+        let api_path = Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]);
+        let base_api_url = base_url.join(&api_path)?;
+        let mut base_api_urls = vec![base_api_url.clone()];
+
+        if let Some(failover_opts) = &failover {
+            for url in failover_opts.base_urls {
+                base_api_urls.push(Self::parse_base_url(url)?.join(&api_path)?);
             }
         }
 
+        let endpoints = Endpoints::new(base_api_urls, failover.as_ref());
+
         Ok(CatenisClient {
-            device_credentials,
-            base_api_url: base_url.join(&Self::merge_url_params(API_BASE_URL_PATH, &[("version", api_version.to_string())]))?,
-            is_secure,
+            signing: SigningState::new(device_credentials),
+            base_api_url,
+            endpoints,
+            api_version,
             use_compression,
+            compression,
             compress_threshold,
-            sign_date: None,
-            signing_key: None,
-            http_client: Self::new_http_client(use_compression)?,
+            retry_policy,
+            rate_limiter,
+            circuit_breaker,
+            clock_skew: ClockSkew::default(),
+            http_client: Self::new_http_client(use_compression, &compression, &connection_settings)?,
+            connection_settings,
+            interceptors: InterceptorChain::default(),
+            metrics: MetricsRecorder::default(),
         })
     }
 
+    /// Instantiate a new builder of a Catenis API client object, whose option settings are
+    /// resolved in a fixed way, regardless of the order in which they are set, and checked for
+    /// conflicts.
+    pub fn builder<'a>() -> CatenisClientBuilder<'a> {
+        CatenisClientBuilder::new()
+    }
+
+    /// Instantiate a new Catenis API client object from a configuration (e.g. loaded from a
+    /// file).
+    pub fn from_config(config: &ClientConfig) -> Result<Self> {
+        config.build::<Self, _>(|builder| builder.build())
+    }
+
+    /// Instantiate a new Catenis API client object configured from environment variables.
+    ///
+    /// Refer to [`ClientConfig::from_env()`] for the names of the environment variables that are
+    /// read.
+    pub fn from_env() -> Result<Self> {
+        Self::from_config(&ClientConfig::from_env()?)
+    }
+
+    /// Retrieve the base URL of the Catenis API, as resolved from the client's option settings.
+    pub fn base_api_url(&self) -> &str {
+        self.base_api_url.as_str()
+    }
+
+    /// Retrieve the version of the Catenis API targeted by the client.
+    ///
+    /// Use its [`supports_method()`](ApiVersion::supports_method) method to check whether a given
+    /// method is available in that version.
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Get a clone of this client that compresses (or not) request bodies regardless of its
+    /// `UseCompression` option setting, to be used for individual calls (e.g. to skip compressing
+    /// a message whose contents are already compressed).
+    ///
+    /// Everything else, including the underlying HTTP client and its connection pool, is shared
+    /// with this client.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new(None)?;
+    /// #
+    /// let result = ctn_client.with_request_compression(false).log_message(
+    ///     Message::Whole(String::from("My message")),
+    ///     None,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_request_compression(&self, compress: bool) -> Self {
+        CatenisClient {
+            use_compression: compress,
+            ..self.clone()
+        }
+    }
+
+    /// Register an interceptor whose hooks should be called around every HTTP request sent to the
+    /// Catenis API server.
+    ///
+    /// Interceptors are called in the order that they have been registered. Refer to
+    /// [`Interceptor`] for an example.
+    pub fn add_interceptor<I: Interceptor + 'static>(&mut self, interceptor: I) {
+        self.interceptors.add(Arc::new(interceptor));
+    }
+
+    /// Set the sink to which metrics about the API method calls, and the activity of the WebSocket
+    /// notification channels created from this client, should be reported.
+    ///
+    /// To share the same sink among several clients, pass an `Arc` of it. Refer to
+    /// [`MetricsSink`] for an example.
+    pub fn set_metrics_sink<S: MetricsSink + 'static>(&mut self, sink: S) {
+        self.metrics.set(Arc::new(sink));
+    }
+
+    /// Set the provider from which the virtual device credentials should be retrieved, replacing
+    /// any credentials passed when instantiating the client.
+    ///
+    /// The credentials are loaded right away, and then again whenever a new signing key needs to
+    /// be generated. The provider is shared by all clones of the client, and the notification
+    /// channels created from them. Refer to [`CredentialProvider`] for an example.
+    pub fn set_credential_provider<P: CredentialProvider + 'static>(&self, provider: P) -> Result<()> {
+        self.signing.credentials().set_provider(Arc::new(provider))
+    }
+
+    /// Reload the virtual device credentials from the client's credential provider.
+    ///
+    /// If the credentials have changed, the new credentials are used for signing all subsequent
+    /// requests from this client, its clones, and the notification channels created from them,
+    /// without the need to instantiate them again.
+    pub fn rotate_credentials(&self) -> Result<()> {
+        self.signing.credentials().refresh()
+    }
+
+    /// Retrieve the current state of the client's circuit breaker, or `None` if the client has
+    /// not been set up to use a circuit breaker (via [`ClientOptions::CircuitBreaker`]).
+    ///
+    /// The circuit breaker is shared by all clones of the client, so this can be used, for
+    /// instance, to report the availability of the Catenis API server from a health endpoint.
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker.state()
+    }
+
+    /// Retrieve the currently measured skew, in seconds, between the Catenis API server's clock and
+    /// the local clock. A positive value means that the local clock is behind.
+    ///
+    /// The skew is learned from the `Date` HTTP header of the responses received from the server,
+    /// and it is compensated for when signing requests. If a request is rejected due to a failed
+    /// authentication after the skew has been found to change, it is signed anew and sent again
+    /// once.
+    pub fn clock_skew_secs(&self) -> i64 {
+        self.clock_skew.secs()
+    }
+
     /// Instantiate a new WebSocket notification channel object for a given Catenis notification event.
     ///
     /// # Example
@@ -229,7 +414,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -267,7 +452,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -317,7 +502,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn log_message(&mut self, message: Message, options: Option<LogMessageOptions>) -> Result<LogMessageResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn log_message(&self, message: Message, options: Option<LogMessageOptions>) -> Result<LogMessageResult> {
         let body = LogMessageRequest {
             message,
             options
@@ -330,9 +516,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::LogMessage, req)?;
 
-        Ok(Self::parse_response::<LogMessageResponse>(res)?.data)
+        Ok(Self::parse_api_response::<LogMessageResponse>(res)?.data)
     }
 
     /// Call *Send Message* API method.
@@ -348,7 +534,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -391,7 +577,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -446,7 +632,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send_message(&mut self, message: Message, target_device: DeviceId, options: Option<SendMessageOptions>) -> Result<SendMessageResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn send_message(&self, message: Message, target_device: DeviceId, options: Option<SendMessageOptions>) -> Result<SendMessageResult> {
         let body = SendMessageRequest {
             message,
             target_device,
@@ -460,9 +647,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::SendMessage, req)?;
 
-        Ok(Self::parse_response::<SendMessageResponse>(res)?.data)
+        Ok(Self::parse_api_response::<SendMessageResponse>(res)?.data)
     }
 
     /// Call *Read Message* API method.
@@ -476,7 +663,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -503,7 +690,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn read_message(&mut self, message_id: &str, options: Option<ReadMessageOptions>) -> Result<ReadMessageResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn read_message(&self, message_id: &str, options: Option<ReadMessageOptions>) -> Result<ReadMessageResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let encoding;
@@ -550,9 +738,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ReadMessage, req)?;
 
-        Ok(Self::parse_response::<ReadMessageResponse>(res)?.data)
+        Ok(Self::parse_api_response::<ReadMessageResponse>(res)?.data)
     }
 
     /// Call *Retrieve Message Container* API method.
@@ -566,7 +754,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -597,7 +785,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_message_container(&mut self, message_id: &str) -> Result<RetrieveMessageContainerResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_message_container(&self, message_id: &str) -> Result<RetrieveMessageContainerResult> {
         let req = self.get_request(
             "messages/:message_id/container",
             Some(&[
@@ -606,9 +795,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveMessageContainer, req)?;
 
-        Ok(Self::parse_response::<RetrieveMessageContainerResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrieveMessageContainerResponse>(res)?.data)
     }
 
     /// Call *Retrieve Message Origin* API method.
@@ -624,7 +813,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     None,
     /// #     &[
     /// #         ClientOptions::Environment(Environment::Sandbox),
@@ -650,6 +839,7 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn retrieve_message_origin(&self, message_id: &str, msg_to_sign: Option<&str>) -> Result<RetrieveMessageOriginResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
@@ -671,9 +861,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.send_request(req)?;
+        let res = self.send_request(ApiMethod::RetrieveMessageOrigin, req)?;
 
-        Ok(Self::parse_response::<RetrieveMessageOriginResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrieveMessageOriginResponse>(res)?.data)
     }
 
     /// Call *Retrieve Message Progress* API method.
@@ -690,7 +880,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -725,7 +915,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_message_progress(&mut self, message_id: &str) -> Result<RetrieveMessageProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_message_progress(&self, message_id: &str) -> Result<RetrieveMessageProgressResult> {
         let req = self.get_request(
             "messages/:message_id/progress",
             Some(&[
@@ -734,9 +925,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveMessageProgress, req)?;
 
-        Ok(Self::parse_response::<RetrieveMessageProgressResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrieveMessageProgressResponse>(res)?.data)
     }
 
     /// Call *List Messages* API method.
@@ -750,7 +941,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -787,7 +978,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_messages(&mut self, options: Option<ListMessagesOptions>) -> Result<ListMessagesResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_messages(&self, options: Option<ListMessagesOptions>) -> Result<ListMessagesResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let action;
@@ -911,9 +1103,67 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListMessages, req)?;
+
+        Ok(Self::parse_api_response::<ListMessagesResponse>(res)?.data)
+    }
+
+    /// Iterate over the messages returned by *List Messages* API method, fetching them in pages of up to
+    /// `page_size` entries (default: **500**) as the iteration proceeds.
+    ///
+    /// Iteration ends after the last page (the one for which `has_more` is `false`), or right
+    /// after an error.
+    ///
+    /// > **Note**: the `limit` and `skip` fields of `options` are ignored; the pages are fetched
+    /// > with the given page size instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// for message in ctn_client.list_messages_iter(
+    ///     Some(ListMessagesOptions {
+    ///         action: Some(MessageActionOption::Send),
+    ///         direction: Some(MessageDirectionOption::Inbound),
+    ///         ..ListMessagesOptions::default()
+    ///     }),
+    ///     Some(100),
+    /// ) {
+    ///     println!("Returned message: {:?}", message?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_messages_iter(&self, options: Option<ListMessagesOptions>, page_size: Option<u16>) -> impl Iterator<Item = Result<MessageEntry>> + '_ {
+        let options = options.unwrap_or_default();
+
+        Paginator::new(page_size, move |limit, skip| {
+            let result = self.list_messages(Some(ListMessagesOptions {
+                limit: Some(limit),
+                skip: Some(skip),
+                ..options.clone()
+            }))?;
 
-        Ok(Self::parse_response::<ListMessagesResponse>(res)?.data)
+            Ok((result.messages, result.has_more))
+        })
     }
 
     /// Call *Issue Asset* API method.
@@ -927,7 +1177,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -955,7 +1205,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn issue_asset(&mut self, asset_info: NewAssetInfo, amount: f64, holding_device: Option<DeviceId>) -> Result<IssueAssetResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn issue_asset(&self, asset_info: NewAssetInfo, amount: f64, holding_device: Option<DeviceId>) -> Result<IssueAssetResult> {
         let body = IssueAssetRequest {
             asset_info,
             amount,
@@ -969,9 +1220,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::IssueAsset, req)?;
 
-        Ok(Self::parse_response::<IssueAssetResponse>(res)?.data)
+        Ok(Self::parse_api_response::<IssueAssetResponse>(res)?.data)
     }
 
     /// Call *Reissue Asset* API method.
@@ -985,7 +1236,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1011,7 +1262,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn reissue_asset(&mut self, asset_id: &str, amount: f64, holding_device: Option<DeviceId>) -> Result<ReissueAssetResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn reissue_asset(&self, asset_id: &str, amount: f64, holding_device: Option<DeviceId>) -> Result<ReissueAssetResult> {
         let body = ReissueAssetRequest {
             amount,
             holding_device
@@ -1026,9 +1278,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ReissueAsset, req)?;
 
-        Ok(Self::parse_response::<ReissueAssetResponse>(res)?.data)
+        Ok(Self::parse_api_response::<ReissueAssetResponse>(res)?.data)
     }
 
     /// Call *Transfer Asset* API method.
@@ -1042,7 +1294,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1068,7 +1320,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn transfer_asset(&mut self, asset_id: &str, amount: f64, receiving_device: DeviceId) -> Result<TransferAssetResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn transfer_asset(&self, asset_id: &str, amount: f64, receiving_device: DeviceId) -> Result<TransferAssetResult> {
         let body = TransferAssetRequest {
             amount,
             receiving_device
@@ -1083,9 +1336,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::TransferAsset, req)?;
 
-        Ok(Self::parse_response::<TransferAssetResponse>(res)?.data)
+        Ok(Self::parse_api_response::<TransferAssetResponse>(res)?.data)
     }
 
     /// Call *Retrieve Asset Info* API method.
@@ -1099,7 +1352,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1120,7 +1373,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_asset_info(&mut self, asset_id: &str) -> Result<RetrieveAssetInfoResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_asset_info(&self, asset_id: &str) -> Result<RetrieveAssetInfoResult> {
         let req = self.get_request(
             "assets/:asset_id",
             Some(&[
@@ -1129,9 +1383,14 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveAssetInfo, req)?;
 
-        Ok(Self::parse_response::<RetrieveAssetInfoResponse>(res)?.data)
+        // Note: responses of older versions of the Catenis API have a different shape
+        if versioning::has_non_fungible_assets(self.api_version) {
+            Ok(Self::parse_api_response::<RetrieveAssetInfoResponse>(res)?.data)
+        } else {
+            Ok(Self::parse_api_response::<RetrieveAssetInfoResponseApi0_11>(res)?.data.into())
+        }
     }
 
     /// Call *Get Asset Balance* API method.
@@ -1145,7 +1404,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1167,7 +1426,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_asset_balance(&mut self, asset_id: &str) -> Result<GetAssetBalanceResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn get_asset_balance(&self, asset_id: &str) -> Result<GetAssetBalanceResult> {
         let req = self.get_request(
             "assets/:asset_id/balance",
             Some(&[
@@ -1176,9 +1436,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::GetAssetBalance, req)?;
 
-        Ok(Self::parse_response::<GetAssetBalanceResponse>(res)?.data)
+        Ok(Self::parse_api_response::<GetAssetBalanceResponse>(res)?.data)
     }
 
     /// Call *List Owned Assets* API method.
@@ -1192,7 +1452,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1222,7 +1482,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_owned_assets(&mut self, limit: Option<u16>, skip: Option<usize>) -> Result<ListOwnedAssetsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_owned_assets(&self, limit: Option<u16>, skip: Option<usize>) -> Result<ListOwnedAssetsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let limit_str;
@@ -1251,9 +1512,54 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListOwnedAssets, req)?;
+
+        Ok(Self::parse_api_response::<ListOwnedAssetsResponse>(res)?.data)
+    }
+
+    /// Iterate over the owned assets returned by *List Owned Assets* API method, fetching them in pages of up to
+    /// `page_size` entries (default: **500**) as the iteration proceeds.
+    ///
+    /// Iteration ends after the last page (the one for which `has_more` is `false`), or right
+    /// after an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// for owned_asset in ctn_client.list_owned_assets_iter(None) {
+    ///     let owned_asset = owned_asset?;
+    ///
+    ///     println!("Asset ID: {}", owned_asset.asset_id);
+    ///     println!(" - current asset balance: {}\n", owned_asset.balance.total);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_owned_assets_iter(&self, page_size: Option<u16>) -> impl Iterator<Item = Result<OwnedAssetEntry>> + '_ {
+        Paginator::new(page_size, move |limit, skip| {
+            let result = self.list_owned_assets(Some(limit), Some(skip))?;
 
-        Ok(Self::parse_response::<ListOwnedAssetsResponse>(res)?.data)
+            Ok((result.owned_assets, result.has_more))
+        })
     }
 
     /// Call *List Issued Assets* API method.
@@ -1267,7 +1573,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1296,7 +1602,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_issued_assets(&mut self, limit: Option<u16>, skip: Option<usize>) -> Result<ListIssuedAssetsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_issued_assets(&self, limit: Option<u16>, skip: Option<usize>) -> Result<ListIssuedAssetsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let limit_str;
@@ -1325,9 +1632,54 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListIssuedAssets, req)?;
+
+        Ok(Self::parse_api_response::<ListIssuedAssetsResponse>(res)?.data)
+    }
+
+    /// Iterate over the issued assets returned by *List Issued Assets* API method, fetching them in pages of up to
+    /// `page_size` entries (default: **500**) as the iteration proceeds.
+    ///
+    /// Iteration ends after the last page (the one for which `has_more` is `false`), or right
+    /// after an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// for issued_asset in ctn_client.list_issued_assets_iter(None) {
+    ///     let issued_asset = issued_asset?;
+    ///
+    ///     println!("Asset ID: {}", issued_asset.asset_id);
+    ///     println!(" - total existent balance: {}\n", issued_asset.total_existent_balance);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_issued_assets_iter(&self, page_size: Option<u16>) -> impl Iterator<Item = Result<IssuedAssetEntry>> + '_ {
+        Paginator::new(page_size, move |limit, skip| {
+            let result = self.list_issued_assets(Some(limit), Some(skip))?;
 
-        Ok(Self::parse_response::<ListIssuedAssetsResponse>(res)?.data)
+            Ok((result.issued_assets, result.has_more))
+        })
     }
 
     /// Call *Retrieve Asset Issuance History* API method.
@@ -1341,7 +1693,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1389,8 +1741,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn retrieve_asset_issuance_history(
-        &mut self,
+        &self,
         asset_id: &str,
         start_date: Option<UtcDateTime>,
         end_date: Option<UtcDateTime>,
@@ -1441,9 +1794,73 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveAssetIssuanceHistory, req)?;
+
+        // Note: responses of older versions of the Catenis API have a different shape
+        if versioning::has_non_fungible_assets(self.api_version) {
+            Ok(Self::parse_api_response::<RetrieveAssetIssuanceHistoryResponse>(res)?.data)
+        } else {
+            Ok(Self::parse_api_response::<RetrieveAssetIssuanceHistoryResponseApi0_11>(res)?.data.into())
+        }
+    }
 
-        Ok(Self::parse_response::<RetrieveAssetIssuanceHistoryResponse>(res)?.data)
+    /// Iterate over the asset issuance events returned by *Retrieve Asset Issuance History* API method, fetching them in pages of up to
+    /// `page_size` entries (default: **500**) as the iteration proceeds.
+    ///
+    /// Iteration ends after the last page (the one for which `has_more` is `false`), or right
+    /// after an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// for issuance_event in ctn_client.retrieve_asset_issuance_history_iter(
+    ///     "aBy2ovnucyWaSB6Tro9x",
+    ///     Some("2020-12-01T00:00:00Z".into()),
+    ///     None,
+    ///     None,
+    /// ) {
+    ///     println!("Asset issuance event: {:?}", issuance_event?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retrieve_asset_issuance_history_iter<'a>(
+        &'a self,
+        asset_id: &'a str,
+        start_date: Option<UtcDateTime>,
+        end_date: Option<UtcDateTime>,
+        page_size: Option<u16>,
+    ) -> impl Iterator<Item = Result<AssetIssuanceEventEntry>> + 'a {
+        Paginator::new(page_size, move |limit, skip| {
+            let result = self.retrieve_asset_issuance_history(
+                asset_id,
+                start_date,
+                end_date,
+                Some(limit),
+                Some(skip),
+            )?;
+
+            Ok((result.issuance_events, result.has_more))
+        })
     }
 
     /// Call *List Asset Holders* API method.
@@ -1457,7 +1874,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1498,7 +1915,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_asset_holders(&mut self, asset_id: &str, limit: Option<u16>, skip: Option<usize>) -> Result<ListAssetHoldersResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_asset_holders(&self, asset_id: &str, limit: Option<u16>, skip: Option<usize>) -> Result<ListAssetHoldersResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let limit_str;
@@ -1529,16 +1947,18 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListAssetHolders, req)?;
 
-        Ok(Self::parse_response::<ListAssetHoldersResponse>(res)?.data)
+        Ok(Self::parse_api_response::<ListAssetHoldersResponse>(res)?.data)
     }
 
-    /// Call *Export Asset* API method.
+    /// Iterate over the asset holders returned by *List Asset Holders* API method, fetching them in pages of up to
+    /// `page_size` entries (default: **500**) as the iteration proceeds.
     ///
-    /// # Examples
+    /// Iteration ends after the last page (the one for which `has_more` is `false`), or right
+    /// after an error.
     ///
-    /// Estimate export cost (in foregin blockchain's native coin):
+    /// # Example
     ///
     /// ```no_run
     /// use catenis_api_client::{
@@ -1547,7 +1967,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1560,22 +1980,62 @@ impl CatenisClient {
     /// #     ],
     /// # )?;
     /// #
-    /// let result = ctn_client.export_asset(
-    ///     "aH2AkrrL55GcThhPNa3J",
-    ///     ForeignBlockchain::Ethereum,
-    ///     NewForeignTokenInfo {
-    ///         name: String::from("Catenis test token #10"),
-    ///         symbol: String::from("CTK10"),
-    ///     },
-    ///     Some(ExportAssetOptions {
-    ///         consumption_profile: None,
-    ///         estimate_only: Some(true),
-    ///     }),
-    /// )?;
-    ///
-    /// println!(
-    ///     "Estimated foreign blockchain transaction execution price: {}",
-    ///     result.estimated_price.unwrap()
+    /// for asset_holder in ctn_client.list_asset_holders_iter("aBy2ovnucyWaSB6Tro9x", None) {
+    ///     println!("Asset holder: {:?}", asset_holder?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_asset_holders_iter<'a>(&'a self, asset_id: &'a str, page_size: Option<u16>) -> impl Iterator<Item = Result<AssetHolderEntry>> + 'a {
+        Paginator::new(page_size, move |limit, skip| {
+            let result = self.list_asset_holders(asset_id, Some(limit), Some(skip))?;
+
+            Ok((result.asset_holders, result.has_more))
+        })
+    }
+
+    /// Call *Export Asset* API method.
+    ///
+    /// # Examples
+    ///
+    /// Estimate export cost (in foregin blockchain's native coin):
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// let result = ctn_client.export_asset(
+    ///     "aH2AkrrL55GcThhPNa3J",
+    ///     ForeignBlockchain::Ethereum,
+    ///     NewForeignTokenInfo {
+    ///         name: String::from("Catenis test token #10"),
+    ///         symbol: String::from("CTK10"),
+    ///     },
+    ///     Some(ExportAssetOptions {
+    ///         consumption_profile: None,
+    ///         estimate_only: Some(true),
+    ///     }),
+    /// )?;
+    ///
+    /// println!(
+    ///     "Estimated foreign blockchain transaction execution price: {}",
+    ///     result.estimated_price.unwrap()
     /// );
     /// # Ok(())
     /// # }
@@ -1590,7 +2050,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1619,8 +2079,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn export_asset(
-        &mut self,
+        &self,
         asset_id: &str,
         foreign_blockchain: ForeignBlockchain,
         token: NewForeignTokenInfo,
@@ -1641,9 +2102,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ExportAsset, req)?;
 
-        Ok(Self::parse_response::<ExportAssetResponse>(res)?.data)
+        Ok(Self::parse_api_response::<ExportAssetResponse>(res)?.data)
     }
 
     /// Call *Migrate Asset* API method.
@@ -1659,7 +2120,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1703,7 +2164,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1743,7 +2204,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1769,8 +2230,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn migrate_asset(
-        &mut self,
+        &self,
         asset_id: &str,
         foreign_blockchain: ForeignBlockchain,
         migration: AssetMigration,
@@ -1791,9 +2253,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::MigrateAsset, req)?;
 
-        Ok(Self::parse_response::<MigrateAssetResponse>(res)?.data)
+        Ok(Self::parse_api_response::<MigrateAssetResponse>(res)?.data)
     }
 
     /// Call *Asset Export Outcome* API method.
@@ -1807,7 +2269,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1844,8 +2306,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn asset_export_outcome(
-        &mut self,
+        &self,
         asset_id: &str,
         foreign_blockchain: ForeignBlockchain,
     ) -> Result<AssetExportOutcomeResult> {
@@ -1858,9 +2321,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::AssetExportOutcome, req)?;
 
-        Ok(Self::parse_response::<AssetExportOutcomeResponse>(res)?.data)
+        Ok(Self::parse_api_response::<AssetExportOutcomeResponse>(res)?.data)
     }
 
     /// Call *Asset Migration Outcome* API method.
@@ -1874,7 +2337,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1913,8 +2376,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn asset_migration_outcome(
-        &mut self,
+        &self,
         migration_id: &str,
     ) -> Result<AssetMigrationOutcomeResult> {
         let req = self.get_request(
@@ -1925,9 +2389,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::AssetMigrationOutcome, req)?;
 
-        Ok(Self::parse_response::<AssetMigrationOutcomeResponse>(res)?.data)
+        Ok(Self::parse_api_response::<AssetMigrationOutcomeResponse>(res)?.data)
     }
 
     /// Call *List Exported Assets* API method.
@@ -1941,7 +2405,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -1978,7 +2442,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_exported_assets(&mut self, options: Option<ListExportedAssetsOptions>) -> Result<ListExportedAssetsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_exported_assets(&self, options: Option<ListExportedAssetsOptions>) -> Result<ListExportedAssetsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let asset_id;
@@ -2066,9 +2531,66 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListExportedAssets, req)?;
+
+        Ok(Self::parse_api_response::<ListExportedAssetsResponse>(res)?.data)
+    }
+
+    /// Iterate over the asset exports returned by *List Exported Assets* API method, fetching them in pages of up to
+    /// `page_size` entries (default: **500**) as the iteration proceeds.
+    ///
+    /// Iteration ends after the last page (the one for which `has_more` is `false`), or right
+    /// after an error.
+    ///
+    /// > **Note**: the `limit` and `skip` fields of `options` are ignored; the pages are fetched
+    /// > with the given page size instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// for asset_export in ctn_client.list_exported_assets_iter(
+    ///     Some(ListExportedAssetsOptions {
+    ///         foreign_blockchain: Some(ForeignBlockchain::Ethereum),
+    ///         ..ListExportedAssetsOptions::default()
+    ///     }),
+    ///     None,
+    /// ) {
+    ///     println!("Asset export: {:?}", asset_export?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_exported_assets_iter(&self, options: Option<ListExportedAssetsOptions>, page_size: Option<u16>) -> impl Iterator<Item = Result<AssetExportEntry>> + '_ {
+        let options = options.unwrap_or_default();
+
+        Paginator::new(page_size, move |limit, skip| {
+            let result = self.list_exported_assets(Some(ListExportedAssetsOptions {
+                limit: Some(limit),
+                skip: Some(skip),
+                ..options.clone()
+            }))?;
 
-        Ok(Self::parse_response::<ListExportedAssetsResponse>(res)?.data)
+            Ok((result.exported_assets, result.has_more))
+        })
     }
 
     /// Call *List Asset Migrations* API method.
@@ -2082,7 +2604,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2122,7 +2644,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_asset_migrations(&mut self, options: Option<ListAssetMigrationsOptions>) -> Result<ListAssetMigrationsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_asset_migrations(&self, options: Option<ListAssetMigrationsOptions>) -> Result<ListAssetMigrationsResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let asset_id;
@@ -2210,9 +2733,66 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListAssetMigrations, req)?;
+
+        Ok(Self::parse_api_response::<ListAssetMigrationsResponse>(res)?.data)
+    }
+
+    /// Iterate over the asset migrations returned by *List Asset Migrations* API method, fetching them in pages of up to
+    /// `page_size` entries (default: **500**) as the iteration proceeds.
+    ///
+    /// Iteration ends after the last page (the one for which `has_more` is `false`), or right
+    /// after an error.
+    ///
+    /// > **Note**: the `limit` and `skip` fields of `options` are ignored; the pages are fetched
+    /// > with the given page size instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment, Result,
+    ///     api::*,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// for asset_migration in ctn_client.list_asset_migrations_iter(
+    ///     Some(ListAssetMigrationsOptions {
+    ///         direction: Some(AssetMigrationDirection::Outward),
+    ///         ..ListAssetMigrationsOptions::default()
+    ///     }),
+    ///     None,
+    /// ) {
+    ///     println!("Asset migration: {:?}", asset_migration?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_asset_migrations_iter(&self, options: Option<ListAssetMigrationsOptions>, page_size: Option<u16>) -> impl Iterator<Item = Result<AssetMigrationEntry>> + '_ {
+        let options = options.unwrap_or_default();
+
+        Paginator::new(page_size, move |limit, skip| {
+            let result = self.list_asset_migrations(Some(ListAssetMigrationsOptions {
+                limit: Some(limit),
+                skip: Some(skip),
+                ..options.clone()
+            }))?;
 
-        Ok(Self::parse_response::<ListAssetMigrationsResponse>(res)?.data)
+            Ok((result.asset_migrations, result.has_more))
+        })
     }
 
     /// Call *List Permission Events* API method.
@@ -2226,7 +2806,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2247,16 +2827,17 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_permission_events(&mut self) -> Result<ListPermissionEventsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_permission_events(&self) -> Result<ListPermissionEventsResult> {
         let req = self.get_request(
             "permission/events",
             None::<KVList>,
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListPermissionEvents, req)?;
 
-        Ok(Self::parse_response::<ListPermissionEventsResponse>(res)?.data)
+        Ok(Self::parse_api_response::<ListPermissionEventsResponse>(res)?.data)
     }
 
     /// Call *Retrieve Permission Rights* API method.
@@ -2270,7 +2851,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2327,7 +2908,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_permission_rights(&mut self, event: PermissionEvent) -> Result<RetrievePermissionRightsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_permission_rights(&self, event: PermissionEvent) -> Result<RetrievePermissionRightsResult> {
         let req = self.get_request(
             "permission/events/:event_name/rights",
             Some(&[
@@ -2336,9 +2918,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrievePermissionRights, req)?;
 
-        Ok(Self::parse_response::<RetrievePermissionRightsResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrievePermissionRightsResponse>(res)?.data)
     }
 
     /// Call *Set Permission Rights* API method.
@@ -2352,7 +2934,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2405,7 +2987,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_permission_rights(&mut self, event: PermissionEvent, rights: AllPermissionRightsUpdate) -> Result<SetPermissionRightsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn set_permission_rights(&self, event: PermissionEvent, rights: AllPermissionRightsUpdate) -> Result<SetPermissionRightsResult> {
         let body_json = serde_json::to_string(&rights)?;
         let req = self.post_request(
             "permission/events/:event_name/rights",
@@ -2416,9 +2999,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::SetPermissionRights, req)?;
 
-        Ok(Self::parse_response::<SetPermissionRightsResponse>(res)?.data)
+        Ok(Self::parse_api_response::<SetPermissionRightsResponse>(res)?.data)
     }
 
     /// Call *Check Effective Permission Right* API method.
@@ -2432,7 +3015,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2459,7 +3042,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn check_effective_permission_right(&mut self, event: PermissionEvent, device: DeviceId) -> Result<CheckEffectivePermissionRightResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn check_effective_permission_right(&self, event: PermissionEvent, device: DeviceId) -> Result<CheckEffectivePermissionRightResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let is_prod_unique_id;
@@ -2484,9 +3068,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::CheckEffectivePermissionRight, req)?;
 
-        Ok(Self::parse_response::<CheckEffectivePermissionRightResponse>(res)?.data)
+        Ok(Self::parse_api_response::<CheckEffectivePermissionRightResponse>(res)?.data)
     }
 
     /// Call *Retrieve Device Identification Info* API method.
@@ -2500,7 +3084,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2526,7 +3110,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_device_identification_info(&mut self, device: DeviceId) -> Result<RetrieveDeviceIdentificationInfoResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_device_identification_info(&self, device: DeviceId) -> Result<RetrieveDeviceIdentificationInfoResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let is_prod_unique_id;
@@ -2550,9 +3135,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveDeviceIdentificationInfo, req)?;
 
-        Ok(Self::parse_response::<RetrieveDeviceIdentificationInfoResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrieveDeviceIdentificationInfoResponse>(res)?.data)
     }
 
     /// Call *List Notification Events* API method.
@@ -2566,7 +3151,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2587,16 +3172,17 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_notification_events(&mut self) -> Result<ListNotificationEventsResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn list_notification_events(&self) -> Result<ListNotificationEventsResult> {
         let req = self.get_request(
             "notification/events",
             None::<KVList>,
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ListNotificationEvents, req)?;
 
-        Ok(Self::parse_response::<ListNotificationEventsResponse>(res)?.data)
+        Ok(Self::parse_api_response::<ListNotificationEventsResponse>(res)?.data)
     }
 
     /// Call *Issue Non-Fungible Asset* API method.
@@ -2610,7 +3196,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2666,8 +3252,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn issue_non_fungible_asset(
-        &mut self,
+        &self,
         issuance_info_or_cont_token: NFAssetIssuanceInfoOrContToken,
         non_fungible_tokens: Option<Vec<Option<NewNonFungibleTokenInfo>>>,
         is_final: Option<bool>
@@ -2704,9 +3291,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::IssueNonFungibleAsset, req)?;
 
-        Ok(Self::parse_response::<IssueNonFungibleAssetResponse>(res)?.data)
+        Ok(Self::parse_api_response::<IssueNonFungibleAssetResponse>(res)?.data)
     }
 
     /// Call *Reissue Non-Fungible Asset* API method.
@@ -2720,7 +3307,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2771,8 +3358,9 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
     pub fn reissue_non_fungible_asset(
-        &mut self,
+        &self,
         asset_id: &str,
         reissuance_info_or_cont_token: NFAssetReissuanceInfoOrContToken,
         non_fungible_tokens: Option<Vec<Option<NewNonFungibleTokenInfo>>>,
@@ -2810,9 +3398,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::ReissueNonFungibleAsset, req)?;
 
-        Ok(Self::parse_response::<ReissueNonFungibleAssetResponse>(res)?.data)
+        Ok(Self::parse_api_response::<ReissueNonFungibleAssetResponse>(res)?.data)
     }
 
     /// Call *Retrieve Non-Fungible Asset Issuance Progress* API method.
@@ -2826,7 +3414,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2871,7 +3459,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_non_fungible_asset_issuance_progress(&mut self, issuance_id: &str) -> Result<RetrieveNFAssetIssuanceProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_non_fungible_asset_issuance_progress(&self, issuance_id: &str) -> Result<RetrieveNFAssetIssuanceProgressResult> {
         let req = self.get_request(
             "assets/non-fungible/issuance/:issuance_id",
             Some(&[
@@ -2880,9 +3469,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleAssetIssuanceProgress, req)?;
 
-        Ok(Self::parse_response::<RetrieveNFAssetIssuanceProgressResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrieveNFAssetIssuanceProgressResponse>(res)?.data)
     }
 
     /// Call *Retrieve Non-Fungible Token* API method.
@@ -2903,7 +3492,7 @@ impl CatenisClient {
     /// }
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -2975,7 +3564,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_non_fungible_token(&mut self, token_id: &str, options: Option<RetrieveNonFungibleTokenOptions>) -> Result<RetrieveNonFungibleTokenResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_non_fungible_token(&self, token_id: &str, options: Option<RetrieveNonFungibleTokenOptions>) -> Result<RetrieveNonFungibleTokenResult> {
         // Prepare query parameters
         let mut params_vec = Vec::new();
         let retrieve_contents;
@@ -3036,9 +3626,9 @@ impl CatenisClient {
             query_params,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleToken, req)?;
 
-        Ok(Self::parse_response::<RetrieveNonFungibleTokenResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrieveNonFungibleTokenResponse>(res)?.data)
     }
 
     /// Call *Retrieve Non-Fungible Token Retrieval Progress* API method.
@@ -3052,7 +3642,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -3101,7 +3691,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_non_fungible_token_retrieval_progress(&mut self, token_id: &str, retrieval_id: &str) -> Result<RetrieveNFTokenRetrievalProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_non_fungible_token_retrieval_progress(&self, token_id: &str, retrieval_id: &str) -> Result<RetrieveNFTokenRetrievalProgressResult> {
         let req = self.get_request(
             "assets/non-fungible/tokens/:token_id/retrieval/:retrieval_id",
             Some(&[
@@ -3111,9 +3702,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleTokenRetrievalProgress, req)?;
 
-        Ok(Self::parse_response::<RetrieveNFTokenRetrievalProgressResponse>(res)?.data)
+        Ok(Self::parse_api_response::<RetrieveNFTokenRetrievalProgressResponse>(res)?.data)
     }
 
     /// Call *Transfer Non-Fungible Token* API method.
@@ -3127,7 +3718,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -3153,7 +3744,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn transfer_non_fungible_token(&mut self, token_id: &str, receiving_device: DeviceId, async_: Option<bool>) -> Result<TransferNonFungibleTokenResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn transfer_non_fungible_token(&self, token_id: &str, receiving_device: DeviceId, async_: Option<bool>) -> Result<TransferNonFungibleTokenResult> {
         let body = TransferNonFungibleTokenRequest {
             receiving_device,
             async_,
@@ -3168,9 +3760,9 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::TransferNonFungibleToken, req)?;
 
-        Ok(Self::parse_response::<TransferNonFungibleTokenResponse>(res)?.data)
+        Ok(Self::parse_api_response::<TransferNonFungibleTokenResponse>(res)?.data)
     }
 
     /// Call *Retrieve Non-Fungible Token Transfer Progress* API method.
@@ -3184,7 +3776,7 @@ impl CatenisClient {
     /// };
     ///
     /// # fn main() -> Result<()> {
-    /// # let mut ctn_client = CatenisClient::new_with_options(
+    /// # let ctn_client = CatenisClient::new_with_options(
     /// #     Some((
     /// #         "drc3XdxNtzoucpw9xiRp",
     /// #         concat!(
@@ -3220,7 +3812,8 @@ impl CatenisClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn retrieve_non_fungible_token_transfer_progress(&mut self, token_id: &str, transfer_id: &str) -> Result<RetrieveNFTokenTransferProgressResult> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn retrieve_non_fungible_token_transfer_progress(&self, token_id: &str, transfer_id: &str) -> Result<RetrieveNFTokenTransferProgressResult> {
         let req = self.get_request(
             "assets/non-fungible/tokens/:token_id/transfer/:transfer_id",
             Some(&[
@@ -3230,30 +3823,231 @@ impl CatenisClient {
             None::<KVList>,
         )?;
 
-        let res = self.sign_and_send_request(req)?;
+        let res = self.sign_and_send_request(ApiMethod::RetrieveNonFungibleTokenTransferProgress, req)?;
+
+        Ok(Self::parse_api_response::<RetrieveNFTokenTransferProgressResponse>(res)?.data)
+    }
+
+    /// Call an arbitrary Catenis API method.
+    ///
+    /// This can be used to call API methods that are not (yet) directly supported by this
+    /// library. The request is signed, compressed and retried, and errors are handled, the same way
+    /// as for the other API methods.
+    ///
+    /// The `endpoint_url_path` is relative to the API's base URL (e.g. `"messages/:message_id"`),
+    /// and may contain `:param` placeholders that are replaced with the values of the
+    /// corresponding `url_params`. The `data` property of the response is deserialized into the
+    /// requested type, which can be [`JsonValue`] to get it as raw JSON.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use catenis_api_client::{
+    /// #     CatenisClient, ClientOptions, Environment,
+    ///     JsonValue, Method, Result,
+    /// };
+    ///
+    /// # fn main() -> Result<()> {
+    /// # let ctn_client = CatenisClient::new_with_options(
+    /// #     Some((
+    /// #         "drc3XdxNtzoucpw9xiRp",
+    /// #         concat!(
+    /// #             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+    /// #             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+    /// #         ),
+    /// #     ).into()),
+    /// #     &[
+    /// #         ClientOptions::Environment(Environment::Sandbox),
+    /// #     ],
+    /// # )?;
+    /// #
+    /// let data: JsonValue = ctn_client.call_api(
+    ///     Method::GET,
+    ///     "messages/:message_id",
+    ///     &[("message_id", "o3muoTnnD6cXYyarYY38")],
+    ///     &[("encoding", "utf8")],
+    ///     None,
+    /// )?;
+    ///
+    /// println!("Read message: {}", data["msgData"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(endpoint, status, latency_ms, retries, request_size, response_size)))]
+    pub fn call_api<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint_url_path: &str,
+        url_params: &[(&str, &str)],
+        query_params: &[(&str, &str)],
+        body: Option<&JsonValue>,
+    ) -> Result<T> {
+        let body_json = match body {
+            Some(body) => serde_json::to_string(body)?,
+            None => String::new(),
+        };
+        let req = self.request_with_body(
+            method,
+            endpoint_url_path,
+            body_json,
+            Some(url_params),
+            if query_params.is_empty() { None } else { Some(query_params) },
+        )?;
+
+        let res = self.sign_and_send_request(ApiMethod::CallApi, req)?;
 
-        Ok(Self::parse_response::<RetrieveNFTokenTransferProgressResponse>(res)?.data)
+        Ok(Self::parse_api_response::<GenericResponse<T>>(res)?.data)
     }
 
     // Definition of private methods
 
-    fn send_request(&self, req: Request) -> Result<Response> {
-        let res = self.http_client
-            .execute(req)
-            .map_err::<Error, _>(Into::into)?;
+    /// Clone of this client that signs requests on behalf of another virtual device.
+    ///
+    /// Everything but the credentials (and the cached signing key) is shared with this client,
+    /// including the underlying HTTP client and its connection pool.
+    pub(crate) fn for_device(&self, device_credentials: DeviceCredentials) -> Self {
+        CatenisClient {
+            signing: SigningState::new(Some(device_credentials)),
+            ..self.clone()
+        }
+    }
+
+    fn send_request(&self, api_method: ApiMethod, req: Request) -> Result<TracedResponse<Response>> {
+        versioning::check_method_support(api_method, self.api_version)?;
+
+        let mut attempt = 1;
+        let mut annotations = HashMap::new();
+        let mut call_trace = CallTrace::start(api_method.name(), &req, &self.metrics);
+        let mut failovers = 0;
+
+        loop {
+            self.circuit_breaker.check()?;
+
+            if let Some(delay) = self.rate_limiter.reserve() {
+                thread::sleep(delay);
+            }
+
+            let mut attempt_req = Self::clone_request(&req)?;
+            let endpoint = self.endpoints.select(attempt_req.url_mut());
+            self.interceptors.before_sign(&mut attempt_req, attempt, &mut annotations)?;
+            self.interceptors.after_sign(&mut attempt_req, attempt, &mut annotations)?;
+            call_trace.attempt(&attempt_req, attempt);
+
+            // Note: fail over at most once per alternative endpoint
+            let failover_endpoint = if failovers + 1 < self.endpoints.len() { Some(endpoint) } else { None };
+
+            match self.send_request_attempt(attempt_req, attempt, false, failover_endpoint, &annotations, &mut call_trace)? {
+                SendAttempt::Done(res) => return Ok(TracedResponse::new(res, call_trace)),
+                SendAttempt::Retry(delay) => thread::sleep(delay),
+                SendAttempt::Resign => (),
+                SendAttempt::Failover => failovers += 1,
+            }
+
+            attempt += 1;
+        }
+    }
+
+    fn sign_and_send_request(&self, api_method: ApiMethod, req: Request) -> Result<TracedResponse<Response>> {
+        versioning::check_method_support(api_method, self.api_version)?;
+
+        let mut attempt = 1;
+        let mut annotations = HashMap::new();
+        let mut call_trace = CallTrace::start(api_method.name(), &req, &self.metrics);
+        let mut failovers = 0;
+        let mut resigned = false;
+
+        loop {
+            self.circuit_breaker.check()?;
+
+            // Note: wait for the rate limiter before signing, so the signature's timestamp is
+            //  not left behind
+            if let Some(delay) = self.rate_limiter.reserve() {
+                thread::sleep(delay);
+            }
+
+            // Note: every attempt needs to be signed anew since its timestamp changes
+            let mut attempt_req = Self::clone_request(&req)?;
+            let endpoint = self.endpoints.select(attempt_req.url_mut());
+            self.interceptors.before_sign(&mut attempt_req, attempt, &mut annotations)?;
+            self.sign_request(&mut attempt_req)?;
+            self.interceptors.after_sign(&mut attempt_req, attempt, &mut annotations)?;
+            call_trace.attempt(&attempt_req, attempt);
+
+            // Note: fail over at most once per alternative endpoint
+            let failover_endpoint = if failovers + 1 < self.endpoints.len() { Some(endpoint) } else { None };
+
+            match self.send_request_attempt(attempt_req, attempt, !resigned, failover_endpoint, &annotations, &mut call_trace)? {
+                SendAttempt::Done(res) => return Ok(TracedResponse::new(res, call_trace)),
+                SendAttempt::Retry(delay) => thread::sleep(delay),
+                SendAttempt::Resign => resigned = true,
+                SendAttempt::Failover => failovers += 1,
+            }
+
+            attempt += 1;
+        }
+    }
+
+    fn send_request_attempt(&self, req: Request, attempt: u32, resign_on_skew: bool, failover_endpoint: Option<usize>, annotations: &HashMap<String, String>, call_trace: &mut CallTrace) -> Result<SendAttempt<Response>> {
+        let method = req.method().clone();
+        let res = match self.http_client.execute(req) {
+            Ok(res) => res,
+            Err(err) => {
+                self.circuit_breaker.record_failure();
+
+                if pinning::is_pin_mismatch_cause(&err) {
+                    // Note: never retry nor fail over a connection rejected for not matching the pins
+                    return Err(Error::new_pin_mismatch_error(None));
+                }
+
+                if let Some(endpoint) = failover_endpoint {
+                    if Endpoints::fails_over_on_error(&err) && self.endpoints.fail_over(endpoint) {
+                        return Ok(SendAttempt::Failover);
+                    }
+                }
+
+                return if let Some(delay) = self.retry_policy.delay_for_error(attempt, &err) {
+                    Ok(SendAttempt::Retry(delay))
+                } else {
+                    Err(err.into())
+                };
+            }
+        };
+
+        call_trace.response(res.status());
+
+        let skew_changed = self.clock_skew.update(res.headers());
+
+        if res.status() == StatusCode::TOO_MANY_REQUESTS {
+            self.rate_limiter.throttle(RetryPolicy::parse_retry_after(res.headers()));
+        } else if res.status().is_success() {
+            self.rate_limiter.recover();
+        }
+
+        if res.status().is_server_error() {
+            self.circuit_breaker.record_failure();
+        } else {
+            self.circuit_breaker.record_success();
+        }
+
+        self.interceptors.after_response(res.status(), res.url(), res.headers(), attempt, annotations)?;
 
         if res.status().is_success() {
-            Ok(res)
+            Ok(SendAttempt::Done(res))
+        } else if failover_endpoint.filter(|&endpoint| self.endpoints.fails_over_on_status(res.status())
+                && self.retry_policy.may_resend(&method, res.status(), res.headers())
+                && self.endpoints.fail_over(endpoint)).is_some() {
+            Ok(SendAttempt::Failover)
+        } else if res.status() == StatusCode::UNAUTHORIZED && skew_changed && resign_on_skew {
+            // Authentication probably failed due to the local clock being off. Sign request
+            //  anew with the corrected time
+            Ok(SendAttempt::Resign)
+        } else if let Some(delay) = self.retry_policy.delay_for_response(attempt, &method, res.status(), res.headers()) {
+            Ok(SendAttempt::Retry(delay))
         } else {
             Err(Error::from_http_response(res))
         }
     }
 
-    fn sign_and_send_request(&mut self, mut req: Request) -> Result<Response> {
-        self.sign_request(&mut req)?;
-        self.send_request(req)
-    }
-
     fn get_request<I, K, V, I2, K2, V2>(&self, endpoint_url_path: &str, url_params: Option<I>, query_params: Option<I2>) -> Result<Request>
         where
             I: IntoIterator,
@@ -3292,6 +4086,20 @@ impl CatenisClient {
             K2: AsRef<str>,
             V2: AsRef<str>,
             <I2 as IntoIterator>::Item: Borrow<(K2, V2)>,
+    {
+        self.request_with_body(Method::POST, endpoint_url_path, body, url_params, query_params)
+    }
+
+    fn request_with_body<I, K, V, I2, K2, V2>(&self, method: Method, endpoint_url_path: &str, body: String, url_params: Option<I>, query_params: Option<I2>) -> Result<Request>
+        where
+            I: IntoIterator,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            <I as IntoIterator>::Item: Borrow<(K, V)>,
+            I2: IntoIterator,
+            K2: AsRef<str>,
+            V2: AsRef<str>,
+            <I2 as IntoIterator>::Item: Borrow<(K2, V2)>,
     {
         let mut endpoint_url_path = String::from(endpoint_url_path);
 
@@ -3300,7 +4108,7 @@ impl CatenisClient {
         }
 
         let mut req_builder = self.http_client
-            .post(self.base_api_url.join(&endpoint_url_path)?);
+            .request(method, self.base_api_url.join(&endpoint_url_path)?);
 
         if body.len() > 0 {
             // Prepare to add body to request
@@ -3308,8 +4116,8 @@ impl CatenisClient {
 
             if self.use_compression && body.len() >= self.compress_threshold {
                 // Add compressed body
-                req_builder = req_builder.body(Self::compress_body(body)?)
-                    .header(CONTENT_ENCODING, HeaderValue::from_static("deflate"));
+                req_builder = req_builder.body(Self::compress_body(body, self.compression.request)?)
+                    .header(CONTENT_ENCODING, HeaderValue::from_static(self.compression.request.content_encoding()));
             } else {
                 // Add plain body
                 req_builder = req_builder.body(body)
@@ -3324,7 +4132,9 @@ impl CatenisClient {
             .map_err(Into::into)
     }
 
-    pub(crate) fn get_ws_request<I, K, V>(&self, endpoint_url_path: &str, url_params: Option<I>) -> Result<Request>
+    /// Assemble the request used to open a WebSocket notification channel, against the endpoint
+    /// in use, returning it along with the index of that endpoint.
+    pub(crate) fn get_ws_request<I, K, V>(&self, endpoint_url_path: &str, url_params: Option<I>) -> Result<(Request, usize)>
         where
             I: IntoIterator,
             K: AsRef<str>,
@@ -3332,149 +4142,161 @@ impl CatenisClient {
             <I as IntoIterator>::Item: Borrow<(K, V)>,
     {
         let mut req = self.get_request(endpoint_url_path, url_params, None::<KVList>)?;
+        let endpoint = self.endpoints.select(req.url_mut());
 
         // Replace URL scheme as appropriate
-        if let Err(_) = req.url_mut().set_scheme(if self.is_secure {"wss"} else {"ws"}) {
+        let ws_scheme = if req.url().scheme() == "https" {"wss"} else {"ws"};
+
+        if req.url_mut().set_scheme(ws_scheme).is_err() {
             return Err(Error::new_client_error(Some("Error resetting URL scheme"), None::<GenericError>));
         }
 
-        Ok(req)
+        Ok((req, endpoint))
     }
 
-    pub(crate) fn sign_request(&mut self, req: &mut Request) -> Result<()> {
-        let mut new_headers = HeaderMap::new();
-        let now;
-        let timestamp;
-
-        // Identify new headers that need to be added to HTTP request
-        {
-            let headers = req.headers();
-
-            if !headers.contains_key(HOST) {
-                // Prepare to add missing 'host' header to HTTP request
-                if let Some(host) = Self::get_host_with_port(req.url()) {
-                    new_headers.insert(HOST, host.parse()?);
-                } else {
-                    return Err(Error::new_client_error(Some("Inconsistent HTTP request: URL missing host"), None::<GenericError>));
-                }
-            }
-
-            // Prepare to add custom 'x-bcot-timestamp' header to HTTP request
-            now = now!();
-            timestamp = now.format("%Y%m%dT%H%M%SZ");
-            new_headers.insert(X_BCOT_TIMESTAMP, timestamp.parse()?);
-        }
-
-        // Add headers to HTTP request
-        {
-            for (key, value) in new_headers.iter() {
-                let val = value.clone();
-
-                req.headers_mut().insert(key, val);
-            }
-        }
-
-        // Prepare to sign HTTP request
-
-        // 1. Assemble conformed request
-
-        // 1.1. Add HTTP verb
-        let mut conformed_request: String = req.method().to_string() + "\n";
-
-        // 1.2. Add URL path
-        conformed_request = conformed_request + &Self::get_url_path_with_query(req.url()) + "\n";
-
-        // 1.3. Assemble and add essential headers
-        {
-            let essential_headers_list = [
-                HOST,
-                HeaderName::from_static(X_BCOT_TIMESTAMP)
-            ];
-            let mut essential_headers = String::from("");
-            let headers = req.headers();
-
-            for header_name in essential_headers_list.iter() {
-                essential_headers = essential_headers + header_name.as_str() + ":" + headers.get(header_name).unwrap().to_str()? + "\n";
+    pub(crate) fn sign_request(&self, req: &mut Request) -> Result<()> {
+        if !req.headers().contains_key(HOST) {
+            // Add missing 'host' header to HTTP request
+            if let Some(host) = Self::get_host_with_port(req.url()) {
+                req.headers_mut().insert(HOST, host.parse()?);
+            } else {
+                return Err(Error::new_client_error(Some("Inconsistent HTTP request: URL missing host"), None::<GenericError>));
             }
-
-            conformed_request = conformed_request + &essential_headers + "\n";
         }
 
-        // 1.4. Hash HTTP request payload and add it
+        let host = String::from(req.headers().get(HOST).unwrap().to_str()?);
+        let method = req.method().to_string();
+        let path_with_query = Self::get_url_path_with_query(req.url());
         let payload = if let Some(body) = req.body_mut() {
             body.as_bytes().expect("Unable to access request body; body not buffered")
         } else {
             b""
         };
 
-        conformed_request = conformed_request + &sha256::Hash::hash(payload).to_hex() + "\n";
+        let (timestamp, authorization) = self.signing.sign(&method, &path_with_query, &host, payload, &self.clock_skew.now())?;
 
-        // 2. Update sign date and signing key
-        self.check_update_sign_date_and_key(&now)?;
+        // Add 'x-bcot-timestamp' and 'authorization' headers to HTTP request
+        req.headers_mut().insert(X_BCOT_TIMESTAMP, timestamp.parse()?);
+        req.headers_mut().insert(AUTHORIZATION, authorization.parse()?);
 
-        // 3. Assemble string to sign
-        let scope = self.sign_date.unwrap().format("%Y%m%d") + "/ctn1_request";
-        let string_to_sign = String::from("CTN1-HMAC-SHA256\n") + &timestamp + "\n"
-            + &scope + "\n"
-            + &sha256::Hash::hash(conformed_request.as_bytes()).to_hex() + "\n";
+        Ok(())
+    }
 
-        // 4. Generate signature
-        let mut hmac_engine = HmacEngine::<sha256::Hash>::new(&self.signing_key.unwrap());
-        hmac_engine.input(string_to_sign.as_bytes());
-        let signature = Hmac::<sha256::Hash>::from_engine(hmac_engine).to_hex();
+    // Definition of private associated ("static") functions
 
-        // Add 'authorization' header to HTTP request
-        let value = String::from("CTN1-HMAC-SHA256 Credential=") + self.get_device_id_ref()?.as_str() + "/"
-            + &scope + ",Signature=" + &signature;
+    fn parse_api_response<T: DeserializeOwned>(res: TracedResponse<Response>) -> Result<T> {
+        let (res, mut call_trace) = res.into_parts();
+        let parsed = Self::parse_response(res)?;
 
-        req.headers_mut().insert(AUTHORIZATION, value.parse()?);
+        // Note: the call is only deemed successful once its response has been parsed
+        call_trace.succeeded();
 
-        Ok(())
+        Ok(parsed)
     }
 
-    // Definition of private associated ("static") functions
-
     fn parse_response<T: DeserializeOwned>(res: Response) -> Result<T> {
         let body = res.text()
-            .map_err::<Error, _>(|e| Error::new_client_error(Some("Inconsistent Catenis API response"), Some(e)))?;
+            .map_err::<Error, _>(|e| if e.is_timeout() {
+                e.into()
+            } else {
+                Error::new_client_error(Some("Inconsistent Catenis API response"), Some(e))
+            })?;
+
+        trace::record_response_size(body.len());
 
         serde_json::from_str(&body)
             .map_err::<Error, _>(|e| Error::new_client_error(Some("Inconsistent Catenis API response"), Some(e)))
     }
 
-    fn new_http_client(use_compression: bool) -> reqwest::Result<HttpClient> {
+    fn new_http_client(use_compression: bool, compression: &CompressionOptions, connection_settings: &ConnectionSettings) -> Result<HttpClient> {
         let mut client_builder = HttpClientBuilder::new();
 
         // Prepare to add default HTTP headers
         let mut headers = HeaderMap::new();
 
         if use_compression {
-            headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+            if let Some(accept_encoding) = compression.accept_encoding() {
+                headers.insert(ACCEPT_ENCODING, accept_encoding);
+            }
 
             // Make sure that compressed response body is automatically decompressed
-            client_builder = client_builder.gzip(true);
+            client_builder = client_builder
+                .gzip(compression.accept_gzip)
+                .deflate(compression.accept_deflate)
+                .brotli(compression.accept_brotli);
         } else {
             // Make sure that response is not compressed
-            client_builder = client_builder.gzip(false);
+            client_builder = client_builder
+                .gzip(false)
+                .deflate(false)
+                .brotli(false);
         }
 
-        client_builder
-            .default_headers(headers)
-            .build()
-    }
+        if let Some(timeout) = connection_settings.request_timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
 
-    fn compress_body(body: String) -> Result<Vec<u8>> {
-        let mut enc = ZlibEncoder::new(body.as_bytes(), Compression::default());
-        let mut enc_body = Vec::new();
-        enc.read_to_end(&mut enc_body)?;
+        if let Some(timeout) = connection_settings.connect_timeout {
+            client_builder = client_builder.connect_timeout(timeout);
+        }
 
-        Ok(enc_body)
-    }
-}
+        if let Some(proxy) = &connection_settings.proxy {
+            client_builder = client_builder.proxy(proxy.to_http_proxy());
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // Set up TLS
+        #[cfg(any(feature = "native-tls", feature = "rustls"))]
+        {
+            match &connection_settings.pinned_keys {
+                // Note: public keys can only be pinned with the `rustls` feature. The server's
+                //  certificate chain is checked against the pins during the TLS handshake, so no
+                //  request is ever sent to a server that does not match them
+                #[cfg(feature = "rustls")]
+                Some(pinned_keys) => {
+                    client_builder = client_builder.use_preconfigured_tls(connection_settings.tls.http_rustls_config(pinned_keys)?);
+                }
+                _ => {
+                    #[cfg(feature = "rustls")]
+                    {
+                        client_builder = client_builder.use_rustls_tls();
+                    }
+
+                    for cert in connection_settings.tls.http_root_certificates()? {
+                        client_builder = client_builder.add_root_certificate(cert);
+                    }
+
+                    if let Some(identity) = connection_settings.tls.http_identity()? {
+                        client_builder = client_builder.identity(identity);
+                    }
+
+                    client_builder = client_builder.tls_built_in_root_certs(connection_settings.tls.use_built_in_roots);
+                }
+            }
+        }
+        #[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+        {
+            connection_settings.tls.check_tls_not_required()?;
+        }
+
+        Ok(client_builder
+            .default_headers(headers)
+            .build()?)
+    }
+
+    fn clone_request(req: &Request) -> Result<Request> {
+        req.try_clone()
+            .ok_or_else(|| Error::new_client_error(Some("Unable to clone HTTP request; body not buffered"), None::<GenericError>))
+    }
+
+    fn compress_body(body: String, compression: RequestCompression) -> Result<Vec<u8>> {
+        Ok(compression.compress(body.as_bytes())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use super::*;
     use crate::{
         test_helper::{
             http_server::{
@@ -3483,6 +4305,8 @@ mod tests {
             },
         },
     };
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    use crate::test_helper::http_server::TEST_CA_CERT;
 
     #[test]
     fn it_issue_api_error() {
@@ -3501,7 +4325,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "d8YpQ7jgPBJEkBrnvp58",
                 "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -3524,7 +4348,7 @@ mod tests {
     #[test]
     fn it_fail_call_api_no_credentials() {
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             None,
             &[
                 ClientOptions::Host("localhost:4001"),
@@ -3581,7 +4405,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3650,7 +4474,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3719,7 +4543,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3792,7 +4616,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3866,7 +4690,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -3940,7 +4764,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4021,7 +4845,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "d8YpQ7jgPBJEkBrnvp58",
                 "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -4092,7 +4916,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "d8YpQ7jgPBJEkBrnvp58",
                 "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -4165,7 +4989,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "d8YpQ7jgPBJEkBrnvp58",
                 "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
@@ -4239,7 +5063,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4305,7 +5129,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4376,7 +5200,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4821,7 +5645,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -4897,7 +5721,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5000,7 +5824,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5121,7 +5945,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5188,7 +6012,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5253,7 +6077,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5324,7 +6148,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5391,7 +6215,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5470,7 +6294,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5514,6 +6338,49 @@ mod tests {
         });
     }
 
+    #[test]
+    fn it_list_owned_assets_iter() {
+        // Simulate two pages of successful 'List Owned Assets' API method responses
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"ownedAssets":[{"assetId":"aA2XY6SLvcNAjkQSrQXR","balance":{"total":150,"unconfirmed":0}},{"assetId":"aBy2ovnucyWaSB6Tro9x","balance":{"total":1445.75,"unconfirmed":0}}],"hasMore":true}}"#).unwrap(),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"ownedAssets":[{"assetId":"aLdqZdNAaxisqySiXtQZ","balance":{"total":150,"unconfirmed":0}}],"hasMore":false}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let asset_ids: Vec<String> = ctn_client.list_owned_assets_iter(Some(2))
+            .map(|owned_asset| owned_asset.unwrap().asset_id)
+            .collect();
+
+        assert_eq!(asset_ids, vec![
+            String::from("aA2XY6SLvcNAjkQSrQXR"),
+            String::from("aBy2ovnucyWaSB6Tro9x"),
+            String::from("aLdqZdNAaxisqySiXtQZ"),
+        ]);
+    }
+
     #[test]
     fn it_list_issued_assets() {
         // Simulate successful 'List Issued Assets' API method response
@@ -5563,7 +6430,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5657,7 +6524,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5706,6 +6573,79 @@ mod tests {
         });
     }
 
+    #[test]
+    fn it_retrieve_asset_issuance_history_api_0_11() {
+        // Simulate successful 'Retrieve Asset Issuance History' API method response of API version 0.11
+
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "issuanceEvents": [
+      {
+        "amount": 1500,
+        "holdingDevice": {
+          "deviceId": "drc3XdxNtzoucpw9xiRp",
+          "name": "TstDev1"
+        },
+        "date": "2020-12-23T10:51:45.935Z"
+      }
+    ],
+    "hasMore": false
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("GET")),
+            path: Some(String::from("/api/0.11/assets/aBy2ovnucyWaSB6Tro9x/issuance")),
+            headers: None,
+            body: None,
+        });
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Version(ApiVersion(0, 11)),
+            ],
+        ).unwrap();
+
+        let result = ctn_client.retrieve_asset_issuance_history(
+            "aBy2ovnucyWaSB6Tro9x",
+            None,
+            None,
+            None,
+            None,
+        ).unwrap();
+
+        assert_eq!(result, RetrieveAssetIssuanceHistoryResult {
+            issuance_events: vec![
+                AssetIssuanceEventEntry::Regular(RegularAssetIssuanceEventEntry {
+                    amount: 1500.0,
+                    holding_device: DeviceInfo {
+                        device_id: String::from("drc3XdxNtzoucpw9xiRp"),
+                        name: Some(String::from("TstDev1")),
+                        prod_unique_id: None,
+                    },
+                    date: "2020-12-23T10:51:45.935Z".into(),
+                }),
+            ],
+            has_more: false,
+        });
+    }
+
     #[test]
     fn it_list_asset_holders() {
         // Simulate successful 'List Asset Holders' API method response
@@ -5771,7 +6711,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5867,7 +6807,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -5949,7 +6889,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6031,7 +6971,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6116,7 +7056,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6209,7 +7149,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6292,7 +7232,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6374,7 +7314,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6479,7 +7419,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6618,7 +7558,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6739,7 +7679,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6849,7 +7789,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -6944,7 +7884,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7046,7 +7986,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7123,7 +8063,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7200,7 +8140,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7273,14 +8213,14 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.send_request(req);
+        let result = ctn_client.send_request(ApiMethod::ReadMessage, req);
 
         assert!(!result.is_err(), "Returned error from sending request");
 
         let res = result.unwrap();
 
         assert!(res.status().is_success(), "Unexpected HTTP response: not success");
-        assert_eq!(res.text().unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
+        assert_eq!(res.into_parts().0.text().unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
     }
 
     #[test]
@@ -7322,7 +8262,7 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.send_request(req);
+        let result = ctn_client.send_request(ApiMethod::ReadMessage, req);
 
         assert!(result.is_err(), "Returned success from sending request");
         assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [400] - Invalid message ID");
@@ -7344,7 +8284,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7366,14 +8306,14 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.sign_and_send_request(req);
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
 
         assert!(!result.is_err(), "Returned error from sending request");
 
         let res = result.unwrap();
 
         assert!(res.status().is_success(), "Unexpected HTTP response: not success");
-        assert_eq!(res.text().unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
+        assert_eq!(res.into_parts().0.text().unwrap(), r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#);
     }
 
     #[test]
@@ -7393,7 +8333,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7415,20 +8355,44 @@ mod tests {
             ]),
         ).unwrap();
 
-        let result = ctn_client.sign_and_send_request(req);
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
 
         assert!(result.is_err(), "Returned success from sending request");
         assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [400] - Invalid message ID");
     }
 
     #[test]
-    fn it_assemble_get_request() {
+    fn it_sign_and_send_request_retry_success() {
+        // Simulate transient error followed by successful 'Read Message' API method response
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    503,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Service unavailable"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        ).with_response_header("Retry-After", "0");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
         // Instantiate Catenis API client
-        let ctn_client = CatenisClient::new(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy::default()),
+            ],
         ).unwrap();
 
         // Get request used for calling 'Read Message' API method
@@ -7442,28 +8406,986 @@ mod tests {
             ]),
         ).unwrap();
 
-        assert_eq!(req.method().to_string(), "GET");
-        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/oNXszDazhuq4utktSMMi?encoding=utf8", DEFAULT_API_VERSION.to_string()));
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
+
+        assert!(result.is_ok(), "Returned error from sending request");
+        assert!(result.unwrap().status().is_success(), "Unexpected HTTP response: not success");
     }
 
     #[test]
-    fn it_assemble_post_request() {
+    fn it_sign_and_send_request_retry_exhausted() {
+        // Simulate persistent error 'Read Message' API method response
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    503,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Service unavailable"}"#).unwrap()),
+                ),
+                HttpServerMode::Error(
+                    503,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Service still unavailable"}"#).unwrap()),
+                ),
+            ]),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
         // Instantiate Catenis API client
-        let ctn_client = CatenisClient::new(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy {
+                    max_attempts: 2,
+                    initial_backoff: std::time::Duration::from_millis(10),
+                    ..RetryPolicy::default()
+                }),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            Some(&[
+                ("encoding", "utf8"),
+            ]),
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
+
+        assert!(result.is_err(), "Returned success from sending request");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [503] - Service still unavailable");
+    }
+
+    #[test]
+    fn it_sign_and_send_request_no_retry_non_idempotent() {
+        // Simulate transient error 'Log Message' API method response
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    500,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Internal server error"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"messageId":"mdx8vuCGWdb2TFeWFZd6"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy {
+                    initial_backoff: std::time::Duration::from_millis(10),
+                    ..RetryPolicy::default()
+                }),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message"}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::LogMessage, req);
+
+        assert!(result.is_err(), "Returned success from sending request");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [500] - Internal server error");
+    }
+
+    #[test]
+    fn it_send_request_no_retry_client_error() {
+        // Simulate error 'Read Message' API method response
+
+        // Start HTTP server in error simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Error(
+                400,
+                Some(HttpBody::from_json(r#"{"status":"error","message":"Invalid message ID"}"#).unwrap()),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy::default()),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req);
+
+        assert!(result.is_err(), "Returned success from sending request");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis API error: [400] - Invalid message ID");
+    }
+
+    #[test]
+    fn it_send_request_timeout() {
+        // Simulate slow 'Retrieve Message Origin' API method response
+
+        // Start HTTP server in success simulation mode delaying the response
+        let http_server = HttpServer::new(
+            HttpServerMode::Delay(
+                Duration::from_millis(500),
+                Box::new(HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+                )),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Timeout(Duration::from_millis(100)),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req);
+
+        assert!(result.is_err(), "Returned success from sending request");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_timeout(), "Returned error is not a timeout error");
+        assert!(err.to_string().starts_with("Catenis client error: Request to Catenis API server timed out"));
+    }
+
+    #[cfg(feature = "rustls")]
+    #[test]
+    fn it_send_request_pinned_public_key() {
+        // Start HTTPS server in success simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+            ),
+            "localhost"
+        ).with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client pinning the server's public key
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::TlsRootCertificates(TEST_CA_CERT),
+                ClientOptions::PinnedPublicKeys(&["sha256//wkoiJEI3H1+FkhR2xwIdrQJbgRaMPty2Y+asZ3//BNs="]),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req);
+
+        assert!(result.is_ok(), "Failed to send request to server with pinned public key: {:?}", result.err());
+    }
+
+    #[cfg(feature = "rustls")]
+    #[test]
+    fn it_send_request_pinned_root_public_key() {
+        // Start HTTPS server in success simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+            ),
+            "localhost"
+        ).with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client pinning the public key of the (root) CA that issued the
+        //  server's certificate, which is not presented by the server
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::TlsRootCertificates(TEST_CA_CERT),
+                ClientOptions::PinnedPublicKeys(&["WFXP2PS2PCUkPpP8EMsWdZwpzbROpAC0cO6zMn0ifT8="]),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req);
+
+        assert!(result.is_ok(), "Failed to send request to server with pinned root public key: {:?}", result.err());
+    }
+
+    #[cfg(feature = "rustls")]
+    #[test]
+    fn it_send_request_pin_mismatch() {
+        // Start HTTPS server in success simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(r#"{"status":"success","data":{}}"#).unwrap(),
+            ),
+            "localhost"
+        ).with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client pinning a different public key
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::TlsRootCertificates(TEST_CA_CERT),
+                ClientOptions::PinnedPublicKeys(&["47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="]),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Retrieve Message Origin' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id/origin",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::RetrieveMessageOrigin, req);
+
+        assert!(result.is_err(), "Sent request to server not matching pinned public key");
+        assert!(result.err().unwrap().is_pin_mismatch(), "Returned error is not a pin mismatch error");
+    }
+
+    #[cfg(not(feature = "rustls"))]
+    #[test]
+    fn it_fail_new_client_pinning_not_enabled() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::PinnedPublicKeys(&["47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="]),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with pinned public keys and no rustls support");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Public key pinning not enabled: the `rustls` feature must be enabled");
+    }
+
+    #[test]
+    fn it_set_connection_timeouts() {
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Timeout(Duration::from_secs(10)),
+                ClientOptions::ConnectTimeout(Duration::from_secs(2)),
+                ClientOptions::WsHandshakeTimeout(Duration::from_secs(5)),
+            ],
+        ).unwrap();
+
+        assert_eq!(ctn_client.connection_settings.request_timeout, Some(Duration::from_secs(10)));
+        assert_eq!(ctn_client.connection_settings.connect_timeout, Some(Duration::from_secs(2)));
+        assert_eq!(ctn_client.connection_settings.ws_handshake_timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn it_share_rate_limit_among_clones() {
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::RateLimit(RateLimit {
+                    requests_per_second: 1.0,
+                    burst: 1,
+                }),
+            ],
+        ).unwrap();
+        let ctn_client_clone = ctn_client.clone();
+
+        assert!(ctn_client.rate_limiter.reserve().is_none(), "Request throttled within burst");
+        assert!(ctn_client_clone.rate_limiter.reserve().is_some(), "Rate limit not shared among clones");
+    }
+
+    #[test]
+    fn it_fail_instantiate_client_invalid_rate_limit() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::RateLimit(RateLimit {
+                    requests_per_second: -1.0,
+                    burst: 10,
+                }),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with invalid rate limit");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid rate limit");
+    }
+
+    #[test]
+    fn it_call_api_raw_json() {
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "msgInfo": {
+      "action": "log"
+    },
+    "msgData": "Test message"
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("GET")),
+            path: Some(format!("/api/{}/messages/oNXszDazhuq4utktSMMi?encoding=utf8", DEFAULT_API_VERSION)),
+            headers: Some(
+                vec![
+                    String::from("x-bcot-timestamp"),
+                    String::from("authorization"),
+                ].into_iter().zip(vec![
+                    None,
+                    None,
+                ]).collect()
+            ),
+            body: None,
+        });
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let result: JsonValue = ctn_client.call_api(
+            Method::GET,
+            "messages/:message_id",
+            &[("message_id", "oNXszDazhuq4utktSMMi")],
+            &[("encoding", "utf8")],
+            None,
+        ).unwrap();
+
+        assert_eq!(result, serde_json::json!({
+            "msgInfo": {
+                "action": "log"
+            },
+            "msgData": "Test message"
+        }));
+    }
+
+    #[test]
+    fn it_call_api_typed_result() {
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "messageId": "oX2mJHwFWp752beHbNDK"
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("POST")),
+            path: Some(format!("/api/{}/messages/log", DEFAULT_API_VERSION)),
+            headers: Some(
+                vec![
+                    String::from("x-bcot-timestamp"),
+                    String::from("authorization"),
+                    String::from("content-type"),
+                ].into_iter().zip(vec![
+                    None,
+                    None,
+                    Some(HttpHeader {
+                        field: String::from("content-type"),
+                        value: String::from("application/json; charset=utf-8"),
+                    }),
+                ]).collect()
+            ),
+            body: Some(String::from(r#"{"message":"Test message"}"#)),
+        });
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let result: LogMessageResult = ctn_client.call_api(
+            Method::POST,
+            "messages/log",
+            &[],
+            &[],
+            Some(&serde_json::json!({"message": "Test message"})),
+        ).unwrap();
+
+        assert_eq!(result, LogMessageResult {
+            continuation_token: None,
+            message_id: Some(String::from("oX2mJHwFWp752beHbNDK")),
+            provisional_message_id: None,
+        });
+    }
+
+    #[test]
+    fn it_fail_fast_with_circuit_open() {
+        // Simulate server error response to 'Read Message' API method
+
+        // Start HTTP server in error simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Error(
+                503,
+                Some(HttpBody::from_json(r#"{"status":"error","message":"Service unavailable"}"#).unwrap()),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::CircuitBreaker(CircuitBreakerPolicy {
+                    failure_threshold: 1,
+                    ..CircuitBreakerPolicy::default()
+                }),
+            ],
+        ).unwrap();
+        let ctn_client_clone = ctn_client.clone();
+
+        assert_eq!(ctn_client.circuit_state(), Some(CircuitState::Closed));
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, CatenisClient::clone_request(&req).unwrap());
+
+        assert!(result.is_err(), "Server error not returned");
+        assert!(result.err().unwrap().api_error_info().is_some(), "Unexpected error kind");
+        assert_eq!(ctn_client_clone.circuit_state(), Some(CircuitState::Open), "Circuit breaker not shared among clones");
+
+        // Next request should fail without being sent
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
+
+        assert!(result.is_err(), "Request sent while circuit breaker is open");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_circuit_open(), "Unexpected error kind: {}", err);
+        assert_eq!(err.to_string(), "Catenis client error: Circuit breaker open; request not sent");
+    }
+
+    #[test]
+    fn it_fail_instantiate_client_invalid_circuit_breaker() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::CircuitBreaker(CircuitBreakerPolicy {
+                    failure_threshold: 0,
+                    ..CircuitBreakerPolicy::default()
+                }),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with invalid circuit breaker policy");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid circuit breaker policy");
+        assert_eq!(CatenisClient::new(None).unwrap().circuit_state(), None);
+    }
+
+    #[test]
+    fn it_sign_and_send_request_compensate_clock_skew() {
+        // Simulate authentication failure due to clock skew followed by successful 'Read Message'
+        //  API method response
+
+        // Set custom "system" time one hour behind
+        let _custom_time = test_helper::time::CustomTime::set(&(time::OffsetDateTime::now_utc() - time::Duration::hours(1)));
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    401,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Authorization failed; timestamp not within acceptable time variation"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        assert_eq!(ctn_client.clock_skew_secs(), 0);
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
+
+        assert!(result.is_ok(), "Request not sent again after clock skew correction");
+        assert!((ctn_client.clock_skew_secs() - 3600).abs() <= 2, "Unexpected clock skew: {}", ctn_client.clock_skew_secs());
+    }
+
+    #[test]
+    fn it_sign_and_send_request_throttled() {
+        // Simulate throttling followed by successful 'Read Message' API method response
+
+        // Start HTTP server in sequence simulation node
+        let http_server = HttpServer::new(
+            HttpServerMode::Sequence(vec![
+                HttpServerMode::Error(
+                    429,
+                    Some(HttpBody::from_json(r#"{"status":"error","message":"Too many requests"}"#).unwrap()),
+                ),
+                HttpServerMode::Success(
+                    HttpBody::from_json(r#"{"status":"success","data":{"msgInfo":{"action":"send","from":{"deviceId":"d8YpQ7jgPBJEkBrnvp58","name":"TstDev2"}},"msgData":"Test message #1 (2020-11-30)"}}"#).unwrap(),
+                ),
+            ]),
+            "localhost"
+        ).with_response_header("Retry-After", "0");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::Retry(RetryPolicy::default()),
+                ClientOptions::RateLimit(RateLimit {
+                    requests_per_second: 5.0,
+                    burst: 1,
+                }),
+            ],
+        ).unwrap();
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let start = std::time::Instant::now();
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
+
+        assert!(result.is_ok(), "Returned error from sending request");
+
+        // Rate should have been halved (from 5 to 2.5 requests per second) after the 429 response,
+        //  so the retry must have waited for about 400 ms
+        assert!(start.elapsed() >= Duration::from_millis(350), "Request not throttled: {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn it_sign_and_send_request_with_interceptor() {
+        struct TraceInterceptor {
+            calls: std::sync::Mutex<Vec<String>>,
+        }
+
+        impl Interceptor for TraceInterceptor {
+            fn before_sign(&self, req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+                req.headers_mut().insert("x-trace-id", HeaderValue::from_static("a1b2c3d4"));
+                req.annotate("trace_id", "a1b2c3d4");
+
+                Ok(())
+            }
+
+            fn after_sign(&self, req: &InterceptedRequest) -> std::result::Result<(), Veto> {
+                self.calls.lock().unwrap().push(format!("after_sign signed: {}", req.headers().contains_key(AUTHORIZATION)));
+
+                Ok(())
+            }
+
+            fn after_response(&self, res: &InterceptedResponse) -> std::result::Result<(), Veto> {
+                self.calls.lock().unwrap().push(format!("after_response {} {:?}", res.status(), res.annotation("trace_id")));
+
+                Ok(())
+            }
+        }
+
+        // Start HTTP server in echo mode to retrieve HTTP request effectively sent
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let interceptor = Arc::new(TraceInterceptor {
+            calls: std::sync::Mutex::new(Vec::new()),
+        });
+        ctn_client.interceptors.add(interceptor.clone());
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.sign_and_send_request(ApiMethod::ReadMessage, req);
+
+        assert!(result.is_ok(), "Returned error from sending request");
+
+        // Parse returned HTTP request from response body
+        let res_body = result.unwrap().into_parts().0.text().unwrap();
+        let http_request = HttpRequest::from_json(&res_body).unwrap();
+
+        assert_eq!(http_request.headers.get("x-trace-id").unwrap().value, "a1b2c3d4");
+        assert_eq!(*interceptor.calls.lock().unwrap(), vec![
+            String::from("after_sign signed: true"),
+            String::from("after_response 200 OK Some(\"a1b2c3d4\")"),
+        ]);
+    }
+
+    #[test]
+    fn it_send_request_vetoed_by_interceptor() {
+        struct ReadOnlyInterceptor;
+
+        impl Interceptor for ReadOnlyInterceptor {
+            fn before_sign(&self, req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+                if req.method() == reqwest::Method::POST {
+                    Err(Veto::new("Write operations not allowed"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host("localhost:1"),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        ctn_client.add_interceptor(ReadOnlyInterceptor);
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message"}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).unwrap();
+
+        let result = ctn_client.send_request(ApiMethod::LogMessage, req);
+
+        assert!(result.is_err(), "Request not vetoed by interceptor");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_vetoed(), "Returned error is not a veto error");
+        assert_eq!(err.to_string(), "Catenis client error: Request vetoed by interceptor: Write operations not allowed");
+    }
+
+    #[test]
+    fn it_report_api_call_metrics() {
+        #[derive(Default)]
+        struct RecordingSink {
+            calls: std::sync::Mutex<Vec<String>>,
+        }
+
+        impl MetricsSink for RecordingSink {
+            fn record_api_call(&self, metrics: &ApiCallMetrics) {
+                self.calls.lock().unwrap().push(format!("{} {:?} {} {}", metrics.api_method, metrics.status, metrics.success, metrics.retries));
+            }
+        }
+
+        // Start HTTP server in error simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Error(
+                400,
+                Some(HttpBody::from_json(r#"{"status":"error","message":"Invalid message ID"}"#).unwrap()),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let sink = Arc::new(RecordingSink::default());
+        ctn_client.set_metrics_sink(sink.clone());
+
+        let result = ctn_client.read_message("oNXszDazhuq4utktSMMi", None);
+
+        assert!(result.is_err(), "Returned success from calling API method");
+        assert_eq!(*sink.calls.lock().unwrap(), vec![
+            String::from("read_message Some(400) false 0"),
+        ]);
+    }
+
+    #[test]
+    fn it_report_api_call_metrics_inconsistent_response() {
+        #[derive(Default)]
+        struct RecordingSink {
+            calls: std::sync::Mutex<Vec<String>>,
+        }
+
+        impl MetricsSink for RecordingSink {
+            fn record_api_call(&self, metrics: &ApiCallMetrics) {
+                self.calls.lock().unwrap().push(format!("{} {:?} {} {}", metrics.api_method, metrics.status, metrics.success, metrics.retries));
+            }
+        }
+
+        // Start HTTP server in success simulation mode, returning an invalid response body
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from(r#"{}"#),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let mut ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap();
+
+        let sink = Arc::new(RecordingSink::default());
+        ctn_client.set_metrics_sink(sink.clone());
+
+        let result = ctn_client.read_message("oNXszDazhuq4utktSMMi", None);
+
+        assert!(result.is_err(), "Returned success from calling API method");
+        assert_eq!(*sink.calls.lock().unwrap(), vec![
+            String::from("read_message Some(200) false 0"),
+        ]);
+    }
+
+    #[test]
+    fn it_assemble_get_request() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+        ).unwrap();
+
+        // Get request used for calling 'Read Message' API method
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            Some(&[
+                ("encoding", "utf8"),
+            ]),
+        ).unwrap();
+
+        assert_eq!(req.method().to_string(), "GET");
+        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/oNXszDazhuq4utktSMMi?encoding=utf8", DEFAULT_API_VERSION.to_string()));
+    }
+
+    #[test]
+    fn it_assemble_post_request() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+        ).unwrap();
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).unwrap();
+
+        assert_eq!(req.method().to_string(), "POST");
+        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
+        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
+        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
+        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"{\"message\":\"Test message\",\"options\":{\"encoding\":\"utf8\"}}");
+    }
+
+    #[test]
+    fn it_assemble_post_request_with_compression_not_done() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::UseCompression(true),
+                ClientOptions::CompressThreshold(57),
+            ]
+        ).unwrap();
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
         ).unwrap();
 
-        // Get request used for calling 'Log Message' API method
-        let req = ctn_client.post_request(
-            "messages/log",
-            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
-            None::<KVList>,
-            None::<KVList>,
-        ).unwrap();
-
         assert_eq!(req.method().to_string(), "POST");
         assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
         assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
@@ -7471,37 +9393,197 @@ mod tests {
         assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"{\"message\":\"Test message\",\"options\":{\"encoding\":\"utf8\"}}");
     }
 
-    #[test]
-    fn it_assemble_post_request_with_compression_not_done() {
-        // Instantiate Catenis API client
+    #[test]
+    fn it_assemble_post_request_with_compression() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::UseCompression(true),
+                ClientOptions::CompressThreshold(56),
+            ]
+        ).unwrap();
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).unwrap();
+
+        assert_eq!(req.method().to_string(), "POST");
+        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
+        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
+        assert_eq!(req.headers().get(CONTENT_ENCODING).unwrap(), "deflate");
+        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"\x78\x9c\x01\x38\x00\xc7\xff\x7b\x22\x6d\x65\x73\x73\x61\x67\x65\x22\x3a\x22\x54\x65\x73\x74\x20\x6d\x65\x73\x73\x61\x67\x65\x22\x2c\x22\x6f\x70\x74\x69\x6f\x6e\x73\x22\x3a\x7b\x22\x65\x6e\x63\x6f\x64\x69\x6e\x67\x22\x3a\x22\x75\x74\x66\x38\x22\x7d\x7d\x2e\x4c\x13\x83");
+    }
+
+    #[test]
+    fn it_assemble_post_request_with_gzip_compression() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Compression(CompressionOptions {
+                    request: RequestCompression::Gzip,
+                    ..CompressionOptions::default()
+                }),
+                ClientOptions::CompressThreshold(56),
+            ]
+        ).unwrap();
+        let body = r#"{"message":"Test message","options":{"encoding":"utf8"}}"#;
+
+        // Get request used for calling 'Log Message' API method
+        let req = ctn_client.post_request(
+            "messages/log",
+            String::from(body),
+            None::<KVList>,
+            None::<KVList>,
+        ).unwrap();
+
+        assert_eq!(req.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+
+        let mut dec_body = String::new();
+        flate2::read::GzDecoder::new(req.body().unwrap().as_bytes().unwrap()).read_to_string(&mut dec_body).unwrap();
+
+        assert_eq!(dec_body, body);
+
+        // Get same request with request compression turned off for this call only
+        let req = ctn_client.with_request_compression(false).post_request(
+            "messages/log",
+            String::from(body),
+            None::<KVList>,
+            None::<KVList>,
+        ).unwrap();
+
+        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
+        assert_eq!(req.body().unwrap().as_bytes().unwrap(), body.as_bytes());
+    }
+
+    #[test]
+    fn it_assemble_get_ws_request() {
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+        ).unwrap();
+
+        // Get request used to connect to WebSocket notification channel
+        let (req, _) = ctn_client.get_ws_request(
+            "notify/ws/:event_name",
+            Some(&[
+                ("event_name", "new-msg-received"),
+            ]),
+        ).unwrap();
+
+        assert_eq!(req.method().to_string(), "GET");
+        assert_eq!(req.url().to_string(), format!("wss://catenis.io/api/{}/notify/ws/new-msg-received", DEFAULT_API_VERSION.to_string()));
+    }
+
+    #[test]
+    fn it_fail_call_unsupported_in_api_version() {
+        // Instantiate Catenis API client targeting an API version that predates non-fungible assets
+        //  Note: the host is unreachable, so any request actually sent would fail differently
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::BaseUrl("http://localhost:1/"),
+                ClientOptions::Version(ApiVersion(0, 11)),
+            ],
+        ).unwrap();
+
+        assert_eq!(ctn_client.api_version(), ApiVersion(0, 11));
+        assert!(ctn_client.api_version().supports_method(ApiMethod::ExportAsset));
+        assert!(!ctn_client.api_version().supports_method(ApiMethod::RetrieveNonFungibleToken));
+
+        let result = ctn_client.retrieve_non_fungible_token("tDGQpGy627J6uAw4grYq", None);
+
+        assert!(result.is_err(), "Called method unsupported in API version");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_unsupported_api_version(), "Returned error is not an unsupported API version error");
+        assert_eq!(err.to_string(), "Catenis client error: Method retrieve_non_fungible_token is unsupported in API version 0.11 (requires API version 0.12 or later); request not sent");
+    }
+
+    #[test]
+    fn it_fail_over_to_next_endpoint() {
+        // Start HTTP server in success simulation node
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "msgInfo": {
+      "action": "log"
+    },
+    "msgData": "Test message"
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        ).with_expected_request(PartialHttpRequest {
+            method: Some(String::from("GET")),
+            path: Some(String::from("/gw/api/0.10/messages/oNXszDazhuq4utktSMMi")),
+            headers: None,
+            body: None,
+        });
+        http_server.start();
+
+        let alt_base_url = format!("http://localhost:{}/gw/", http_server.get_port());
+
+        // Instantiate Catenis API client whose primary endpoint is unreachable
         let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
             &[
-                ClientOptions::UseCompression(true),
-                ClientOptions::CompressThreshold(57),
-            ]
+                ClientOptions::BaseUrl("http://localhost:1/"),
+                ClientOptions::Version(ApiVersion(0, 10)),
+                ClientOptions::Failover(FailoverOptions {
+                    base_urls: &[&alt_base_url],
+                    ..FailoverOptions::default()
+                }),
+            ],
         ).unwrap();
 
-        // Get request used for calling 'Log Message' API method
-        let req = ctn_client.post_request(
-            "messages/log",
-            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
-            None::<KVList>,
-            None::<KVList>,
+        let result: JsonValue = ctn_client.call_api(
+            Method::GET,
+            "messages/:message_id",
+            &[("message_id", "oNXszDazhuq4utktSMMi")],
+            &[],
+            None,
         ).unwrap();
 
-        assert_eq!(req.method().to_string(), "POST");
-        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
-        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
-        assert!(!req.headers().contains_key(CONTENT_ENCODING), "Request contains unexpected Content-Encoding HTTP header");
-        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"{\"message\":\"Test message\",\"options\":{\"encoding\":\"utf8\"}}");
+        assert_eq!(result["msgData"], "Test message");
+
+        // Endpoint in use is remembered
+        let (req, _) = ctn_client.get_ws_request(
+            "notify/ws/:event_name",
+            Some(&[
+                ("event_name", "new-msg-received"),
+            ]),
+        ).unwrap();
+
+        assert_eq!(req.url().to_string(), format!("ws://localhost:{}/gw/api/0.10/notify/ws/new-msg-received", http_server.get_port()));
     }
 
     #[test]
-    fn it_assemble_post_request_with_compression() {
+    fn it_assemble_requests_with_base_url() {
         // Instantiate Catenis API client
         let ctn_client = CatenisClient::new_with_options(
             Some((
@@ -7509,46 +9591,48 @@ mod tests {
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
             ).into()),
             &[
-                ClientOptions::UseCompression(true),
-                ClientOptions::CompressThreshold(56),
-            ]
+                ClientOptions::BaseUrl("https://gw.internal/catenis"),
+                ClientOptions::Version(ApiVersion(0, 10)),
+            ],
         ).unwrap();
 
-        // Get request used for calling 'Log Message' API method
-        let req = ctn_client.post_request(
-            "messages/log",
-            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
-            None::<KVList>,
+        let req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
             None::<KVList>,
         ).unwrap();
 
-        assert_eq!(req.method().to_string(), "POST");
-        assert_eq!(req.url().to_string(), format!("https://catenis.io/api/{}/messages/log", DEFAULT_API_VERSION.to_string()));
-        assert_eq!(req.headers().get(CONTENT_TYPE).unwrap(), "application/json; charset=utf-8");
-        assert_eq!(req.headers().get(CONTENT_ENCODING).unwrap(), "deflate");
-        assert_eq!(req.body().unwrap().as_bytes().unwrap(), b"\x78\x9c\x01\x38\x00\xc7\xff\x7b\x22\x6d\x65\x73\x73\x61\x67\x65\x22\x3a\x22\x54\x65\x73\x74\x20\x6d\x65\x73\x73\x61\x67\x65\x22\x2c\x22\x6f\x70\x74\x69\x6f\x6e\x73\x22\x3a\x7b\x22\x65\x6e\x63\x6f\x64\x69\x6e\x67\x22\x3a\x22\x75\x74\x66\x38\x22\x7d\x7d\x2e\x4c\x13\x83");
-    }
+        assert_eq!(req.url().to_string(), "https://gw.internal/catenis/api/0.10/messages/oNXszDazhuq4utktSMMi");
 
-    #[test]
-    fn it_assemble_get_ws_request() {
-        // Instantiate Catenis API client
-        let ctn_client = CatenisClient::new(
-            Some((
-                "drc3XdxNtzoucpw9xiRp",
-                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
-            ).into()),
+        let (req, _) = ctn_client.get_ws_request(
+            "notify/ws/:event_name",
+            Some(&[
+                ("event_name", "new-msg-received"),
+            ]),
         ).unwrap();
 
-        // Get request used to connect to WebSocket notification channel
-        let req = ctn_client.get_ws_request(
+        assert_eq!(req.url().to_string(), "wss://gw.internal/catenis/api/0.10/notify/ws/new-msg-received");
+
+        // Instantiate Catenis API client with insecure base URL
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::BaseUrl("http://gw.internal:8080/catenis/"),
+                ClientOptions::Environment(Environment::Sandbox),
+                ClientOptions::Version(ApiVersion(0, 10)),
+            ],
+        ).unwrap();
+
+        let (req, _) = ctn_client.get_ws_request(
             "notify/ws/:event_name",
             Some(&[
                 ("event_name", "new-msg-received"),
             ]),
         ).unwrap();
 
-        assert_eq!(req.method().to_string(), "GET");
-        assert_eq!(req.url().to_string(), format!("wss://catenis.io/api/{}/notify/ws/new-msg-received", DEFAULT_API_VERSION.to_string()));
+        assert_eq!(req.url().to_string(), "ws://sandbox.gw.internal:8080/catenis/api/0.10/notify/ws/new-msg-received");
     }
 
     #[test]
@@ -7557,7 +9641,7 @@ mod tests {
         let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
 
         // Instantiate Catenis API client
-        let mut ctn_client = CatenisClient::new_with_options(
+        let ctn_client = CatenisClient::new_with_options(
             Some((
                 "drc3XdxNtzoucpw9xiRp",
                 "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7586,6 +9670,153 @@ mod tests {
         assert_eq!(req.headers().get(AUTHORIZATION).unwrap(), "CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4");
     }
 
+    #[test]
+    fn it_sign_request_rotated_credentials() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let secret = Arc::new(std::sync::Mutex::new(String::from("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3")));
+        let provider_secret = secret.clone();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::Host("localhost:3000"),
+                ClientOptions::Secure(false),
+                ClientOptions::Version(ApiVersion(0, 10)),
+            ],
+        ).unwrap();
+
+        ctn_client.set_credential_provider(move || -> Result<DeviceCredentials> {
+            Ok(("drc3XdxNtzoucpw9xiRp", provider_secret.lock().unwrap().as_str()).into())
+        }).unwrap();
+
+        let ctn_client_clone = ctn_client.clone();
+
+        // Get request used for calling 'Log Message' API method
+        let mut req = ctn_client_clone.post_request(
+            "messages/log",
+            String::from(r#"{"message":"Test message","options":{"encoding":"utf8"}}"#),
+            None::<KVList>,
+            None::<KVList>,
+        ).unwrap();
+        let mut req2 = CatenisClient::clone_request(&req).unwrap();
+
+        // Sign request
+        ctn_client_clone.sign_request(&mut req).unwrap();
+
+        assert_eq!(req.headers().get(AUTHORIZATION).unwrap(), "CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4");
+
+        // Rotate credentials, and sign request again from client's clone
+        *secret.lock().unwrap() = String::from("a8e6ab2b7af9fbe0ef13d3a8a6e5e2d1c94e0a9b81b3c5f5c0f4bbdbb2e36f8a92d8c1e6a5c3b7e0f1d2c4b6a8e0f2d4c6b8a0e2f4d6c8b0a2e4f6d8c0b2a4e6");
+        ctn_client.rotate_credentials().unwrap();
+
+        ctn_client_clone.sign_request(&mut req2).unwrap();
+
+        assert_ne!(req2.headers().get(AUTHORIZATION), req.headers().get(AUTHORIZATION), "Request not signed with rotated credentials");
+    }
+
+    #[test]
+    fn it_fail_rotate_credentials_no_provider() {
+        let ctn_client = CatenisClient::new(None).unwrap();
+
+        let result = ctn_client.rotate_credentials();
+
+        assert!(result.is_err(), "Rotated credentials without a provider");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Missing credential provider");
+    }
+
+    #[test]
+    fn it_share_client_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<CatenisClient>();
+
+        // Instantiate Catenis API client
+        let ctn_client = Arc::new(CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host("localhost:3000"),
+                ClientOptions::Secure(false),
+            ],
+        ).unwrap());
+
+        let handles: Vec<_> = (0..4).map(|_| {
+            let ctn_client = ctn_client.clone();
+
+            thread::spawn(move || {
+                let mut req = ctn_client.get_request(
+                    "messages/:message_id",
+                    Some(&[
+                        ("message_id", "oNXszDazhuq4utktSMMi"),
+                    ]),
+                    None::<KVList>,
+                ).unwrap();
+                ctn_client.sign_request(&mut req).unwrap();
+
+                req
+            })
+        }).collect();
+
+        for handle in handles {
+            let req = handle.join().unwrap();
+
+            assert!(req.headers().get(AUTHORIZATION).unwrap().to_str().unwrap().starts_with("CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/"), "Request not properly signed");
+        }
+    }
+
+    #[test]
+    fn it_redact_client_debug_output() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host("localhost:3000"),
+                ClientOptions::Secure(false),
+                ClientOptions::Proxy(ProxyOptions {
+                    url: "http://proxy.example.com:3128",
+                    credentials: Some(("proxy_user", "proxy_password")),
+                    no_proxy: &["*"],
+                }),
+            ],
+        ).unwrap();
+
+        // Make sure that a signing key is generated
+        let mut req = ctn_client.get_request(
+            "messages/:message_id",
+            Some(&[
+                ("message_id", "oNXszDazhuq4utktSMMi"),
+            ]),
+            None::<KVList>,
+        ).unwrap();
+        ctn_client.sign_request(&mut req).unwrap();
+
+        let signing_key = ctn_client.signing.signing_key().unwrap();
+        let signing_key = signing_key.as_bytes();
+        let debug_output = format!("{:?}", ctn_client);
+
+        assert!(!debug_output.contains("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0"), "API access secret leaked: {}", debug_output);
+        assert!(!debug_output.contains(&format!("{:?}", signing_key)), "Signing key leaked: {}", debug_output);
+        assert!(debug_output.contains("SigningKey([redacted])"), "Unexpected debug output: {}", debug_output);
+        assert!(!debug_output.contains("proxy_user") && !debug_output.contains("proxy_password"), "Proxy credentials leaked: {}", debug_output);
+
+        let debug_output = format!("{:?}", ctn_client.new_ws_notify_channel(NotificationEvent::NewMsgReceived));
+
+        assert!(!debug_output.contains("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0"), "API access secret leaked: {}", debug_output);
+        assert!(!debug_output.contains(&format!("{:?}", signing_key)), "Signing key leaked: {}", debug_output);
+        assert!(!debug_output.contains("proxy_password"), "Proxy credentials leaked: {}", debug_output);
+    }
+
     #[test]
     fn it_parse_response() {
         // Simulate successful 'Read Message' API method response
@@ -7660,7 +9891,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Get HTTP client and generate an HTTP request
-        let http_client = CatenisClient::new_http_client(false).unwrap();
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
         let req = http_client.get(&format!("http://localhost:{}/messages", server_port)).build().unwrap();
 
         // Send HTTP request and get the response
@@ -7682,7 +9913,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Get HTTP client and generate an HTTP request
-        let http_client = CatenisClient::new_http_client(true).unwrap();
+        let http_client = CatenisClient::new_http_client(true, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
         let req = http_client.get(&format!("http://localhost:{}/messages", server_port)).build().unwrap();
 
         // Send HTTP request and get the response
@@ -7696,6 +9927,117 @@ mod tests {
         assert_eq!(http_request.headers.get("accept-encoding").unwrap().value, "gzip");
     }
 
+    #[test]
+    fn it_get_new_http_client_with_response_encodings() {
+        // Start HTTP server in echo mode to retrieve HTTP request effectively sent
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client and generate an HTTP request
+        let compression = CompressionOptions {
+            accept_deflate: true,
+            accept_brotli: true,
+            ..CompressionOptions::default()
+        };
+        let http_client = CatenisClient::new_http_client(true, &compression, &ConnectionSettings::default()).unwrap();
+        let req = http_client.get(format!("http://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let res = http_client.execute(req).unwrap();
+
+        // Parse returned HTTP request from response body
+        let res_body = res.text().unwrap();
+        let http_request = HttpRequest::from_json(&res_body).unwrap();
+
+        assert_eq!(http_request.headers.get("accept-encoding").unwrap().value, "gzip, deflate, br");
+    }
+
+    #[test]
+    fn it_get_new_http_client_with_proxy() {
+        // Start HTTP server in echo mode to act as proxy and retrieve HTTP request effectively sent
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost");
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client and generate an HTTP request
+        let connection_settings = ConnectionSettings {
+            proxy: Some(ProxySettings::from_options(&ProxyOptions {
+                url: &format!("http://localhost:{}", server_port),
+                credentials: Some(("user", "pass")),
+                no_proxy: &[],
+            }).unwrap()),
+            ..ConnectionSettings::default()
+        };
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &connection_settings).unwrap();
+        let req = http_client.get("http://catenis.test/messages").build().unwrap();
+
+        // Send HTTP request and get the response
+        let res = http_client.execute(req).unwrap();
+
+        // Parse returned HTTP request from response body
+        let res_body = res.text().unwrap();
+        let http_request = HttpRequest::from_json(&res_body).unwrap();
+
+        assert_eq!(http_request.headers.get("host").unwrap().value, "catenis.test");
+        assert_eq!(http_request.headers.get("proxy-authorization").unwrap().value, "Basic dXNlcjpwYXNz");
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    #[test]
+    fn it_get_new_http_client_with_root_certificate() {
+        // Start HTTPS server in echo mode
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost").with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client trusting the test CA and generate an HTTP request
+        let mut connection_settings = ConnectionSettings::default();
+        connection_settings.tls.add_root_certificates(TEST_CA_CERT).unwrap();
+
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &connection_settings).unwrap();
+        let req = http_client.get(format!("https://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let result = http_client.execute(req);
+
+        assert!(result.is_ok(), "Failed to send request to server with trusted certificate: {:?}", result.err());
+    }
+
+    #[test]
+    fn it_get_new_http_client_untrusted_certificate() {
+        // Start HTTPS server in echo mode
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost").with_tls();
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client not trusting the test CA and generate an HTTP request
+        let http_client = CatenisClient::new_http_client(false, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
+        let req = http_client.get(format!("https://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let result = http_client.execute(req);
+
+        assert!(result.is_err(), "Sent request to server with untrusted certificate");
+    }
+
+    #[test]
+    fn it_fail_instantiate_client_invalid_root_certificate() {
+        let result = CatenisClient::new_with_options(
+            None,
+            &[
+                ClientOptions::TlsRootCertificates(b"not a certificate"),
+            ],
+        );
+
+        assert!(result.is_err(), "Instantiated client with invalid root certificate");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid root certificates: no PEM encoded certificate found");
+    }
+
     #[test]
     fn it_automatically_decompress_response() {
         // Start HTTP server in success simulation mode
@@ -7712,7 +10054,7 @@ mod tests {
         let server_port = http_server.get_port();
 
         // Get HTTP client and generate an HTTP request
-        let http_client = CatenisClient::new_http_client(true).unwrap();
+        let http_client = CatenisClient::new_http_client(true, &CompressionOptions::default(), &ConnectionSettings::default()).unwrap();
         let req = http_client.get(&format!("http://localhost:{}/messages", server_port)).build().unwrap();
 
         // Send HTTP request and get the response
@@ -7724,11 +10066,43 @@ mod tests {
         assert_eq!(res_body.as_ref(), body.as_bytes());
     }
 
+    #[test]
+    fn it_automatically_decompress_deflate_response() {
+        // Start HTTP server in success simulation mode
+        let body = r#"{"status":"success","data":{"messageId":"mg9x9vCqYMg9YtKdDwQx"}}"#;
+
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(body).unwrap().with_content_encoding(ContentEncoding::Deflate),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Get HTTP client and generate an HTTP request
+        let compression = CompressionOptions {
+            accept_deflate: true,
+            ..CompressionOptions::default()
+        };
+        let http_client = CatenisClient::new_http_client(true, &compression, &ConnectionSettings::default()).unwrap();
+        let req = http_client.get(format!("http://localhost:{}/messages", server_port)).build().unwrap();
+
+        // Send HTTP request and get the response
+        let res = http_client.execute(req).unwrap();
+
+        // Read response body
+        let res_body = res.bytes().unwrap();
+
+        assert_eq!(res_body.as_ref(), body.as_bytes());
+    }
+
     #[test]
     fn it_compress_body() {
         let body = String::from("This is only a test");
 
-        let compressed_body = CatenisClient::compress_body(body).unwrap();
+        let compressed_body = CatenisClient::compress_body(body, RequestCompression::Deflate).unwrap();
 
         assert_eq!(compressed_body.as_slice(), b"\x78\x9c\x0b\xc9\xc8\x2c\x56\x00\xa2\xfc\xbc\x9c\x4a\x85\x44\x85\x92\xd4\xe2\x12\x00\x43\x81\x06\xd8");
     }
@@ -7780,7 +10154,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7883,7 +10257,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -7974,7 +10348,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8055,7 +10429,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8144,7 +10518,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8222,7 +10596,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8293,7 +10667,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8361,7 +10735,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8427,7 +10801,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -8496,7 +10870,7 @@ mod tests {
             let server_port = http_server.get_port();
 
             // Instantiate Catenis API client
-            let mut ctn_client = CatenisClient::new_with_options(
+            let ctn_client = CatenisClient::new_with_options(
                 Some((
                     "drc3XdxNtzoucpw9xiRp",
                     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
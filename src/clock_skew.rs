@@ -0,0 +1,102 @@
+use std::sync::{
+    Arc,
+    atomic::{
+        AtomicI64, Ordering,
+    },
+};
+use reqwest::header::{
+    HeaderMap, DATE,
+};
+use time::{
+    Duration, OffsetDateTime, PrimitiveDateTime,
+};
+
+#[cfg(test)]
+use crate::test_helper;
+
+/// Largest difference, in seconds, between the measured and the current skew that is attributed to
+/// the resolution of the `Date` HTTP header and network latency, and thus ignored.
+const SKEW_TOLERANCE_SECS: i64 = 1;
+
+/// Correction applied to the local clock when signing requests, learned from the `Date` HTTP
+/// header of the responses received from the Catenis API server. It is shared by all clones of a
+/// client.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClockSkew(Arc<AtomicI64>);
+
+impl ClockSkew {
+    /// The current skew, in seconds, between the server's clock and the local clock. A positive
+    /// value means that the local clock is behind the server's clock.
+    pub(crate) fn secs(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// The current time according to the server's clock.
+    pub(crate) fn now(&self) -> OffsetDateTime {
+        now!() + Duration::seconds(self.secs())
+    }
+
+    /// Measure the skew from the `Date` HTTP header of a response, returning whether the skew
+    /// correction has changed.
+    pub(crate) fn update(&self, headers: &HeaderMap) -> bool {
+        let server_date = match headers.get(DATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| PrimitiveDateTime::parse(value.trim(), "%a, %d %b %Y %H:%M:%S GMT").ok()) {
+            Some(date) => date.assume_utc(),
+            None => return false,
+        };
+
+        let measured = (server_date - now!()).whole_seconds();
+        let current = self.secs();
+
+        if (measured - current).abs() > SKEW_TOLERANCE_SECS {
+            self.0.store(measured, Ordering::Relaxed);
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use super::*;
+
+    fn headers(date: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(DATE, HeaderValue::from_static(date));
+
+        headers
+    }
+
+    #[test]
+    fn it_measure_clock_skew() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let skew = ClockSkew::default();
+        let skew_clone = skew.clone();
+
+        assert!(skew.update(&headers("Tue, 01 Dec 2020 06:10:00 GMT")), "Skew not updated");
+        assert_eq!(skew_clone.secs(), 600, "Skew not shared among clones");
+        assert_eq!(skew.now(), time::date!(2020-12-01).with_time(time::time!(06:10:00)).assume_utc());
+
+        assert!(skew.update(&headers("Tue, 01 Dec 2020 05:59:00 GMT")), "Skew not updated");
+        assert_eq!(skew.secs(), -60);
+    }
+
+    #[test]
+    fn it_ignore_small_or_missing_skew() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let skew = ClockSkew::default();
+
+        assert!(!skew.update(&headers("Tue, 01 Dec 2020 06:00:01 GMT")), "Skew updated within tolerance");
+        assert!(!skew.update(&headers("invalid date")), "Skew updated from invalid date");
+        assert!(!skew.update(&HeaderMap::new()), "Skew updated without date");
+        assert_eq!(skew.secs(), 0);
+    }
+}
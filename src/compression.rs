@@ -0,0 +1,157 @@
+use std::io::{
+    self, Read,
+};
+use flate2::{
+    Compression,
+    read::{
+        GzEncoder, ZlibEncoder,
+    },
+};
+use reqwest::header::HeaderValue;
+use serde::Deserialize;
+
+/// Algorithm used to compress request bodies.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestCompression {
+    /// Zlib stream (`deflate` content encoding).
+    Deflate,
+    /// Gzip stream (`gzip` content encoding).
+    Gzip,
+}
+
+impl RequestCompression {
+    /// The value of the `Content-Encoding` HTTP header for a body compressed with this algorithm.
+    pub(crate) fn content_encoding(&self) -> &'static str {
+        match self {
+            RequestCompression::Deflate => "deflate",
+            RequestCompression::Gzip => "gzip",
+        }
+    }
+
+    /// Compress a (request) body with this algorithm.
+    pub(crate) fn compress(&self, body: &[u8]) -> io::Result<Vec<u8>> {
+        let mut enc_body = Vec::new();
+
+        match self {
+            RequestCompression::Deflate => ZlibEncoder::new(body, Compression::default()).read_to_end(&mut enc_body)?,
+            RequestCompression::Gzip => GzEncoder::new(body, Compression::default()).read_to_end(&mut enc_body)?,
+        };
+
+        Ok(enc_body)
+    }
+}
+
+/// Compression settings of a Catenis API client.
+///
+/// These settings only take effect while compression is enabled (refer to
+/// [`ClientOptions::UseCompression`](crate::ClientOptions::UseCompression)). Request bodies are
+/// only compressed if their size reaches the compression threshold, and compression can be
+/// skipped for individual calls via the client's `with_request_compression()` method.
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     CatenisClient, ClientOptions, CompressionOptions, RequestCompression, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let ctn_client = CatenisClient::new_with_options(
+///     Some((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into()),
+///     &[
+///         ClientOptions::Compression(CompressionOptions {
+///             request: RequestCompression::Gzip,
+///             accept_brotli: true,
+///             ..CompressionOptions::default()
+///         }),
+///     ],
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompressionOptions {
+    /// Algorithm used to compress request bodies. Default value: **`RequestCompression::Deflate`**.
+    pub request: RequestCompression,
+    /// Indicates whether `gzip` encoded response bodies should be accepted. Default value:
+    /// **`true`**.
+    pub accept_gzip: bool,
+    /// Indicates whether `deflate` encoded response bodies should be accepted. Default value:
+    /// **`false`**.
+    pub accept_deflate: bool,
+    /// Indicates whether `br` (brotli) encoded response bodies should be accepted. Default
+    /// value: **`false`**.
+    pub accept_brotli: bool,
+}
+
+impl Default for CompressionOptions {
+    fn default() -> Self {
+        CompressionOptions {
+            request: RequestCompression::Deflate,
+            accept_gzip: true,
+            accept_deflate: false,
+            accept_brotli: false,
+        }
+    }
+}
+
+impl CompressionOptions {
+    /// The value of the `Accept-Encoding` HTTP header advertising the accepted response
+    /// encodings, or `None` if no encoding is accepted.
+    pub(crate) fn accept_encoding(&self) -> Option<HeaderValue> {
+        let encodings: Vec<&str> = [
+            (self.accept_gzip, "gzip"),
+            (self.accept_deflate, "deflate"),
+            (self.accept_brotli, "br"),
+        ].iter()
+            .filter(|(accepted, _)| *accepted)
+            .map(|(_, encoding)| *encoding)
+            .collect();
+
+        if encodings.is_empty() {
+            None
+        } else {
+            HeaderValue::from_str(&encodings.join(", ")).ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::read::GzDecoder;
+    use super::*;
+
+    #[test]
+    fn it_assemble_accept_encoding() {
+        assert_eq!(CompressionOptions::default().accept_encoding().unwrap(), "gzip");
+        assert_eq!(CompressionOptions {
+            accept_deflate: true,
+            accept_brotli: true,
+            ..CompressionOptions::default()
+        }.accept_encoding().unwrap(), "gzip, deflate, br");
+        assert!(CompressionOptions {
+            accept_gzip: false,
+            ..CompressionOptions::default()
+        }.accept_encoding().is_none(), "Assembled empty Accept-Encoding HTTP header");
+    }
+
+    #[test]
+    fn it_compress_gzip() {
+        let body = br#"{"message":"Test message","options":{"encoding":"utf8"}}"#;
+
+        let enc_body = RequestCompression::Gzip.compress(body).unwrap();
+        let mut dec_body = Vec::new();
+        GzDecoder::new(enc_body.as_slice()).read_to_end(&mut dec_body).unwrap();
+
+        assert_eq!(RequestCompression::Gzip.content_encoding(), "gzip");
+        assert_eq!(dec_body, body.to_vec());
+    }
+}
@@ -0,0 +1,539 @@
+use std::{
+    env, fs,
+    fmt::{
+        self,
+        Debug, Formatter,
+    },
+    path::{
+        Path, PathBuf,
+    },
+    time::Duration,
+};
+use serde::{
+    Deserialize, Deserializer,
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    ApiAccessSecret, ApiVersion, CatenisClientBuilder, CircuitBreakerPolicy, ClientIdentity,
+    CompressionOptions, DeviceCredentials, Environment, Error, FailoverOptions, ProxyOptions, RateLimit, Result, RetryPolicy,
+    API_ACCESS_SECRET_ENV_VAR, DEVICE_ID_ENV_VAR,
+    error::GenericError,
+};
+
+/// Name of the environment variable holding the full base URL (with optional path prefix) of the
+/// Catenis API server.
+pub const BASE_URL_ENV_VAR: &str = "CATENIS_BASE_URL";
+/// Name of the environment variable holding the host name (with optional port) of the Catenis
+/// API server.
+pub const HOST_ENV_VAR: &str = "CATENIS_HOST";
+/// Name of the environment variable holding the environment of the Catenis API server (`prod` or
+/// `sandbox`).
+pub const ENVIRONMENT_ENV_VAR: &str = "CATENIS_ENVIRONMENT";
+/// Name of the environment variable indicating whether a secure connection should be used
+/// (`true` or `false`).
+pub const SECURE_ENV_VAR: &str = "CATENIS_SECURE";
+/// Name of the environment variable holding the version of the Catenis API to target (e.g.
+/// `0.12`).
+pub const API_VERSION_ENV_VAR: &str = "CATENIS_API_VERSION";
+
+/// Configuration of a Catenis API client that can be deserialized (with
+/// [serde](https://crates.io/crates/serde)) from a configuration file.
+///
+/// It covers the virtual device credentials and all the [`ClientOptions`](crate::ClientOptions).
+/// All fields are optional, and durations are expressed in milliseconds. Certificates and
+/// private keys are read from the PEM files at the given paths when the client is instantiated.
+///
+/// The configuration is applied via [`CatenisClientBuilder`], so conflicting settings are
+/// rejected in the same way.
+///
+/// # Example
+///
+/// Configuration file in TOML format (requires the **`toml`** feature):
+///
+/// ```toml
+/// device_id = "drc3XdxNtzoucpw9xiRp"
+/// api_access_secret = "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+/// environment = "sandbox"
+/// version = "0.12"
+/// timeout_ms = 10000
+///
+/// [retry]
+/// max_attempts = 5
+/// initial_backoff_ms = 1000
+///
+/// [proxy]
+/// url = "http://proxy.example.com:3128"
+/// no_proxy = ["localhost"]
+/// ```
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     CatenisClient, ClientConfig, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let config = ClientConfig::from_json_file("catenis.json")?;
+/// let ctn_client = CatenisClient::from_config(&config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClientConfig {
+    /// ID of the virtual device. Must be set along with `api_access_secret`.
+    pub device_id: Option<String>,
+    /// API access secret of the virtual device. Must be set along with `device_id`.
+    pub api_access_secret: Option<ApiAccessSecret>,
+    /// Full base URL (with optional path prefix) of target Catenis API server. Cannot be set
+    /// along with `host`.
+    pub base_url: Option<String>,
+    /// Host name (with optional port) of target Catenis API server.
+    pub host: Option<String>,
+    /// Environment of target Catenis API server: `"prod"` or `"sandbox"`.
+    pub environment: Option<Environment>,
+    /// Indicates whether a secure connection (HTTPS) should be used.
+    pub secure: Option<bool>,
+    /// Version of Catenis API to target (e.g. `"0.12"`).
+    pub version: Option<ApiVersion>,
+    /// Indicates whether request body should be compressed.
+    pub use_compression: Option<bool>,
+    /// Algorithm used to compress request body (`"deflate"` or `"gzip"`) and encodings accepted
+    /// for response body, with the `request`, `accept_gzip`, `accept_deflate` and `accept_brotli`
+    /// keys.
+    pub compression: Option<CompressionOptions>,
+    /// Minimum size, in bytes, of request body for it to be compressed.
+    pub compress_threshold: Option<usize>,
+    /// Policy for automatically retrying failed requests, with the `max_attempts`,
+    /// `initial_backoff_ms`, `max_backoff_ms`, `jitter` and `retry_non_idempotent` keys.
+    pub retry: Option<RetryPolicy>,
+    /// Limit on the rate at which requests are sent, with the `requests_per_second` and `burst`
+    /// keys.
+    pub rate_limit: Option<RateLimit>,
+    /// Policy of a circuit breaker, with the `failure_threshold`, `open_duration_ms` and
+    /// `half_open_probes` keys.
+    pub circuit_breaker: Option<CircuitBreakerPolicy>,
+    /// Maximum time, in milliseconds, allowed for a complete request.
+    pub timeout_ms: Option<u64>,
+    /// Maximum time, in milliseconds, allowed for establishing a connection.
+    pub connect_timeout_ms: Option<u64>,
+    /// Maximum overall time, in milliseconds, allowed for completing the WebSocket handshake.
+    pub ws_handshake_timeout_ms: Option<u64>,
+    /// HTTP proxy through which connections should go.
+    pub proxy: Option<ProxyConfig>,
+    /// Paths of PEM files with additional root certificates to trust.
+    pub tls_root_certificates: Vec<PathBuf>,
+    /// Indicates whether the built-in root certificates should be trusted.
+    pub tls_built_in_roots: Option<bool>,
+    /// Client certificate to be presented when establishing TLS connections.
+    pub tls_client_identity: Option<ClientIdentityConfig>,
+    /// Pins of the public keys that the Catenis API server's certificate is allowed to have.
+    pub pinned_public_keys: Option<Vec<String>>,
+    /// Alternative endpoints to fail over to.
+    pub failover: Option<FailoverConfig>,
+}
+
+/// HTTP proxy settings of a [`ClientConfig`]. Refer to [`ProxyOptions`] for details.
+///
+/// > **Note**: the password is zeroed when it is dropped, and redacted when the settings are
+/// > debug formatted.
+#[derive(Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProxyConfig {
+    /// URL of the proxy server.
+    pub url: String,
+    /// User name used to authenticate with the proxy server. Must be set along with `password`.
+    pub username: Option<String>,
+    /// Password used to authenticate with the proxy server. Must be set along with `username`.
+    #[serde(deserialize_with = "deserialize_secret")]
+    pub password: Option<Zeroizing<String>>,
+    /// List of hosts that should be accessed directly, bypassing the proxy.
+    pub no_proxy: Vec<String>,
+}
+
+impl Debug for ProxyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "[redacted]"))
+            .field("no_proxy", &self.no_proxy)
+            .finish()
+    }
+}
+
+/// Client certificate settings of a [`ClientConfig`]. Refer to [`ClientIdentity`] for details.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientIdentityConfig {
+    /// Path of the PEM file with the certificate chain, starting with the client's own
+    /// certificate.
+    pub certificate_chain: PathBuf,
+    /// Path of the PEM file with the PKCS #8 private key matching the client's certificate.
+    pub private_key: PathBuf,
+}
+
+/// Failover settings of a [`ClientConfig`]. Refer to [`FailoverOptions`] for details.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FailoverConfig {
+    /// Base URLs of the alternative endpoints, in order of preference.
+    pub base_urls: Vec<String>,
+    /// Indicates whether the client should also fail over on `5xx` HTTP status codes.
+    pub on_server_error: bool,
+    /// Time after which the client fails back to the primary endpoint.
+    #[serde(rename = "cool_down_ms", deserialize_with = "deserialize_millis")]
+    pub cool_down: Duration,
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        let defaults = FailoverOptions::default();
+
+        FailoverConfig {
+            base_urls: Vec::new(),
+            on_server_error: defaults.on_server_error,
+            cool_down: defaults.cool_down,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Load the configuration from a JSON file.
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| Error::new_client_error(Some("Invalid client configuration"), Some(e)))
+    }
+
+    /// Load the configuration from a TOML file.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        toml::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| Error::new_client_error(Some("Invalid client configuration"), Some(e)))
+    }
+
+    /// Load the configuration from the following environment variables, all of them optional:
+    ///
+    /// | Variable                    | Setting                      |
+    /// |-----------------------------|------------------------------|
+    /// | `CATENIS_DEVICE_ID`         | `device_id`                  |
+    /// | `CATENIS_API_ACCESS_SECRET` | `api_access_secret`          |
+    /// | `CATENIS_BASE_URL`          | `base_url`                   |
+    /// | `CATENIS_HOST`              | `host`                       |
+    /// | `CATENIS_ENVIRONMENT`       | `environment` (`prod` or `sandbox`) |
+    /// | `CATENIS_SECURE`            | `secure` (`true` or `false`) |
+    /// | `CATENIS_API_VERSION`       | `version` (e.g. `0.12`)      |
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars<F: Fn(&str) -> Option<String>>(var: F) -> Result<Self> {
+        let invalid_var = |name: &str| Error::new_client_error(Some(&format!("Invalid environment variable {}", name)), None::<GenericError>);
+
+        Ok(ClientConfig {
+            device_id: var(DEVICE_ID_ENV_VAR),
+            api_access_secret: var(API_ACCESS_SECRET_ENV_VAR).map(ApiAccessSecret::from),
+            base_url: var(BASE_URL_ENV_VAR),
+            host: var(HOST_ENV_VAR),
+            environment: match var(ENVIRONMENT_ENV_VAR).as_deref() {
+                Some("prod") => Some(Environment::Prod),
+                Some("sandbox") => Some(Environment::Sandbox),
+                Some(_) => return Err(invalid_var(ENVIRONMENT_ENV_VAR)),
+                None => None,
+            },
+            secure: var(SECURE_ENV_VAR)
+                .map(|value| value.parse().map_err(|_| invalid_var(SECURE_ENV_VAR)))
+                .transpose()?,
+            version: var(API_VERSION_ENV_VAR)
+                .map(|value| value.parse().map_err(|_| invalid_var(API_VERSION_ENV_VAR)))
+                .transpose()?,
+            ..ClientConfig::default()
+        })
+    }
+
+    /// Set up a client builder with this configuration, and build the client with it.
+    pub(crate) fn build<C, F>(&self, build: F) -> Result<C>
+        where
+            F: for<'a> FnOnce(CatenisClientBuilder<'a, C>) -> Result<C>
+    {
+        let device_credentials = match (&self.device_id, &self.api_access_secret) {
+            (Some(device_id), Some(secret)) => Some(DeviceCredentials::from((device_id.as_str(), secret.clone()))),
+            (None, None) => None,
+            _ => return Err(Error::new_client_error(Some("Incomplete virtual device credentials"), None::<GenericError>)),
+        };
+        let proxy_credentials = match &self.proxy {
+            Some(ProxyConfig { username: Some(username), password: Some(password), .. }) => Some((username.as_str(), password.as_str())),
+            Some(ProxyConfig { username: None, password: None, .. }) | None => None,
+            _ => return Err(Error::new_client_error(Some("Incomplete proxy credentials"), None::<GenericError>)),
+        };
+        let no_proxy: Vec<&str> = self.proxy.iter().flat_map(|proxy| proxy.no_proxy.iter().map(String::as_str)).collect();
+        let root_certificates = self.tls_root_certificates.iter()
+            .map(fs::read)
+            .collect::<std::io::Result<Vec<_>>>()?;
+        let client_identity = match &self.tls_client_identity {
+            Some(identity) => Some((fs::read(&identity.certificate_chain)?, Zeroizing::new(fs::read(&identity.private_key)?))),
+            None => None,
+        };
+        let failover_urls: Vec<&str> = self.failover.iter().flat_map(|failover| failover.base_urls.iter().map(String::as_str)).collect();
+        let pins: Option<Vec<&str>> = self.pinned_public_keys.as_ref()
+            .map(|pins| pins.iter().map(String::as_str).collect());
+
+        let mut builder = CatenisClientBuilder::new();
+
+        if let Some(device_credentials) = device_credentials {
+            builder = builder.device_credentials(device_credentials);
+        }
+
+        if let Some(base_url) = &self.base_url {
+            builder = builder.base_url(base_url);
+        }
+
+        if let Some(host) = &self.host {
+            builder = builder.host(host);
+        }
+
+        if let Some(environment) = self.environment {
+            builder = builder.environment(environment);
+        }
+
+        if let Some(secure) = self.secure {
+            builder = builder.secure(secure);
+        }
+
+        if let Some(version) = self.version {
+            builder = builder.version(version);
+        }
+
+        if let Some(use_compression) = self.use_compression {
+            builder = builder.use_compression(use_compression);
+        }
+
+        if let Some(compression) = self.compression {
+            builder = builder.compression(compression);
+        }
+
+        if let Some(threshold) = self.compress_threshold {
+            builder = builder.compress_threshold(threshold);
+        }
+
+        if let Some(policy) = self.retry {
+            builder = builder.retry(policy);
+        }
+
+        if let Some(limit) = self.rate_limit {
+            builder = builder.rate_limit(limit);
+        }
+
+        if let Some(policy) = self.circuit_breaker {
+            builder = builder.circuit_breaker(policy);
+        }
+
+        if let Some(timeout) = self.timeout_ms {
+            builder = builder.timeout(Duration::from_millis(timeout));
+        }
+
+        if let Some(timeout) = self.connect_timeout_ms {
+            builder = builder.connect_timeout(Duration::from_millis(timeout));
+        }
+
+        if let Some(timeout) = self.ws_handshake_timeout_ms {
+            builder = builder.ws_handshake_timeout(Duration::from_millis(timeout));
+        }
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(ProxyOptions {
+                url: &proxy.url,
+                credentials: proxy_credentials,
+                no_proxy: &no_proxy,
+            });
+        }
+
+        for pem in &root_certificates {
+            builder = builder.tls_root_certificates(pem);
+        }
+
+        if let Some(use_built_in_roots) = self.tls_built_in_roots {
+            builder = builder.tls_built_in_roots(use_built_in_roots);
+        }
+
+        if let Some((certificate_chain, private_key)) = &client_identity {
+            builder = builder.tls_client_identity(ClientIdentity {
+                certificate_chain,
+                private_key,
+            });
+        }
+
+        if let Some(pins) = &pins {
+            builder = builder.pinned_public_keys(pins);
+        }
+
+        if let Some(failover) = &self.failover {
+            builder = builder.failover(FailoverOptions {
+                base_urls: &failover_urls,
+                on_server_error: failover.on_server_error,
+                cool_down: failover.cool_down,
+            });
+        }
+
+        build(builder)
+    }
+}
+
+/// Deserialize an optional secret, which is zeroed when it is dropped.
+fn deserialize_secret<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<Zeroizing<String>>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.map(Zeroizing::new))
+}
+
+/// Deserialize a duration expressed in milliseconds.
+pub(crate) fn deserialize_millis<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Duration, D::Error> {
+    Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::{
+        CatenisClient, RequestCompression,
+    };
+    use super::*;
+
+    #[test]
+    fn it_deserialize_json_config() {
+        let config: ClientConfig = serde_json::from_str(r#"{
+  "device_id": "drc3XdxNtzoucpw9xiRp",
+  "api_access_secret": "secret",
+  "environment": "sandbox",
+  "version": "0.10",
+  "timeout_ms": 10000,
+  "compression": {
+    "request": "gzip",
+    "accept_brotli": true
+  },
+  "retry": {
+    "max_attempts": 5,
+    "initial_backoff_ms": 1000
+  },
+  "circuit_breaker": {
+    "open_duration_ms": 60000
+  },
+  "proxy": {
+    "url": "http://proxy.example.com:3128",
+    "no_proxy": ["localhost"]
+  }
+}"#).unwrap();
+
+        assert_eq!(config, ClientConfig {
+            device_id: Some(String::from("drc3XdxNtzoucpw9xiRp")),
+            api_access_secret: Some(ApiAccessSecret::from("secret")),
+            environment: Some(Environment::Sandbox),
+            version: Some(ApiVersion(0, 10)),
+            timeout_ms: Some(10000),
+            compression: Some(CompressionOptions {
+                request: RequestCompression::Gzip,
+                accept_brotli: true,
+                ..CompressionOptions::default()
+            }),
+            retry: Some(RetryPolicy {
+                max_attempts: 5,
+                initial_backoff: Duration::from_secs(1),
+                ..RetryPolicy::default()
+            }),
+            circuit_breaker: Some(CircuitBreakerPolicy {
+                open_duration: Duration::from_secs(60),
+                ..CircuitBreakerPolicy::default()
+            }),
+            proxy: Some(ProxyConfig {
+                url: String::from("http://proxy.example.com:3128"),
+                no_proxy: vec![String::from("localhost")],
+                ..ProxyConfig::default()
+            }),
+            ..ClientConfig::default()
+        });
+
+        assert!(serde_json::from_str::<ClientConfig>(r#"{"hots":"localhost"}"#).is_err(), "Accepted unknown setting");
+    }
+
+    #[test]
+    fn it_redact_proxy_password() {
+        let config: ClientConfig = serde_json::from_str(r#"{
+            "proxy": {"url": "http://proxy.example.com:3128", "username": "proxy_user", "password": "proxy_password"}
+        }"#).unwrap();
+
+        assert_eq!(config.proxy.as_ref().unwrap().password.as_ref().unwrap().as_str(), "proxy_password");
+
+        let debug_output = format!("{:?}", config);
+
+        assert!(!debug_output.contains("proxy_password"), "Proxy password leaked: {}", debug_output);
+        assert!(debug_output.contains(r#"password: Some("[redacted]")"#), "Unexpected debug output: {}", debug_output);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn it_deserialize_toml_config() {
+        let config: ClientConfig = toml::from_str(r#"
+host = "localhost:3000"
+secure = false
+
+[rate_limit]
+requests_per_second = 10.0
+burst = 20
+"#).unwrap();
+
+        assert_eq!(config.host.as_deref(), Some("localhost:3000"));
+        assert_eq!(config.secure, Some(false));
+        assert_eq!(config.rate_limit, Some(RateLimit {
+            requests_per_second: 10.0,
+            burst: 20,
+        }));
+    }
+
+    #[test]
+    fn it_load_config_from_env_vars() {
+        let vars: HashMap<&str, &str> = vec![
+            ("CATENIS_DEVICE_ID", "drc3XdxNtzoucpw9xiRp"),
+            ("CATENIS_API_ACCESS_SECRET", "secret"),
+            ("CATENIS_ENVIRONMENT", "sandbox"),
+            ("CATENIS_SECURE", "false"),
+            ("CATENIS_API_VERSION", "0.10"),
+        ].into_iter().collect();
+
+        let config = ClientConfig::from_vars(|name| vars.get(name).map(|value| String::from(*value))).unwrap();
+
+        assert_eq!(config, ClientConfig {
+            device_id: Some(String::from("drc3XdxNtzoucpw9xiRp")),
+            api_access_secret: Some(ApiAccessSecret::from("secret")),
+            environment: Some(Environment::Sandbox),
+            secure: Some(false),
+            version: Some(ApiVersion(0, 10)),
+            ..ClientConfig::default()
+        });
+
+        let result = ClientConfig::from_vars(|name| if name == "CATENIS_ENVIRONMENT" { Some(String::from("staging")) } else { None });
+
+        assert!(result.is_err(), "Loaded config from invalid environment variable");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid environment variable CATENIS_ENVIRONMENT");
+    }
+
+    #[test]
+    fn it_instantiate_client_from_config() {
+        let ctn_client = CatenisClient::from_config(&ClientConfig {
+            device_id: Some(String::from("drc3XdxNtzoucpw9xiRp")),
+            api_access_secret: Some(ApiAccessSecret::from("secret")),
+            host: Some(String::from("localhost:3000")),
+            environment: Some(Environment::Sandbox),
+            secure: Some(false),
+            version: Some(ApiVersion(0, 10)),
+            ..ClientConfig::default()
+        }).unwrap();
+
+        assert_eq!(ctn_client.base_api_url(), "http://sandbox.localhost:3000/api/0.10/");
+
+        let result = CatenisClient::from_config(&ClientConfig {
+            device_id: Some(String::from("drc3XdxNtzoucpw9xiRp")),
+            ..ClientConfig::default()
+        });
+
+        assert!(result.is_err(), "Instantiated client with incomplete credentials");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Incomplete virtual device credentials");
+    }
+}
@@ -0,0 +1,334 @@
+use std::{
+    cell::Cell,
+    io::{
+        self,
+        Read, Write,
+    },
+    net::{
+        TcpStream, ToSocketAddrs,
+    },
+    time::{
+        Duration, Instant,
+    },
+};
+use tungstenite::{
+    self,
+    client::uri_mode,
+    handshake::{
+        client::Request as WsRequest,
+        HandshakeError,
+    },
+    stream::{
+        MaybeTlsStream, Mode,
+    },
+    WebSocket,
+};
+
+use crate::{
+    Error, Result,
+    error::GenericError,
+    pinning::PinSet,
+    proxy::ProxySettings,
+    tls::{
+        self,
+        TlsSettings,
+    },
+};
+
+/// Settings used for establishing connections with the Catenis API server.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConnectionSettings {
+    pub request_timeout: Option<Duration>,
+    pub connect_timeout: Option<Duration>,
+    pub ws_handshake_timeout: Option<Duration>,
+    pub proxy: Option<ProxySettings>,
+    pub tls: TlsSettings,
+    pub pinned_keys: Option<PinSet>,
+}
+
+/// Open a TCP connection to the given host, trying each of its resolved addresses in turn.
+pub(crate) fn tcp_connect(host: &str, port: u16, connect_timeout: Option<Duration>) -> io::Result<TcpStream> {
+    let mut last_err = None;
+
+    for addr in (host, port).to_socket_addrs()? {
+        let result = if let Some(timeout) = connect_timeout {
+            TcpStream::connect_timeout(&addr, timeout)
+        } else {
+            TcpStream::connect(addr)
+        };
+
+        match result {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_err = Some(err),
+        }
+    }
+
+    Err(last_err.unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Host name could not be resolved")))
+}
+
+/// TCP stream that enforces an overall deadline on the I/O operations carried out while a
+/// WebSocket connection is being established, no matter how slowly the peer responds.
+///
+/// Before each read or write, the timeout of the underlying TCP stream is shrunk to the time
+/// remaining until the deadline.
+#[derive(Debug)]
+pub(crate) struct DeadlineStream {
+    stream: TcpStream,
+    deadline: Cell<Option<Instant>>,
+}
+
+impl DeadlineStream {
+    pub(crate) fn new(stream: TcpStream, timeout: Option<Duration>) -> Self {
+        DeadlineStream {
+            stream,
+            deadline: Cell::new(timeout.map(|timeout| Instant::now() + timeout)),
+        }
+    }
+
+    pub(crate) fn get_ref(&self) -> &TcpStream {
+        &self.stream
+    }
+
+    /// Lift the deadline, removing the timeouts that it has set on the underlying TCP stream.
+    pub(crate) fn clear_deadline(&self) -> io::Result<()> {
+        if self.deadline.take().is_some() {
+            self.stream.set_read_timeout(None)?;
+            self.stream.set_write_timeout(None)?;
+        }
+
+        Ok(())
+    }
+
+    /// Time remaining until the deadline, or `None` if no deadline is set.
+    fn remaining_time(&self) -> io::Result<Option<Duration>> {
+        match self.deadline.get() {
+            Some(deadline) => {
+                let now = Instant::now();
+
+                if now < deadline {
+                    Ok(Some(deadline - now))
+                } else {
+                    // Note: reported just like an expired socket timeout, so it is handled the same
+                    //  way by the TLS and WebSocket handshakes
+                    Err(io::Error::new(io::ErrorKind::WouldBlock, "WebSocket handshake deadline has elapsed"))
+                }
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl Read for DeadlineStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(remaining) = self.remaining_time()? {
+            self.stream.set_read_timeout(Some(remaining))?;
+        }
+
+        self.stream.read(buf)
+    }
+}
+
+impl Write for DeadlineStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(remaining) = self.remaining_time()? {
+            self.stream.set_write_timeout(Some(remaining))?;
+        }
+
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+/// Establish a (blocking) WebSocket connection honoring the connect and handshake timeouts.
+///
+/// > **Note**: the TLS handshake is carried out here, instead of being delegated to `tungstenite`,
+/// > because the latter cannot cope with a read timeout expiring in the middle of it, nor with a
+/// > custom TLS configuration.
+pub(crate) fn connect_ws(req: WsRequest, settings: &ConnectionSettings) -> Result<WebSocket<MaybeTlsStream<DeadlineStream>>> {
+    let mode = uri_mode(req.uri())?;
+    let host = String::from(req.uri().host()
+        .ok_or_else(|| Error::new_client_error(Some("Inconsistent WebSocket URL: missing host"), None::<GenericError>))?);
+    let port = req.uri().port_u16().unwrap_or(match mode {
+        Mode::Plain => 80,
+        Mode::Tls => 443,
+    });
+
+    let proxy = settings.proxy.as_ref().filter(|proxy| !proxy.bypass(&host));
+
+    let stream = if let Some(proxy) = proxy {
+        tcp_connect(proxy.host(), proxy.port(), settings.connect_timeout)
+    } else {
+        tcp_connect(&host, port, settings.connect_timeout)
+    }
+        .map_err(|err| if is_io_timeout(&err) {
+            Error::new_timeout_error(Some("Timed out connecting to WebSocket server"), Some(err))
+        } else {
+            Error::new_client_error(Some("Failed to establish WebSocket connection"), Some(err))
+        })?;
+
+    // Limit the overall time that the handshake (including opening a tunnel through the proxy and
+    //  the TLS handshake) may take
+    let mut stream = DeadlineStream::new(stream, settings.ws_handshake_timeout);
+
+    if let Some(proxy) = proxy {
+        proxy.tunnel(&mut stream, &host, port)?;
+    }
+
+    let stream = match mode {
+        Mode::Plain => MaybeTlsStream::Plain(stream),
+        Mode::Tls => settings.tls.connect(&host, stream, settings.pinned_keys.as_ref())?,
+    };
+
+    let (ws, _) = tungstenite::client(req, stream)
+        .map_err(|err| match err {
+            HandshakeError::Interrupted(_) => Error::new_timeout_error(
+                Some("Timed out performing WebSocket handshake"),
+                None::<GenericError>
+            ),
+            HandshakeError::Failure(tungstenite::Error::Io(err)) if is_io_timeout(&err) => Error::new_timeout_error(
+                Some("Timed out performing WebSocket handshake"),
+                Some(err)
+            ),
+            HandshakeError::Failure(err) => Error::new_client_error(
+                Some("Failed to establish WebSocket connection"),
+                Some(err)
+            ),
+        })?;
+
+    // Handshake done. Lift deadline
+    tls::inner_stream(ws.get_ref())?.clear_deadline()?;
+
+    Ok(ws)
+}
+
+/// Indicates whether an I/O error has been caused by a timeout.
+pub(crate) fn is_io_timeout(err: &io::Error) -> bool {
+    matches!(err.kind(), io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use tungstenite::client::IntoClientRequest;
+    #[cfg(feature = "rustls")]
+    use crate::test_helper::http_server::{
+        HttpServer, HttpServerMode, TEST_CA_CERT,
+    };
+    use super::*;
+
+    #[test]
+    fn it_connect_to_host() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let stream = tcp_connect("localhost", port, Some(Duration::from_secs(5)));
+
+        assert!(stream.is_ok(), "Failed to connect to host");
+    }
+
+    #[test]
+    fn it_fail_connect_to_unresolved_host() {
+        let result = tcp_connect("invalid-host.invalid", 80, None);
+
+        assert!(result.is_err(), "Connected to host when it should have failed");
+    }
+
+    #[test]
+    fn it_time_out_ws_handshake() {
+        // Server that accepts connections but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let req = format!("ws://localhost:{}/", port).into_client_request().unwrap();
+        let settings = ConnectionSettings {
+            ws_handshake_timeout: Some(Duration::from_millis(100)),
+            ..ConnectionSettings::default()
+        };
+
+        let result = connect_ws(req, &settings);
+
+        assert!(result.is_err(), "WebSocket handshake succeeded when it should have timed out");
+        assert!(result.err().unwrap().is_timeout(), "Returned error is not a timeout error");
+    }
+
+    #[test]
+    fn it_time_out_slow_ws_handshake() {
+        // Server that keeps trickling bytes of a response header that never ends
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream.write_all(b"HTTP/1.1 101 Switching Protocols\r\nX-Padding: ").unwrap();
+
+            for _ in 0..200 {
+                if stream.write_all(b"x").is_err() {
+                    break;
+                }
+
+                std::thread::sleep(Duration::from_millis(50));
+            }
+        });
+
+        let req = format!("ws://localhost:{}/", port).into_client_request().unwrap();
+        let settings = ConnectionSettings {
+            ws_handshake_timeout: Some(Duration::from_millis(300)),
+            ..ConnectionSettings::default()
+        };
+
+        let start = Instant::now();
+        let result = connect_ws(req, &settings);
+
+        assert!(result.is_err(), "WebSocket handshake succeeded when it should have timed out");
+        assert!(result.err().unwrap().is_timeout(), "Returned error is not a timeout error");
+        assert!(start.elapsed() < Duration::from_secs(2), "WebSocket handshake deadline not enforced");
+    }
+
+    #[test]
+    fn it_lift_deadline() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let mut stream = DeadlineStream::new(TcpStream::connect(("127.0.0.1", port)).unwrap(), Some(Duration::from_millis(10)));
+        std::thread::sleep(Duration::from_millis(20));
+
+        let result = stream.read(&mut [0_u8]);
+
+        assert!(result.is_err(), "Read from stream past its deadline");
+        assert!(is_io_timeout(&result.err().unwrap()), "Returned error is not a timeout error");
+
+        stream.clear_deadline().unwrap();
+
+        assert!(stream.remaining_time().unwrap().is_none(), "Deadline not lifted");
+        assert_eq!(stream.get_ref().read_timeout().unwrap(), None);
+    }
+
+    #[cfg(feature = "rustls")]
+    #[test]
+    fn it_reject_ws_pin_mismatch() {
+        let http_server = HttpServer::new(HttpServerMode::Echo, "localhost").with_tls();
+        http_server.start();
+
+        let req = format!("wss://localhost:{}/", http_server.get_port()).into_client_request().unwrap();
+        let mut settings = ConnectionSettings {
+            pinned_keys: Some(PinSet::from_pins(&["47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="]).unwrap()),
+            ..ConnectionSettings::default()
+        };
+        settings.tls.add_root_certificates(TEST_CA_CERT).unwrap();
+
+        let result = connect_ws(req, &settings);
+
+        assert!(result.is_err(), "WebSocket connection succeeded when server certificate does not match pins");
+        assert!(result.err().unwrap().is_pin_mismatch(), "Returned error is not a pin mismatch error");
+    }
+
+    #[test]
+    fn it_identify_io_timeout() {
+        assert!(is_io_timeout(&io::Error::new(io::ErrorKind::TimedOut, "timed out")));
+        assert!(is_io_timeout(&io::Error::new(io::ErrorKind::WouldBlock, "would block")));
+        assert!(!is_io_timeout(&io::Error::new(io::ErrorKind::ConnectionRefused, "refused")));
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    env, fs,
+    fmt::{
+        self, Debug, Formatter,
+    },
+    path::PathBuf,
+    sync::{
+        Arc, PoisonError, RwLock,
+    },
+};
+use serde::Deserialize;
+use zeroize::Zeroizing;
+
+use crate::{
+    DeviceCredentials, Error, Result,
+    error::GenericError,
+};
+
+/// Default name of the environment variable holding the device ID.
+pub const DEVICE_ID_ENV_VAR: &str = "CATENIS_DEVICE_ID";
+/// Default name of the environment variable holding the device's API access secret.
+pub const API_ACCESS_SECRET_ENV_VAR: &str = "CATENIS_API_ACCESS_SECRET";
+
+/// Source of the virtual device credentials used by a Catenis API client.
+///
+/// The client calls the provider when it needs to generate a new signing key: the first time
+/// that a request is signed, and whenever the current signing key expires. Credentials can also
+/// be reloaded at any time by calling the client's `rotate_credentials()` method.
+///
+/// Besides the built-in [`EnvCredentialProvider`] and [`FileCredentialProvider`], any closure
+/// returning `Result<DeviceCredentials>` can be used as a provider.
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     CatenisClient, DeviceCredentials, EnvCredentialProvider, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let ctn_client = CatenisClient::new(None)?;
+///
+/// // Read credentials from the CATENIS_DEVICE_ID and CATENIS_API_ACCESS_SECRET environment variables
+/// ctn_client.set_credential_provider(EnvCredentialProvider::default())?;
+///
+/// // Or fetch them from somewhere else
+/// ctn_client.set_credential_provider(|| -> Result<DeviceCredentials> {
+///     Ok(("drc3XdxNtzoucpw9xiRp", "<secret fetched from a vault>").into())
+/// })?;
+///
+/// // Later, after the API access secret has been changed
+/// ctn_client.rotate_credentials()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait CredentialProvider: Send + Sync {
+    /// Retrieve the current virtual device credentials.
+    fn credentials(&self) -> Result<DeviceCredentials>;
+}
+
+impl<F> CredentialProvider for F
+    where
+        F: Fn() -> Result<DeviceCredentials> + Send + Sync
+{
+    fn credentials(&self) -> Result<DeviceCredentials> {
+        self()
+    }
+}
+
+/// Credential provider that reads the virtual device credentials from environment variables.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EnvCredentialProvider {
+    device_id_var: String,
+    api_access_secret_var: String,
+}
+
+impl EnvCredentialProvider {
+    /// Instantiate a new provider that reads the credentials from the given environment
+    /// variables.
+    pub fn new(device_id_var: &str, api_access_secret_var: &str) -> Self {
+        EnvCredentialProvider {
+            device_id_var: String::from(device_id_var),
+            api_access_secret_var: String::from(api_access_secret_var),
+        }
+    }
+}
+
+impl Default for EnvCredentialProvider {
+    /// Reads the credentials from the `CATENIS_DEVICE_ID` and `CATENIS_API_ACCESS_SECRET`
+    /// environment variables.
+    fn default() -> Self {
+        Self::new(DEVICE_ID_ENV_VAR, API_ACCESS_SECRET_ENV_VAR)
+    }
+}
+
+impl CredentialProvider for EnvCredentialProvider {
+    fn credentials(&self) -> Result<DeviceCredentials> {
+        let read_var = |name: &str| env::var(name)
+            .map_err(|_| Error::new_client_error(Some(&format!("Missing or invalid environment variable {}", name)), None::<GenericError>));
+
+        Ok(DeviceCredentials {
+            device_id: read_var(&self.device_id_var)?,
+            api_access_secret: read_var(&self.api_access_secret_var)?.into(),
+        })
+    }
+}
+
+/// Credential provider that reads the virtual device credentials from a JSON file.
+///
+/// The file is read every time that credentials are needed, and it should have the following
+/// format:
+///
+/// ```json
+/// {
+///   "deviceId": "drc3XdxNtzoucpw9xiRp",
+///   "apiAccessSecret": "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileCredentialProvider {
+    path: PathBuf,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CredentialsFile {
+    device_id: String,
+    api_access_secret: String,
+}
+
+impl FileCredentialProvider {
+    /// Instantiate a new provider that reads the credentials from the given file.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileCredentialProvider {
+            path: path.into(),
+        }
+    }
+}
+
+impl CredentialProvider for FileCredentialProvider {
+    fn credentials(&self) -> Result<DeviceCredentials> {
+        let contents = Zeroizing::new(fs::read_to_string(&self.path)?);
+        let file: CredentialsFile = serde_json::from_str(&contents)?;
+
+        Ok(DeviceCredentials {
+            device_id: file.device_id,
+            api_access_secret: file.api_access_secret.into(),
+        })
+    }
+}
+
+struct CredentialState {
+    provider: Option<Arc<dyn CredentialProvider>>,
+    credentials: Option<DeviceCredentials>,
+    /// Incremented every time that the credentials change.
+    generation: u64,
+}
+
+/// Virtual device credentials shared by all clones of a client (and the notification channels
+/// created from them), so they can be rotated at once.
+#[derive(Clone)]
+pub(crate) struct SharedCredentials(Arc<RwLock<CredentialState>>);
+
+impl Debug for SharedCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let state = self.0.read().unwrap_or_else(PoisonError::into_inner);
+
+        write!(f, "SharedCredentials(generation: {}, provider: {})", state.generation, if state.provider.is_some() { "set" } else { "unset" })
+    }
+}
+
+impl SharedCredentials {
+    pub(crate) fn new(credentials: Option<DeviceCredentials>) -> Self {
+        SharedCredentials(Arc::new(RwLock::new(CredentialState {
+            provider: None,
+            credentials,
+            generation: 0,
+        })))
+    }
+
+    /// Retrieve the current credentials, along with their generation.
+    pub(crate) fn get(&self) -> (Option<DeviceCredentials>, u64) {
+        let state = self.0.read().unwrap_or_else(PoisonError::into_inner);
+
+        (state.credentials.clone(), state.generation)
+    }
+
+    pub(crate) fn generation(&self) -> u64 {
+        self.0.read().unwrap_or_else(PoisonError::into_inner).generation
+    }
+
+    pub(crate) fn has_provider(&self) -> bool {
+        self.0.read().unwrap_or_else(PoisonError::into_inner).provider.is_some()
+    }
+
+    /// Set a new provider, and load the credentials from it.
+    pub(crate) fn set_provider(&self, provider: Arc<dyn CredentialProvider>) -> Result<()> {
+        let credentials = provider.credentials()?;
+        let mut state = self.0.write().unwrap_or_else(PoisonError::into_inner);
+
+        state.provider = Some(provider);
+        Self::replace(&mut state, credentials);
+
+        Ok(())
+    }
+
+    /// Reload the credentials from the provider.
+    pub(crate) fn refresh(&self) -> Result<()> {
+        let provider = self.0.read().unwrap_or_else(PoisonError::into_inner).provider.clone()
+            .ok_or_else(|| Error::new_client_error(Some("Missing credential provider"), None::<GenericError>))?;
+        let credentials = provider.credentials()?;
+
+        Self::replace(&mut self.0.write().unwrap_or_else(PoisonError::into_inner), credentials);
+
+        Ok(())
+    }
+
+    fn replace(state: &mut CredentialState, credentials: DeviceCredentials) {
+        if state.credentials.as_ref() != Some(&credentials) {
+            state.credentials = Some(credentials);
+            state.generation += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{
+        AtomicUsize, Ordering,
+    };
+    use super::*;
+
+    #[test]
+    fn it_read_credentials_from_env() {
+        env::set_var("CTN_TEST_DEVICE_ID", "drc3XdxNtzoucpw9xiRp");
+        env::set_var("CTN_TEST_API_ACCESS_SECRET", "secret");
+
+        let credentials = EnvCredentialProvider::new("CTN_TEST_DEVICE_ID", "CTN_TEST_API_ACCESS_SECRET").credentials().unwrap();
+
+        assert_eq!(credentials, DeviceCredentials::from(("drc3XdxNtzoucpw9xiRp", "secret")));
+
+        let result = EnvCredentialProvider::new("CTN_TEST_DEVICE_ID", "CTN_TEST_UNDEFINED_VAR").credentials();
+
+        assert!(result.is_err(), "Read credentials from undefined environment variable");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Missing or invalid environment variable CTN_TEST_UNDEFINED_VAR");
+    }
+
+    #[test]
+    fn it_read_credentials_from_file() {
+        let path = env::temp_dir().join(format!("ctn_test_credentials_{}.json", std::process::id()));
+        fs::write(&path, r#"{"deviceId":"drc3XdxNtzoucpw9xiRp","apiAccessSecret":"secret"}"#).unwrap();
+
+        let result = FileCredentialProvider::new(&path).credentials();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(result.unwrap(), DeviceCredentials::from(("drc3XdxNtzoucpw9xiRp", "secret")));
+        assert!(FileCredentialProvider::new(&path).credentials().is_err(), "Read credentials from missing file");
+    }
+
+    #[test]
+    fn it_rotate_shared_credentials() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let shared = SharedCredentials::new(None);
+        let shared_clone = shared.clone();
+
+        assert!(shared.refresh().is_err(), "Refreshed credentials without a provider");
+
+        shared.set_provider(Arc::new(move || -> Result<DeviceCredentials> {
+            let call = calls_clone.fetch_add(1, Ordering::SeqCst);
+
+            Ok(("drc3XdxNtzoucpw9xiRp", if call < 2 { "secret1" } else { "secret2" }).into())
+        })).unwrap();
+
+        assert_eq!(shared_clone.get(), (Some(("drc3XdxNtzoucpw9xiRp", "secret1").into()), 1));
+
+        // Credentials unchanged
+        shared.refresh().unwrap();
+
+        assert_eq!(shared_clone.generation(), 1);
+
+        // Credentials changed
+        shared.refresh().unwrap();
+
+        assert_eq!(shared_clone.get(), (Some(("drc3XdxNtzoucpw9xiRp", "secret2").into()), 2));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -10,6 +10,11 @@ use std::{
     error, fmt, result,
 };
 
+use crate::{
+    ApiVersion,
+    interceptor::Veto,
+};
+
 #[derive(Debug, Deserialize, PartialEq)]
 pub(crate) struct CatenisErrorResponse {
     pub status: String,
@@ -28,6 +33,11 @@ pub(crate) type GenericError = Box<dyn error::Error + Send + Sync>;
 ///
 /// An error that takes place in the client itself, and could be of many different kinds.
 ///
+/// > **Note**: an error caused by a timeout while communicating with the Catenis API server, or by
+/// > the server's certificate not matching the pinned public keys, is also reported as a client
+/// > error. Use the [`is_timeout`](Error::is_timeout) and [`is_pin_mismatch`](Error::is_pin_mismatch)
+/// > methods to identify them.
+///
 /// # Catenis API error
 ///
 /// A response returned by the Catenis API server reporting an error condition while processing the
@@ -41,7 +51,7 @@ pub(crate) type GenericError = Box<dyn error::Error + Send + Sync>;
 /// # };
 /// #
 /// # fn main() -> Result<()> {
-/// # let mut ctn_client = CatenisClient::new_with_options(
+/// # let ctn_client = CatenisClient::new_with_options(
 /// #     Some((
 /// #         "drc3XdxNtzoucpw9xiRp",
 /// #         "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
@@ -79,6 +89,11 @@ struct Inner {
 #[derive(Debug)]
 enum ErrorKind {
     Client(Option<String>),
+    Timeout(Option<String>),
+    PinMismatch(Option<String>),
+    Vetoed(Option<String>),
+    CircuitOpen(Option<String>),
+    UnsupportedApiVersion(Option<String>),
     Api(ApiErrorInfo),
 }
 
@@ -148,6 +163,50 @@ impl Error {
         }
     }
 
+    pub(crate) fn new_timeout_error<E>(message: Option<&str>, source: Option<E>) -> Self
+        where
+            E: Into<GenericError>
+    {
+        Error {
+            inner: Box::new(Inner {
+                kind: ErrorKind::Timeout(message.map(String::from)),
+                source: source.map(Into::into),
+            })
+        }
+    }
+
+    pub(crate) fn new_pin_mismatch_error(message: Option<&str>) -> Self {
+        Error {
+            inner: Box::new(Inner {
+                kind: ErrorKind::PinMismatch(Some(String::from(
+                    message.unwrap_or(crate::pinning::PIN_MISMATCH_MESSAGE)
+                ))),
+                source: None,
+            })
+        }
+    }
+
+    pub(crate) fn new_circuit_open_error() -> Self {
+        Error {
+            inner: Box::new(Inner {
+                kind: ErrorKind::CircuitOpen(Some(String::from("Circuit breaker open; request not sent"))),
+                source: None,
+            })
+        }
+    }
+
+    pub(crate) fn new_unsupported_api_version_error(api_method: &str, api_version: ApiVersion, introduced_in: ApiVersion) -> Self {
+        Error {
+            inner: Box::new(Inner {
+                kind: ErrorKind::UnsupportedApiVersion(Some(format!(
+                    "Method {} is unsupported in API version {} (requires API version {} or later); request not sent",
+                    api_method, api_version, introduced_in
+                ))),
+                source: None,
+            })
+        }
+    }
+
     pub(crate) fn new_api_error(http_status_code: StatusCode, text_message: Option<&str>, ctn_message: Option<&str>) -> Self {
         Error {
             inner: Box::new(Inner {
@@ -208,6 +267,37 @@ impl Error {
         }
     }
 
+    /// Indicates whether this is an error caused by a timeout while communicating with the Catenis
+    /// API server.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.inner.kind, ErrorKind::Timeout(_))
+    }
+
+    /// Indicates whether this is an error caused by the certificate presented by the Catenis API
+    /// server not matching any of the pinned public keys.
+    pub fn is_pin_mismatch(&self) -> bool {
+        matches!(self.inner.kind, ErrorKind::PinMismatch(_))
+    }
+
+    /// Indicates whether this is an error caused by an [`Interceptor`](crate::Interceptor) having
+    /// vetoed the HTTP request.
+    pub fn is_vetoed(&self) -> bool {
+        matches!(self.inner.kind, ErrorKind::Vetoed(_))
+    }
+
+    /// Indicates whether this is an error caused by the client's circuit breaker being open, in
+    /// which case the HTTP request has not been sent.
+    pub fn is_circuit_open(&self) -> bool {
+        matches!(self.inner.kind, ErrorKind::CircuitOpen(_))
+    }
+
+    /// Indicates whether this is an error caused by calling a method that is not supported by the
+    /// version of the Catenis API targeted by the client, in which case the HTTP request has not
+    /// been sent.
+    pub fn is_unsupported_api_version(&self) -> bool {
+        matches!(self.inner.kind, ErrorKind::UnsupportedApiVersion(_))
+    }
+
     /// Retrieves information about the Catenis API error.
     pub fn api_error_info(&self) -> Option<&ApiErrorInfo> {
         if let ErrorKind::Api(error_info) = &self.inner.kind {
@@ -221,7 +311,12 @@ impl Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.inner.kind {
-            ErrorKind::Client(client_msg) => {
+            ErrorKind::Client(client_msg)
+                | ErrorKind::Timeout(client_msg)
+                | ErrorKind::PinMismatch(client_msg)
+                | ErrorKind::Vetoed(client_msg)
+                | ErrorKind::CircuitOpen(client_msg)
+                | ErrorKind::UnsupportedApiVersion(client_msg) => {
                 let mut error_message = String::from("Catenis client error");
 
                 if let Some(msg) = client_msg {
@@ -252,7 +347,6 @@ pub trait ConvertibleError {}
 
 // Just add a new line for the desired Error type below
 impl ConvertibleError for std::io::Error {}
-impl ConvertibleError for reqwest::Error {}
 impl ConvertibleError for reqwest::header::InvalidHeaderValue {}
 impl ConvertibleError for reqwest::header::ToStrError {}
 impl ConvertibleError for url::ParseError {}
@@ -271,6 +365,27 @@ impl<E> From<E> for Error
     }
 }
 
+impl From<Veto> for Error {
+    fn from(veto: Veto) -> Error {
+        Error {
+            inner: Box::new(Inner {
+                kind: ErrorKind::Vetoed(Some(format!("Request vetoed by interceptor: {}", veto))),
+                source: None,
+            })
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        if err.is_timeout() {
+            Error::new_timeout_error(Some("Request to Catenis API server timed out"), Some(err))
+        } else {
+            Error::new_client_error(None, Some(err))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::io;
@@ -348,6 +463,25 @@ mod tests {
         assert_eq!(err.to_string(), "Catenis client error: Sample client error description: Custom I/O error");
     }
 
+    #[test]
+    fn it_generate_timeout_error() {
+        let source_err = io::Error::new(io::ErrorKind::TimedOut, "Custom timeout error");
+        let err = Error::new_timeout_error(Some("Sample timeout description"), Some(source_err));
+
+        assert!(!err.is_api_error());
+        assert!(err.is_timeout());
+        assert_eq!(err.to_string(), "Catenis client error: Sample timeout description: Custom timeout error");
+    }
+
+    #[test]
+    fn it_generate_unsupported_api_version_error() {
+        let err = Error::new_unsupported_api_version_error("export_asset", ApiVersion(0, 10), ApiVersion(0, 11));
+
+        assert!(err.is_unsupported_api_version());
+        assert!(!err.is_api_error());
+        assert_eq!(err.to_string(), "Catenis client error: Method export_asset is unsupported in API version 0.10 (requires API version 0.11 or later); request not sent");
+    }
+
     #[test]
     fn it_generate_api_error_not_ctn() {
         let err = Error::new_api_error(StatusCode::BAD_REQUEST, None, None);
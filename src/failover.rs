@@ -0,0 +1,229 @@
+use std::{
+    sync::{
+        Arc, Mutex, PoisonError,
+    },
+    time::{
+        Duration, Instant,
+    },
+};
+use reqwest::{
+    StatusCode, Url,
+};
+
+/// Settings for failing over to alternative Catenis API endpoints.
+///
+/// The endpoint resolved from the other option settings (the primary endpoint) is used first.
+/// When a request cannot reach the endpoint in use (because of a connection or TLS error), or,
+/// optionally, when it gets a `5xx` HTTP status code in response, the client switches to the
+/// next endpoint in the list, and sends the request again right away, once per available
+/// endpoint. The endpoint in use is shared by all clones of the client, and the client fails
+/// back to the primary endpoint once `cool_down` has elapsed.
+///
+/// WebSocket notification channels are opened (and reopened) against the endpoint in use, and
+/// fail over in the same way if it cannot be reached.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use catenis_api_client::{
+///     CatenisClient, ClientOptions, FailoverOptions, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let ctn_client = CatenisClient::new_with_options(
+///     Some((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into()),
+///     &[
+///         ClientOptions::BaseUrl("https://eu.gw.example.com/catenis/"),
+///         ClientOptions::Failover(FailoverOptions {
+///             base_urls: &["https://us.gw.example.com/catenis/"],
+///             cool_down: Duration::from_secs(300),
+///             ..FailoverOptions::default()
+///         }),
+///     ],
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct FailoverOptions<'a> {
+    /// Base URLs of the alternative endpoints, in order of preference, in the same format as
+    /// [`ClientOptions::BaseUrl`](crate::ClientOptions::BaseUrl).
+    ///
+    /// > **Note**: the `Host`, `Environment` and `Secure` options do not apply to them.
+    pub base_urls: &'a [&'a str],
+    /// Indicates whether the client should also fail over when the Catenis API server responds
+    /// with a `5xx` HTTP status code. Non-idempotent (`POST`) requests are only failed over in
+    /// that case under the same conditions under which they would be retried (refer to
+    /// [`RetryPolicy`](crate::RetryPolicy)). Default value: **`false`**.
+    pub on_server_error: bool,
+    /// Time after which the client fails back to the primary endpoint. Default value:
+    /// **`60 s`**.
+    pub cool_down: Duration,
+}
+
+impl Default for FailoverOptions<'_> {
+    fn default() -> Self {
+        FailoverOptions {
+            base_urls: &[],
+            on_server_error: false,
+            cool_down: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ActiveEndpoint {
+    index: usize,
+    since: Instant,
+}
+
+/// Endpoints (base API URLs) of a client, the primary one first. The endpoint in use is shared
+/// by all clones of the client.
+#[derive(Debug, Clone)]
+pub(crate) struct Endpoints {
+    base_api_urls: Arc<[Url]>,
+    on_server_error: bool,
+    cool_down: Duration,
+    active: Arc<Mutex<ActiveEndpoint>>,
+}
+
+impl Endpoints {
+    pub(crate) fn new(base_api_urls: Vec<Url>, options: Option<&FailoverOptions>) -> Self {
+        let options = options.copied().unwrap_or_default();
+
+        Endpoints {
+            base_api_urls: base_api_urls.into(),
+            on_server_error: options.on_server_error,
+            cool_down: options.cool_down,
+            active: Arc::new(Mutex::new(ActiveEndpoint {
+                index: 0,
+                since: Instant::now(),
+            })),
+        }
+    }
+
+    /// Number of endpoints, including the primary one.
+    pub(crate) fn len(&self) -> usize {
+        self.base_api_urls.len()
+    }
+
+    /// Point a URL assembled from the primary endpoint to the endpoint in use, returning the
+    /// index of the latter.
+    pub(crate) fn select(&self, url: &mut Url) -> usize {
+        if self.len() == 1 {
+            return 0;
+        }
+
+        let index = {
+            let mut active = self.active.lock().unwrap_or_else(PoisonError::into_inner);
+
+            if active.index != 0 && active.since.elapsed() >= self.cool_down {
+                // Fail back to primary endpoint
+                active.index = 0;
+            }
+
+            active.index
+        };
+
+        if index != 0 {
+            if let Some(rebased_url) = url.as_str().strip_prefix(self.base_api_urls[0].as_str())
+                    .and_then(|path| self.base_api_urls[index].join(path).ok()) {
+                *url = rebased_url;
+            }
+        }
+
+        index
+    }
+
+    /// Switch to the endpoint following the one with the given index (unless another endpoint
+    /// is already in use), returning whether there is another endpoint to fail over to.
+    pub(crate) fn fail_over(&self, index: usize) -> bool {
+        if self.len() == 1 {
+            return false;
+        }
+
+        let mut active = self.active.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if active.index == index {
+            active.index = (index + 1) % self.len();
+            active.since = Instant::now();
+        }
+
+        true
+    }
+
+    /// Indicates whether a request that got the given HTTP status code in response should fail
+    /// over.
+    pub(crate) fn fails_over_on_status(&self, status: StatusCode) -> bool {
+        self.on_server_error && status.is_server_error()
+    }
+
+    /// Indicates whether a request that failed to be sent with the given error should fail over.
+    pub(crate) fn fails_over_on_error(err: &reqwest::Error) -> bool {
+        err.is_connect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints(cool_down: Duration) -> Endpoints {
+        Endpoints::new(
+            vec![
+                Url::parse("https://eu.gw.example.com/catenis/api/0.12/").unwrap(),
+                Url::parse("https://us.gw.example.com/api/0.12/").unwrap(),
+                Url::parse("http://localhost:3000/api/0.12/").unwrap(),
+            ],
+            Some(&FailoverOptions {
+                cool_down,
+                ..FailoverOptions::default()
+            }),
+        )
+    }
+
+    #[test]
+    fn it_fail_over_to_next_endpoints() {
+        let endpoints = endpoints(Duration::from_secs(3600));
+        let mut url = Url::parse("https://eu.gw.example.com/catenis/api/0.12/messages/log").unwrap();
+
+        assert_eq!(endpoints.select(&mut url.clone()), 0);
+        assert!(endpoints.fail_over(0));
+        // Failing over again from the same endpoint has no effect
+        assert!(endpoints.fail_over(0));
+
+        assert_eq!(endpoints.select(&mut url.clone()), 1);
+        assert!(endpoints.fail_over(1));
+
+        assert_eq!(endpoints.select(&mut url), 2);
+        assert_eq!(url.as_str(), "http://localhost:3000/api/0.12/messages/log");
+
+        assert!(endpoints.fail_over(2));
+        assert_eq!(endpoints.select(&mut url.clone()), 0);
+    }
+
+    #[test]
+    fn it_fail_back_after_cool_down() {
+        let endpoints = endpoints(Duration::from_secs(0));
+        let mut url = Url::parse("https://eu.gw.example.com/catenis/api/0.12/messages/log").unwrap();
+
+        assert!(endpoints.fail_over(0));
+        assert_eq!(endpoints.select(&mut url), 0);
+        assert_eq!(url.as_str(), "https://eu.gw.example.com/catenis/api/0.12/messages/log");
+    }
+
+    #[test]
+    fn it_not_fail_over_single_endpoint() {
+        let endpoints = Endpoints::new(vec![Url::parse("https://catenis.io/api/0.12/").unwrap()], None);
+
+        assert!(!endpoints.fail_over(0));
+        assert!(!endpoints.fails_over_on_status(StatusCode::BAD_GATEWAY));
+    }
+}
@@ -0,0 +1,445 @@
+use std::{
+    collections::HashMap,
+    fmt::{
+        self, Debug, Display, Formatter,
+    },
+    mem,
+    sync::Arc,
+};
+use reqwest::{
+    header::HeaderMap,
+    Method, StatusCode, Url,
+};
+
+use crate::{
+    Error, Result,
+};
+
+/// Hooks that are called around every HTTP request sent to the Catenis API server.
+///
+/// All methods have a default implementation that does nothing, so only the required ones need
+/// to be implemented. Any of the hooks can veto the call by returning an error, in which case the
+/// API method fails with an error for which [`Error::is_vetoed`] returns `true`.
+///
+/// When a request is retried (see [`RetryPolicy`](crate::RetryPolicy)), the hooks are called
+/// again for each attempt.
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     CatenisClient, ClientOptions, Environment, Result,
+///     Interceptor, InterceptedRequest, InterceptedResponse, Veto,
+/// };
+///
+/// struct AuditInterceptor;
+///
+/// impl Interceptor for AuditInterceptor {
+///     fn before_sign(&self, req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+///         req.headers_mut().insert("x-corp-trace-id", "a1b2c3d4".parse().unwrap());
+///         req.annotate("trace_id", "a1b2c3d4");
+///
+///         Ok(())
+///     }
+///
+///     fn after_response(&self, res: &InterceptedResponse) -> std::result::Result<(), Veto> {
+///         println!("[{}] {} -> {}", res.annotation("trace_id").unwrap_or(""), res.url(), res.status());
+///
+///         Ok(())
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let mut ctn_client = CatenisClient::new_with_options(
+///     Some((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into()),
+///     &[
+///         ClientOptions::Environment(Environment::Sandbox),
+///     ],
+/// )?;
+///
+/// ctn_client.add_interceptor(AuditInterceptor);
+/// # Ok(())
+/// # }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called before the request is signed. The request's headers can be modified.
+    fn before_sign(&self, _req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+        Ok(())
+    }
+
+    /// Called after the request is signed, right before it is sent.
+    ///
+    /// > **Note**: for requests that do not require authentication, this is called even though
+    /// > the request is not actually signed.
+    fn after_sign(&self, _req: &InterceptedRequest) -> std::result::Result<(), Veto> {
+        Ok(())
+    }
+
+    /// Called after a response is received, before it is processed. This is called for both
+    /// successful and error responses.
+    fn after_response(&self, _res: &InterceptedResponse) -> std::result::Result<(), Veto> {
+        Ok(())
+    }
+}
+
+/// Indicates that an interceptor has refused to let an HTTP request proceed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Veto {
+    reason: String,
+}
+
+impl Veto {
+    /// Create a new veto with the given reason.
+    pub fn new<S: Into<String>>(reason: S) -> Self {
+        Veto {
+            reason: reason.into(),
+        }
+    }
+
+    /// The reason for the veto.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for Veto {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+/// View of an HTTP request passed to an [`Interceptor`].
+pub struct InterceptedRequest<'a> {
+    method: &'a Method,
+    url: &'a Url,
+    headers: &'a mut HeaderMap,
+    body: Option<&'a [u8]>,
+    attempt: u32,
+    annotations: &'a mut HashMap<String, String>,
+}
+
+impl<'a> InterceptedRequest<'a> {
+    /// The request's HTTP method.
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    /// The request's URL.
+    pub fn url(&self) -> &Url {
+        self.url
+    }
+
+    /// The request's HTTP headers.
+    pub fn headers(&self) -> &HeaderMap {
+        self.headers
+    }
+
+    /// Mutable access to the request's HTTP headers.
+    ///
+    /// > **Note**: changes made after the request is signed may invalidate its signature.
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        self.headers
+    }
+
+    /// The request's body, as sent to the server (it may be compressed).
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body
+    }
+
+    /// The attempt number (starting at 1) of sending this request.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Attach an annotation to the call. Annotations are made available to all the subsequent
+    /// hooks called for the same API method call.
+    pub fn annotate<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        self.annotations.insert(key.into(), value.into());
+    }
+
+    /// Retrieve an annotation attached to the call.
+    pub fn annotation(&self, key: &str) -> Option<&str> {
+        self.annotations.get(key).map(String::as_str)
+    }
+}
+
+/// View of an HTTP response passed to an [`Interceptor`].
+pub struct InterceptedResponse<'a> {
+    status: StatusCode,
+    url: &'a Url,
+    headers: &'a HeaderMap,
+    attempt: u32,
+    annotations: &'a HashMap<String, String>,
+}
+
+impl<'a> InterceptedResponse<'a> {
+    /// The response's HTTP status code.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The URL of the response.
+    pub fn url(&self) -> &Url {
+        self.url
+    }
+
+    /// The response's HTTP headers.
+    pub fn headers(&self) -> &HeaderMap {
+        self.headers
+    }
+
+    /// The attempt number (starting at 1) of sending the request.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Retrieve an annotation attached to the call.
+    pub fn annotation(&self, key: &str) -> Option<&str> {
+        self.annotations.get(key).map(String::as_str)
+    }
+}
+
+/// Common interface of the blocking and asynchronous HTTP requests.
+pub(crate) trait HttpRequest {
+    fn method(&self) -> &Method;
+    fn url(&self) -> &Url;
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    fn headers(&self) -> &HeaderMap;
+    fn headers_mut(&mut self) -> &mut HeaderMap;
+    fn body_bytes(&self) -> Option<&[u8]>;
+}
+
+impl HttpRequest for reqwest::blocking::Request {
+    fn method(&self) -> &Method {
+        self.method()
+    }
+
+    fn url(&self) -> &Url {
+        self.url()
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        self.headers()
+    }
+
+    fn headers_mut(&mut self) -> &mut HeaderMap {
+        self.headers_mut()
+    }
+
+    fn body_bytes(&self) -> Option<&[u8]> {
+        self.body().and_then(|body| body.as_bytes())
+    }
+}
+
+#[cfg(feature = "async")]
+impl HttpRequest for reqwest::Request {
+    fn method(&self) -> &Method {
+        self.method()
+    }
+
+    fn url(&self) -> &Url {
+        self.url()
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        self.headers()
+    }
+
+    fn headers_mut(&mut self) -> &mut HeaderMap {
+        self.headers_mut()
+    }
+
+    fn body_bytes(&self) -> Option<&[u8]> {
+        self.body().and_then(|body| body.as_bytes())
+    }
+}
+
+/// Chain of interceptors registered on a client.
+#[derive(Clone, Default)]
+pub(crate) struct InterceptorChain(Vec<Arc<dyn Interceptor>>);
+
+impl Debug for InterceptorChain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "InterceptorChain({} interceptors)", self.0.len())
+    }
+}
+
+impl InterceptorChain {
+    pub(crate) fn add(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.0.push(interceptor);
+    }
+
+    pub(crate) fn before_sign<R: HttpRequest>(&self, req: &mut R, attempt: u32, annotations: &mut HashMap<String, String>) -> Result<()> {
+        self.with_request(req, attempt, annotations, |interceptor, intercepted_req| {
+            interceptor.before_sign(intercepted_req)
+        })
+    }
+
+    pub(crate) fn after_sign<R: HttpRequest>(&self, req: &mut R, attempt: u32, annotations: &mut HashMap<String, String>) -> Result<()> {
+        self.with_request(req, attempt, annotations, |interceptor, intercepted_req| {
+            interceptor.after_sign(intercepted_req)
+        })
+    }
+
+    pub(crate) fn after_response(&self, status: StatusCode, url: &Url, headers: &HeaderMap, attempt: u32, annotations: &HashMap<String, String>) -> Result<()> {
+        let intercepted_res = InterceptedResponse {
+            status,
+            url,
+            headers,
+            attempt,
+            annotations,
+        };
+
+        for interceptor in &self.0 {
+            interceptor.after_response(&intercepted_res).map_err(Error::from)?;
+        }
+
+        Ok(())
+    }
+
+    fn with_request<R, F>(&self, req: &mut R, attempt: u32, annotations: &mut HashMap<String, String>, hook: F) -> Result<()>
+        where
+            R: HttpRequest,
+            F: Fn(&dyn Interceptor, &mut InterceptedRequest) -> std::result::Result<(), Veto>
+    {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+
+        // Note: headers are temporarily moved out of the request so they can be borrowed mutably
+        //  alongside the other parts of the request
+        let mut headers = mem::take(req.headers_mut());
+
+        let result = {
+            let mut intercepted_req = InterceptedRequest {
+                method: req.method(),
+                url: req.url(),
+                headers: &mut headers,
+                body: req.body_bytes(),
+                attempt,
+                annotations,
+            };
+
+            self.0.iter()
+                .try_for_each(|interceptor| hook(interceptor.as_ref(), &mut intercepted_req))
+        };
+
+        *req.headers_mut() = headers;
+
+        result.map_err(Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use reqwest::header::HeaderValue;
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingInterceptor {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl Interceptor for RecordingInterceptor {
+        fn before_sign(&self, req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+            self.calls.lock().unwrap().push(format!("before_sign {} {} {}", req.method(), req.url(), req.attempt()));
+            req.headers_mut().insert("x-trace-id", HeaderValue::from_static("1234"));
+            req.annotate("trace_id", "1234");
+
+            Ok(())
+        }
+
+        fn after_sign(&self, req: &InterceptedRequest) -> std::result::Result<(), Veto> {
+            self.calls.lock().unwrap().push(format!("after_sign {:?} {:?}", req.headers().get("x-trace-id"), req.body()));
+
+            Ok(())
+        }
+
+        fn after_response(&self, res: &InterceptedResponse) -> std::result::Result<(), Veto> {
+            self.calls.lock().unwrap().push(format!("after_response {} {:?}", res.status(), res.annotation("trace_id")));
+
+            Ok(())
+        }
+    }
+
+    struct VetoInterceptor;
+
+    impl Interceptor for VetoInterceptor {
+        fn before_sign(&self, req: &mut InterceptedRequest) -> std::result::Result<(), Veto> {
+            if req.method() == Method::POST {
+                Err(Veto::new("Write operations not allowed"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn it_call_interceptor_hooks() {
+        let interceptor = Arc::new(RecordingInterceptor::default());
+        let mut chain = InterceptorChain::default();
+        chain.add(interceptor.clone());
+
+        let mut req = reqwest::blocking::Client::new()
+            .post("https://catenis.io/api/0.12/messages/log")
+            .body("{}")
+            .build()
+            .unwrap();
+        let mut annotations = HashMap::new();
+
+        chain.before_sign(&mut req, 1, &mut annotations).unwrap();
+        chain.after_sign(&mut req, 1, &mut annotations).unwrap();
+        chain.after_response(StatusCode::OK, req.url(), &HeaderMap::new(), 1, &annotations).unwrap();
+
+        assert_eq!(req.headers().get("x-trace-id").unwrap(), "1234");
+        assert_eq!(*interceptor.calls.lock().unwrap(), vec![
+            String::from("before_sign POST https://catenis.io/api/0.12/messages/log 1"),
+            String::from("after_sign Some(\"1234\") Some([123, 125])"),
+            String::from("after_response 200 OK Some(\"1234\")"),
+        ]);
+    }
+
+    #[test]
+    fn it_veto_request() {
+        let mut chain = InterceptorChain::default();
+        chain.add(Arc::new(VetoInterceptor));
+
+        let mut req = reqwest::blocking::Client::new()
+            .post("https://catenis.io/api/0.12/messages/log")
+            .header("x-custom", "abc")
+            .build()
+            .unwrap();
+
+        let result = chain.before_sign(&mut req, 1, &mut HashMap::new());
+
+        assert!(result.is_err(), "Request not vetoed");
+
+        let err = result.err().unwrap();
+
+        assert!(err.is_vetoed());
+        assert_eq!(err.to_string(), "Catenis client error: Request vetoed by interceptor: Write operations not allowed");
+
+        // Make sure that request headers have been preserved
+        assert_eq!(req.headers().get("x-custom").unwrap(), "abc");
+    }
+
+    #[test]
+    fn it_skip_empty_chain() {
+        let chain = InterceptorChain::default();
+
+        let mut req = reqwest::blocking::Client::new()
+            .get("https://catenis.io/api/0.12/messages/abc")
+            .build()
+            .unwrap();
+
+        assert!(chain.before_sign(&mut req, 1, &mut HashMap::new()).is_ok());
+        assert_eq!(format!("{:?}", chain), "InterceptorChain(0 interceptors)");
+    }
+}
@@ -18,7 +18,7 @@
 //!
 //! # fn main() -> Result<()> {
 //! // Instantiate Catenis API client object
-//! let mut ctn_client = CatenisClient::new_with_options(
+//! let ctn_client = CatenisClient::new_with_options(
 //!     Some((
 //!         "drc3XdxNtzoucpw9xiRp",
 //!         concat!(
@@ -39,6 +39,9 @@
 //! # }
 //! ```
 //!
+//! All API methods take a shared reference to the client object, which is also `Send` and `Sync`,
+//! so a single client object can be shared (e.g. via an `Arc`) among several threads.
+//!
 //! ## Notification
 //!
 //! The library also makes it easy for receiving notifications from the Catenis system through its
@@ -52,7 +55,7 @@
 //! To activate asynchronous processing, the **`async`** feature must be enabled.
 //!
 //! ```toml
-//! catenis_api_client = { version = "3.0", features = ["async"] }
+//! catenis_api_client = { version = "4.0", features = ["async"] }
 //! ```
 //!
 //! The asynchronous version of the client can then be accessed from the [`async_impl`] module.
@@ -68,7 +71,7 @@
 //! # #[tokio::main]
 //! # async fn main() -> Result<()> {
 //! // Instantiate asynchronous Catenis API client object
-//! let mut ctn_client = async_impl::CatenisClient::new_with_options(
+//! let ctn_client = async_impl::CatenisClient::new_with_options(
 //!     Some((
 //!         "drc3XdxNtzoucpw9xiRp",
 //!         concat!(
@@ -83,24 +86,92 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## TLS backend
+//!
+//! By default, the library uses the platform's native TLS implementation (via the
+//! [native-tls](https://crates.io/crates/native-tls) crate). To use [rustls](https://crates.io/crates/rustls)
+//! instead (for instance, to build fully static binaries), disable the default features and enable the
+//! **`rustls`** feature. Note that public key pinning (see [`ClientOptions::PinnedPublicKeys`]) is only
+//! available with the `rustls` feature.
+//!
+//! ```toml
+//! catenis_api_client = { version = "4.0", default-features = false, features = ["rustls"] }
+//! ```
+//!
+//! With neither the `native-tls` nor the `rustls` feature enabled, the library still builds, but only plain
+//! (`http` and `ws`) connections are supported, and any TLS related option setting makes the instantiation of
+//! the client fail.
+//!
+//! ## Tracing
+//!
+//! Enable the **`tracing`** feature to have the library emit [tracing](https://crates.io/crates/tracing)
+//! spans and events. Each API method call is recorded as a span named after the method (e.g.
+//! `log_message`) carrying the endpoint path, HTTP status, latency, retry count and payload sizes.
+//! WebSocket notification channels emit events for their lifecycle (connection, authentication, channel
+//! open and close codes) within a `ws_notify_channel` span.
+//!
+//! The value of the `authorization` header is redacted, and request or response bodies are never
+//! recorded.
+//!
+//! ```toml
+//! catenis_api_client = { version = "4.0", features = ["tracing"] }
+//! ```
+//!
+//! ## Metrics
+//!
+//! To feed dashboards, implement the [`MetricsSink`] trait and register it with a client via its
+//! [`set_metrics_sink()`](CatenisClient::set_metrics_sink) method. The sink is notified of every API method call (method name, HTTP status,
+//! latency, retry count and whether it succeeded), and of the activity (open, reopen, close and delivered
+//! notifications) of the WebSocket notification channels created from that client.
 
 use std::{
     fmt::{
         self,
         Display, Formatter,
     },
+    str::FromStr,
+    time::Duration,
 };
+use serde::{
+    Deserialize, Deserializer,
+    de,
+};
+use zeroize::Zeroizing;
 
 pub use serde_json::Value as JsonValue;
 pub use serde_json::Map as JsonMap;
+pub use reqwest::Method;
 pub use api::JsonObject;
 
 #[macro_use]
 mod macro_impl;
 
 mod base_client;
+mod builder;
+mod circuit_breaker;
 mod client;
+mod clock_skew;
+mod compression;
+mod config;
+mod connection;
+mod credentials;
 mod date_time;
+mod failover;
+mod interceptor;
+mod metrics;
+mod pagination;
+mod pinning;
+mod pool;
+mod proxy;
+mod rate_limit;
+mod retry;
+mod signer;
+mod signing_state;
+mod tls;
+mod trace;
+mod verifier;
+mod versioning;
 
 #[cfg(test)]
 mod test_helper;
@@ -114,11 +185,45 @@ pub mod async_impl;
 use error::GenericError;
 
 pub use client::*;
+pub use builder::CatenisClientBuilder;
 #[doc(no_inline)]
 pub use error::{
     Error, Result,
 };
 pub use date_time::UtcDateTime;
+pub use retry::RetryPolicy;
+pub use rate_limit::RateLimit;
+pub use config::{
+    ClientConfig, ProxyConfig, ClientIdentityConfig, FailoverConfig,
+    BASE_URL_ENV_VAR, HOST_ENV_VAR, ENVIRONMENT_ENV_VAR, SECURE_ENV_VAR, API_VERSION_ENV_VAR,
+};
+pub use circuit_breaker::{
+    CircuitBreakerPolicy, CircuitState,
+};
+pub use signer::{
+    RequestSigner, SignatureHeaders,
+};
+pub use verifier::{
+    SignatureVerifier, VerifiedRequest, VerificationError, VerificationResult,
+};
+pub use credentials::{
+    CredentialProvider, EnvCredentialProvider, FileCredentialProvider,
+    DEVICE_ID_ENV_VAR, API_ACCESS_SECRET_ENV_VAR,
+};
+pub use pool::DeviceClientPool;
+pub use proxy::ProxyOptions;
+pub use failover::FailoverOptions;
+pub use compression::{
+    CompressionOptions, RequestCompression,
+};
+pub use tls::ClientIdentity;
+pub use interceptor::{
+    Interceptor, InterceptedRequest, InterceptedResponse, Veto,
+};
+pub use metrics::{
+    MetricsSink, ApiCallMetrics, NotifyChannelActivity,
+};
+pub use versioning::ApiMethod;
 #[doc(no_inline)]
 pub use notification::WsNotifyChannel;
 
@@ -130,25 +235,91 @@ const DEFAULT_API_VERSION: ApiVersion = ApiVersion(0, 12);
 type KVList<'a> = &'a [(&'a str, &'a str)];
 
 /// Credentials for a Catenis virtual device to access the Catenis API services.
+///
+/// > **Note**: the API access secret is redacted when the credentials are debug formatted.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct DeviceCredentials {
     /// The device ID.
     pub device_id: String,
     /// Device's API access secret.
-    pub api_access_secret: String,
+    pub api_access_secret: ApiAccessSecret,
 }
 
 impl From<(&str, &str)> for DeviceCredentials {
     fn from(pair: (&str, &str)) -> DeviceCredentials {
         DeviceCredentials {
             device_id: String::from(pair.0),
-            api_access_secret: String::from(pair.1),
+            api_access_secret: ApiAccessSecret::from(pair.1),
         }
     }
 }
 
+impl From<(&str, ApiAccessSecret)> for DeviceCredentials {
+    fn from(pair: (&str, ApiAccessSecret)) -> DeviceCredentials {
+        DeviceCredentials {
+            device_id: String::from(pair.0),
+            api_access_secret: pair.1,
+        }
+    }
+}
+
+/// The API access secret of a Catenis virtual device.
+///
+/// Its contents are zeroed when it is dropped, and it cannot be printed by accident: it does not
+/// implement `Display`, and its `Debug` implementation redacts the secret.
+///
+/// # Example
+///
+/// ```
+/// use catenis_api_client::{
+///     ApiAccessSecret, DeviceCredentials,
+/// };
+///
+/// let secret = ApiAccessSecret::from(concat!(
+///     "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///     "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+/// ));
+/// let device_credentials = DeviceCredentials::from(("drc3XdxNtzoucpw9xiRp", secret));
+///
+/// assert!(!format!("{:?}", device_credentials).contains(device_credentials.api_access_secret.expose_secret()));
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct ApiAccessSecret(Zeroizing<String>);
+
+impl ApiAccessSecret {
+    /// Retrieve the actual secret.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ApiAccessSecret {
+    fn from(secret: String) -> Self {
+        ApiAccessSecret(Zeroizing::new(secret))
+    }
+}
+
+impl From<&str> for ApiAccessSecret {
+    fn from(secret: &str) -> Self {
+        ApiAccessSecret(Zeroizing::new(String::from(secret)))
+    }
+}
+
+impl fmt::Debug for ApiAccessSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ApiAccessSecret([redacted])")
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiAccessSecret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
 /// Available Catenis API server environments.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Environment {
     /// Production environment.
     Prod,
@@ -169,30 +340,132 @@ pub enum Environment {
 /// assert_eq!(api_version.to_string(), "10.0");
 /// # }
 ///```
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct ApiVersion(pub u16, pub u16);
 
+impl ApiVersion {
+    /// Indicates whether a given method of the Catenis API client (e.g.
+    /// [`ApiMethod::ExportAsset`]) is available in this version of the Catenis API.
+    pub fn supports_method(&self, api_method: ApiMethod) -> bool {
+        versioning::check_method_support(api_method, *self).is_ok()
+    }
+}
+
 impl Display for ApiVersion {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}.{}", self.0, self.1)
     }
 }
 
+impl FromStr for ApiVersion {
+    type Err = Error;
+
+    /// Parse an API version in the `"<major>.<minor>"` format (e.g. `"0.12"`).
+    fn from_str(s: &str) -> Result<Self> {
+        s.split_once('.')
+            .and_then(|(major, minor)| Some(ApiVersion(major.parse().ok()?, minor.parse().ok()?)))
+            .ok_or_else(|| Error::new_client_error(Some("Invalid API version"), None::<GenericError>))
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
 /// Option settings that can be used when instantiating the Catenis API client.
 #[derive(Debug, Copy, Clone)]
 pub enum ClientOptions<'a> {
+    /// Full base URL of target Catenis API server, made up of the scheme (`http` or `https`), host,
+    /// optional port and optional path prefix (e.g. `"https://gw.internal/catenis/"`), to which
+    /// the API path (`api/:version/`) is appended. It is also used, with the scheme changed to
+    /// `ws` or `wss`, for notification channels. Default value: **`"https://catenis.io/"`**.
+    ///
+    /// > **Note**: this option replaces the outcome of any previously listed `Host`, `Environment`
+    /// > or `Secure` options, while those listed after it are applied to it.
+    BaseUrl(&'a str),
     /// Host name (with optional port) of target Catenis API server. Default value: **`"catenis.io"`**.
     Host(&'a str),
     /// Environment of target Catenis API server. Default value: **`Environment::Prod`**.
     Environment(Environment),
     /// Indicates whether a secure connection (HTTPS) should be used. Default value: **`true`**.
     Secure(bool),
-    /// Version of Catenis API to target. Default value: **`ApiVersion(11, 0)`**.
+    /// Version of Catenis API to target. Default value: **`ApiVersion(0, 12)`**.
+    ///
+    /// > **Note**: methods that are not available in the targeted version (e.g. the non-fungible
+    /// > asset methods, which require version 0.12) fail, without sending the request, with an
+    /// > error for which [`Error::is_unsupported_api_version`] returns `true`. Responses are
+    /// > deserialized with the shape returned by the targeted version: for versions prior to
+    /// > 0.12, asset issuance history entries are all
+    /// > [`Regular`](api::AssetIssuanceEventEntry::Regular), and the `is_non_fungible` field of
+    /// > [`RetrieveAssetInfoResult`](api::RetrieveAssetInfoResult) is set to `false`.
     Version(ApiVersion),
-    /// Indicates whether request body should be compressed. Default value: **`true`**.
+    /// Indicates whether request body should be compressed, and compressed response body should
+    /// be accepted. Default value: **`true`**.
     UseCompression(bool),
+    /// Algorithm used to compress request body, and encodings accepted for response body, while
+    /// compression is in use. Default value: **`CompressionOptions::default()`** (`deflate`
+    /// compressed request body, and `gzip` encoded response body).
+    Compression(CompressionOptions),
     /// Minimum size, in bytes, of request body for it to be compressed. Default value: **`1024`**.
     CompressThreshold(usize),
+    /// Policy for automatically retrying failed requests. Default value: **`RetryPolicy::no_retry()`**.
+    Retry(RetryPolicy),
+    /// Limit on the rate at which requests are sent, shared by all clones of the client.
+    /// Default value: **no limit**.
+    RateLimit(RateLimit),
+    /// Policy of a circuit breaker that makes requests fail fast while the Catenis API server is
+    /// failing, shared by all clones of the client. Default value: **no circuit breaker**.
+    CircuitBreaker(CircuitBreakerPolicy),
+    /// Maximum time allowed for a complete request, from connecting until the response body has
+    /// been read. Default value: **`30 s`** for the blocking client, and **no timeout** for the
+    /// asynchronous client.
+    Timeout(Duration),
+    /// Maximum time allowed for establishing a connection with the Catenis API server, for both
+    /// regular requests and notification channels. Default value: **no timeout**.
+    ConnectTimeout(Duration),
+    /// Maximum overall time allowed for completing the WebSocket handshake when opening a
+    /// notification channel, counted from when the connection is established. It covers opening
+    /// the tunnel through the proxy (if any), the TLS handshake and the WebSocket handshake proper,
+    /// and it is enforced no matter how slowly the server responds. Default value: **no timeout**.
+    WsHandshakeTimeout(Duration),
+    /// HTTP proxy through which connections with the Catenis API server, including WebSocket
+    /// notification channels, should go. Default value: **no proxy**.
+    ///
+    /// > **Note**: when this option is not set, proxies configured via environment variables are
+    /// > honored for regular requests only.
+    Proxy(ProxyOptions<'a>),
+    /// PEM encoded bundle of additional root certificates to trust when establishing TLS
+    /// connections. This option can be used multiple times. Default value: **no additional
+    /// root certificates**.
+    TlsRootCertificates(&'a [u8]),
+    /// Indicates whether the built-in root certificates (from the operating system with the
+    /// `native-tls` feature, or from the `webpki-roots` crate with the `rustls` feature) should be
+    /// trusted. Default value: **`true`**.
+    TlsBuiltInRoots(bool),
+    /// Client certificate to be presented when establishing TLS connections. Default value:
+    /// **no client certificate**.
+    TlsClientIdentity(ClientIdentity<'a>),
+    /// Base64 encoded SHA-256 hashes of the DER encoded SubjectPublicKeyInfo (SPKI) of the public
+    /// keys that the Catenis API server's certificate chain is allowed to have. Each pin may
+    /// optionally be prefixed with `sha256//`. A secure connection whose server certificate chain
+    /// does not match any of the pins fails with an error for which [`Error::is_pin_mismatch`]
+    /// returns `true`. Default value: **no pinning**.
+    ///
+    /// > **Note**: the pins are matched against every certificate of the chain presented by the
+    /// > server, and against the trusted root certificate that issued it, so an intermediate or
+    /// > root CA may be pinned. The check takes place during the TLS handshake, before anything
+    /// > is sent to the server.
+    ///
+    /// > **Note**: public key pinning requires the **`rustls`** feature. Without it, the
+    /// > instantiation of the client fails when this option is set.
+    PinnedPublicKeys(&'a [&'a str]),
+    /// Alternative endpoints to fail over to when the Catenis API server cannot be reached.
+    /// Default value: **no failover**.
+    Failover(FailoverOptions<'a>),
 }
 
 #[cfg(test)]
@@ -208,7 +481,7 @@ mod tests {
 
         assert_eq!(device_credentials, DeviceCredentials {
             device_id: String::from("drc3XdxNtzoucpw9xiRp"),
-            api_access_secret: String::from("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"),
+            api_access_secret: ApiAccessSecret::from("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"),
         });
     }
 
@@ -221,14 +494,35 @@ mod tests {
 
         assert_eq!(device_credentials, DeviceCredentials {
             device_id: String::from("drc3XdxNtzoucpw9xiRp"),
-            api_access_secret: String::from("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"),
+            api_access_secret: ApiAccessSecret::from("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"),
         });
     }
 
+    #[test]
+    fn it_redact_api_access_secret() {
+        let device_credentials = DeviceCredentials::from((
+            "drc3XdxNtzoucpw9xiRp",
+            ApiAccessSecret::from(String::from("4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3")),
+        ));
+
+        assert_eq!(
+            format!("{:?}", device_credentials),
+            r#"DeviceCredentials { device_id: "drc3XdxNtzoucpw9xiRp", api_access_secret: ApiAccessSecret([redacted]) }"#
+        );
+        assert_eq!(device_credentials.api_access_secret.expose_secret(), "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3");
+    }
+
     #[test]
     fn it_format_api_version() {
         let api_version = ApiVersion(10, 0);
 
         assert_eq!(api_version.to_string(), "10.0");
     }
+
+    #[test]
+    fn it_parse_api_version() {
+        assert_eq!("0.12".parse::<ApiVersion>().unwrap(), ApiVersion(0, 12));
+        assert!("0.x".parse::<ApiVersion>().is_err(), "Parsed invalid API version");
+        assert!("12".parse::<ApiVersion>().is_err(), "Parsed invalid API version");
+    }
 }
\ No newline at end of file
@@ -7,6 +7,14 @@ macro_rules! now {
     () => { time::OffsetDateTime::now_utc() }
 }
 
+/// Emit a `tracing` event. Expands to nothing if the `tracing` feature is not enabled.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+
 /// Helper for defining custom non-fungible token metadata properties.
 ///
 /// Pass a JSON literal as the single input parameter, and the macro will return the corresponding
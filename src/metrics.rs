@@ -0,0 +1,227 @@
+use std::{
+    fmt::{
+        self, Debug, Formatter,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::api::NotificationEvent;
+
+/// Receiver of metrics about the calls made to the Catenis API and the activity of WebSocket
+/// notification channels.
+///
+/// All methods have a default implementation that does nothing, so only the required ones need
+/// to be implemented. The methods are called synchronously from the client (or from the
+/// notification channel's thread/task), so they should return quickly, for instance by simply
+/// updating counters and histograms.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::atomic::{
+///     AtomicU64, Ordering,
+/// };
+/// use catenis_api_client::{
+///     CatenisClient, ClientOptions, Environment, Result,
+///     MetricsSink, ApiCallMetrics,
+/// };
+///
+/// #[derive(Default)]
+/// struct ErrorCounter {
+///     errors: AtomicU64,
+/// }
+///
+/// impl MetricsSink for ErrorCounter {
+///     fn record_api_call(&self, metrics: &ApiCallMetrics) {
+///         if !metrics.success {
+///             self.errors.fetch_add(1, Ordering::Relaxed);
+///
+///             println!("{} failed (status: {:?}) after {:?}", metrics.api_method, metrics.status, metrics.latency);
+///         }
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let mut ctn_client = CatenisClient::new_with_options(
+///     Some((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into()),
+///     &[
+///         ClientOptions::Environment(Environment::Sandbox),
+///     ],
+/// )?;
+///
+/// ctn_client.set_metrics_sink(ErrorCounter::default());
+/// # Ok(())
+/// # }
+/// ```
+pub trait MetricsSink: Send + Sync {
+    /// Called when a call to a Catenis API method ends, either successfully or not.
+    fn record_api_call(&self, _metrics: &ApiCallMetrics) {}
+
+    /// Called when there is activity on a WebSocket notification channel.
+    fn record_notify_channel(&self, _event: &NotificationEvent, _activity: NotifyChannelActivity) {}
+}
+
+impl<T: MetricsSink + ?Sized> MetricsSink for Arc<T> {
+    fn record_api_call(&self, metrics: &ApiCallMetrics) {
+        (**self).record_api_call(metrics)
+    }
+
+    fn record_notify_channel(&self, event: &NotificationEvent, activity: NotifyChannelActivity) {
+        (**self).record_notify_channel(event, activity)
+    }
+}
+
+/// Metrics about a single Catenis API method call.
+#[derive(Debug, Clone)]
+pub struct ApiCallMetrics<'a> {
+    /// Name of the API method (e.g. `log_message`).
+    pub api_method: &'a str,
+    /// HTTP status code of the (last) response received, if any.
+    pub status: Option<u16>,
+    /// Indicates whether the call succeeded.
+    pub success: bool,
+    /// Total time taken by the call, including the time spent waiting between retries.
+    pub latency: Duration,
+    /// Number of times that the request has been retried.
+    pub retries: u32,
+}
+
+/// Activity on a WebSocket notification channel.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NotifyChannelActivity {
+    /// The notification channel has been successfully open.
+    Open,
+    /// A notification channel that had been previously open has been open again.
+    Reopen,
+    /// The notification channel has been closed.
+    Close,
+    /// A notification has been delivered.
+    Notification,
+}
+
+/// Optional metrics sink of a client.
+#[derive(Clone, Default)]
+pub(crate) struct MetricsRecorder(Option<Arc<dyn MetricsSink>>);
+
+impl Debug for MetricsRecorder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "MetricsRecorder({})", if self.0.is_some() { "set" } else { "unset" })
+    }
+}
+
+impl MetricsRecorder {
+    pub(crate) fn set(&mut self, sink: Arc<dyn MetricsSink>) {
+        self.0 = Some(sink);
+    }
+
+    pub(crate) fn record_api_call(&self, metrics: &ApiCallMetrics) {
+        if let Some(sink) = &self.0 {
+            sink.record_api_call(metrics);
+        }
+    }
+
+    pub(crate) fn record_notify_channel(&self, event: &NotificationEvent, activity: NotifyChannelActivity) {
+        if let Some(sink) = &self.0 {
+            sink.record_notify_channel(event, activity);
+        }
+    }
+}
+
+/// Reports the activity of a WebSocket notification channel's connection to the client's metrics
+/// sink.
+///
+/// > **Note**: the closing of the notification channel is reported when this object is dropped,
+/// > so it is done regardless of how the connection ends (closed by either side, or failed).
+pub(crate) struct NotifyChannelMetrics {
+    metrics: MetricsRecorder,
+    event: NotificationEvent,
+}
+
+impl NotifyChannelMetrics {
+    pub(crate) fn new(metrics: MetricsRecorder, event: NotificationEvent) -> Self {
+        NotifyChannelMetrics {
+            metrics,
+            event,
+        }
+    }
+
+    pub(crate) fn record(&self, activity: NotifyChannelActivity) {
+        self.metrics.record_notify_channel(&self.event, activity);
+    }
+}
+
+impl Drop for NotifyChannelMetrics {
+    fn drop(&mut self) {
+        self.record(NotifyChannelActivity::Close);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingSink {
+        activities: Mutex<Vec<(NotificationEvent, NotifyChannelActivity)>>,
+    }
+
+    impl MetricsSink for RecordingSink {
+        fn record_notify_channel(&self, event: &NotificationEvent, activity: NotifyChannelActivity) {
+            self.activities.lock().unwrap().push((event.clone(), activity));
+        }
+    }
+
+    #[test]
+    fn it_report_to_shared_sink() {
+        let sink = Arc::new(RecordingSink::default());
+        let mut recorder = MetricsRecorder::default();
+        recorder.set(Arc::new(sink.clone()));
+
+        recorder.record_notify_channel(&NotificationEvent::NewMsgReceived, NotifyChannelActivity::Open);
+        recorder.record_api_call(&ApiCallMetrics {
+            api_method: "log_message",
+            status: Some(200),
+            success: true,
+            latency: Duration::from_millis(10),
+            retries: 0,
+        });
+
+        assert_eq!(*sink.activities.lock().unwrap(), vec![
+            (NotificationEvent::NewMsgReceived, NotifyChannelActivity::Open),
+        ]);
+        assert_eq!(format!("{:?}", recorder), "MetricsRecorder(set)");
+    }
+
+    #[test]
+    fn it_report_notify_channel_close_on_drop() {
+        let sink = Arc::new(RecordingSink::default());
+        let mut recorder = MetricsRecorder::default();
+        recorder.set(Arc::new(sink.clone()));
+
+        let channel_metrics = NotifyChannelMetrics::new(recorder, NotificationEvent::NewMsgReceived);
+        channel_metrics.record(NotifyChannelActivity::Open);
+        drop(channel_metrics);
+
+        assert_eq!(*sink.activities.lock().unwrap(), vec![
+            (NotificationEvent::NewMsgReceived, NotifyChannelActivity::Open),
+            (NotificationEvent::NewMsgReceived, NotifyChannelActivity::Close),
+        ]);
+    }
+
+    #[test]
+    fn it_skip_unset_sink() {
+        let recorder = MetricsRecorder::default();
+
+        recorder.record_notify_channel(&NotificationEvent::NewMsgReceived, NotifyChannelActivity::Close);
+
+        assert_eq!(format!("{:?}", recorder), "MetricsRecorder(unset)");
+    }
+}
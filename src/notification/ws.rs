@@ -23,9 +23,6 @@ use tungstenite::{
     client::{
         IntoClientRequest,
     },
-    stream::{
-        MaybeTlsStream,
-    },
 };
 use serde::{
     Serialize,
@@ -33,12 +30,14 @@ use serde::{
 
 use super::*;
 use crate::{
-    CatenisClient,
+    CatenisClient, NotifyChannelActivity,
     api::{
         NotificationEvent,
     },
     Result, Error, X_BCOT_TIMESTAMP,
     error::GenericError,
+    connection, tls,
+    metrics::NotifyChannelMetrics,
 };
 
 pub use tungstenite::protocol::CloseFrame;
@@ -177,52 +176,64 @@ impl WsNotifyChannel {
         where
             F: Fn(WsNotifyChannelEvent) + Send + 'static
     {
-        // Prepare to connect to Catenis WebSocket notification service
-        //  Note: this request is only used to assemble the URL for the notification service
-        //      and generate the required data for authentication with the notification service.
-        //      The actual request used to open a WebSocket connection is created below
-        //      (from this request's URL).
-        let mut auth_req = self.api_client.get_ws_request(
-            "notify/ws/:event_name",
-            Some(&[("event_name", self.event.to_string().as_str())])
-        )?;
-
-        self.api_client.sign_request(&mut auth_req)?;
-
-        let ws_notify_auth_msg_json = serde_json::to_string(
-            &WsNotifyChannelAuthentication {
-                x_bcot_timestamp: auth_req.headers()
-                    .get(X_BCOT_TIMESTAMP)
-                    .unwrap_or(&HeaderValue::from_static(""))
-                    .to_str()?
-                    .into(),
-                authorization: auth_req.headers()
-                    .get(AUTHORIZATION)
-                    .unwrap_or(&HeaderValue::from_static(""))
-                    .to_str()?
-                    .into()
+        // Note: fail over at most once per alternative endpoint
+        let mut failovers = 0;
+
+        let (ws_notify_auth_msg_json, mut ws) = loop {
+            // Prepare to connect to Catenis WebSocket notification service
+            //  Note: this request is only used to assemble the URL for the notification service
+            //      and generate the required data for authentication with the notification service.
+            //      The actual request used to open a WebSocket connection is created below
+            //      (from this request's URL).
+            let (mut auth_req, endpoint) = self.api_client.get_ws_request(
+                "notify/ws/:event_name",
+                Some(&[("event_name", self.event.to_string().as_str())])
+            )?;
+
+            self.api_client.sign_request(&mut auth_req)?;
+
+            let ws_notify_auth_msg_json = serde_json::to_string(
+                &WsNotifyChannelAuthentication {
+                    x_bcot_timestamp: auth_req.headers()
+                        .get(X_BCOT_TIMESTAMP)
+                        .unwrap_or(&HeaderValue::from_static(""))
+                        .to_str()?
+                        .into(),
+                    authorization: auth_req.headers()
+                        .get(AUTHORIZATION)
+                        .unwrap_or(&HeaderValue::from_static(""))
+                        .to_str()?
+                        .into()
+                }
+            )?;
+
+            // Create request to open WebSocket connection
+            let mut req = auth_req.url().as_str().into_client_request()?;
+
+            // Add HTTP header specifying the expected WebSocket subprotocol
+            req.headers_mut().insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static(NOTIFY_WS_PROTOCOL));
+
+            // Try to establish WebSocket connection
+            match connection::connect_ws(req, &self.api_client.connection_settings) {
+                Ok(ws) => {
+                    trace_event!(debug, url = %auth_req.url(), "WebSocket notification channel connection established");
+
+                    break (ws_notify_auth_msg_json, ws);
+                },
+                Err(err) => {
+                    if failovers + 1 < self.api_client.endpoints.len() && !err.is_pin_mismatch()
+                            && self.api_client.endpoints.fail_over(endpoint) {
+                        // Endpoint in use could not be reached. Try again with the next one
+                        failovers += 1;
+                    } else {
+                        return Err(err);
+                    }
+                }
             }
-        )?;
-
-        // Create request to open WebSocket connection
-        let mut req = auth_req.url().as_str().into_client_request()?;
-
-        // Add HTTP header specifying the expected WebSocket subprotocol
-        req.headers_mut().insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static(NOTIFY_WS_PROTOCOL));
-
-        // Try to establish WebSocket connection
-        let (mut ws, _) = tungstenite::connect(req)
-            .map_err(|err| Error::new_client_error(
-                Some("Failed to establish WebSocket connection"),
-                Some(err)
-            ))?;
+        };
 
         // Set read timeout for WebSocket connection
-        match ws.get_ref() {
-            MaybeTlsStream::Plain(stream) =>  stream,
-            MaybeTlsStream::NativeTls(tls_stream) => tls_stream.get_ref(),
-            &_ => panic!("Unexpected TLS stream type"),
-        }.set_read_timeout(Some(std::time::Duration::from_millis(500)))
+        tls::inner_stream(ws.get_ref())?.get_ref().set_read_timeout(Some(std::time::Duration::from_millis(500)))
             .map_err(|err| Error::new_client_error(
                 Some("Failed to set read timeout for WebSocket connection"),
                 Some(err)
@@ -232,9 +243,19 @@ impl WsNotifyChannel {
         let (tx, rx) = mpsc::sync_channel(128);
 
         // Save communication channel with WebSocket thread
-        self.tx = Some(tx);
+        let is_reopen = self.tx.replace(tx).is_some();
+
+        // Prepare to report metrics from WebSocket thread
+        //  Note: the closing of the notification channel is reported once the thread ends
+        let channel_metrics = NotifyChannelMetrics::new(self.api_client.metrics.clone(), self.event.clone());
+
+        #[cfg(feature = "tracing")]
+        let ws_span = tracing::info_span!("ws_notify_channel", event = %self.event.to_string());
 
         Ok(thread::spawn(move || {
+            #[cfg(feature = "tracing")]
+            let _span_guard = ws_span.enter();
+
             // Create notification event handler thread
             let (h_tx, h_rx) = mpsc::channel();
 
@@ -289,6 +310,8 @@ impl WsNotifyChannel {
                 return;
             }
 
+            trace_event!(debug, "WebSocket notification channel authentication message sent");
+
             loop {
                 // Receive data from WebSocket connection
                 match ws.read_message() {
@@ -297,6 +320,13 @@ impl WsNotifyChannel {
                             Message::Text(text) => {
                                 // A text message was received
                                 if text == NOTIFY_WS_CHANNEL_OPEN {
+                                    trace_event!(info, "WebSocket notification channel open");
+                                    channel_metrics.record(if is_reopen {
+                                        NotifyChannelActivity::Reopen
+                                    } else {
+                                        NotifyChannelActivity::Open
+                                    });
+
                                     // WebSocket notification channel open and ready to send
                                     //  notification. Send open message to notification event
                                     //  handler thread
@@ -309,6 +339,8 @@ impl WsNotifyChannel {
                                     // Parse received message
                                     match serde_json::from_str(text.as_str()) {
                                         Ok(notify_message) => {
+                                            channel_metrics.record(NotifyChannelActivity::Notification);
+
                                             // Send notify message to notification event handler
                                             //  thread
                                             h_tx.send(
@@ -318,6 +350,8 @@ impl WsNotifyChannel {
                                             ).unwrap_or(());
                                         },
                                         Err(_) => {
+                                            trace_event!(warn, code = 4000, "Closing WebSocket notification channel: unexpected notification message received");
+
                                             // Unexpected notification message. Force closing of
                                             //  WebSocket notification channel reporting error
                                             //  condition
@@ -354,6 +388,8 @@ impl WsNotifyChannel {
                                 }
                             },
                             Message::Binary(bin) => {
+                                trace_event!(warn, code = u16::from(CloseCode::Unsupported), "Closing WebSocket notification channel: unexpected binary message received");
+
                                 // A binary message was received. This is unexpected, so
                                 //  force closing of WebSocket notification channel reporting
                                 //  the error condition
@@ -388,6 +424,8 @@ impl WsNotifyChannel {
                             },
                             Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => (),
                             Message::Close(close_info) => {
+                                trace_event!(debug, code = ?close_info.as_ref().map(|frame| u16::from(frame.code)), "WebSocket notification channel close frame received");
+
                                 // WebSocket connection is being closed. Send close message
                                 //  to notification event handler thread...
                                 h_tx.send(
@@ -422,6 +460,8 @@ impl WsNotifyChannel {
                                 }
                             },
                             tungstenite::error::Error::ConnectionClosed => {
+                                trace_event!(debug, "WebSocket notification channel connection closed");
+
                                 // WebSocket connection has been closed. Indicate that
                                 //  thread should be exited
                                 exit = true;
@@ -435,6 +475,8 @@ impl WsNotifyChannel {
                         }
 
                         if let Some(err) = err_to_report {
+                            trace_event!(warn, error = %err, "WebSocket notification channel connection failed");
+
                             // Send error message to notification event
                             //  handler thread
                             h_tx.send(
@@ -467,6 +509,8 @@ impl WsNotifyChannel {
                                 return;
                             },
                             WsNotifyChannelCommand::Close => {
+                                trace_event!(debug, code = u16::from(CloseCode::Normal), "Closing WebSocket notification channel");
+
                                 // Close WebSocket connection
                                 if let Err(err) = ws.close(Some(CloseFrame {
                                     code: CloseCode::Normal,
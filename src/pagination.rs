@@ -0,0 +1,112 @@
+use std::vec;
+
+use crate::Result;
+
+/// Default number of entries requested per page by the iterator variants of the list methods.
+pub(crate) const DEFAULT_PAGE_SIZE: u16 = 500;
+
+/// Iterator over the entries returned by a paginated (list) API method, which fetches the pages
+/// lazily, as the entries are consumed.
+///
+/// The page fetching function receives the page size (`limit`) and the number of entries to skip
+/// (`skip`), and returns the entries of that page along with whether there are more entries yet
+/// to be returned (`has_more`). Iteration ends after the last page, or right after an error is
+/// returned.
+pub(crate) struct Paginator<T, F> {
+    fetch_page: F,
+    page_size: u16,
+    skip: usize,
+    entries: vec::IntoIter<T>,
+    done: bool,
+}
+
+impl<T, F> Paginator<T, F>
+    where
+        F: FnMut(u16, usize) -> Result<(Vec<T>, bool)>
+{
+    pub(crate) fn new(page_size: Option<u16>, fetch_page: F) -> Self {
+        Paginator {
+            fetch_page,
+            page_size: page_size.unwrap_or(DEFAULT_PAGE_SIZE),
+            skip: 0,
+            entries: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+}
+
+impl<T, F> Iterator for Paginator<T, F>
+    where
+        F: FnMut(u16, usize) -> Result<(Vec<T>, bool)>
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.entries.next() {
+                return Some(Ok(entry));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            match (self.fetch_page)(self.page_size, self.skip) {
+                Ok((entries, has_more)) => {
+                    self.skip += entries.len();
+                    // Note: an empty page also ends the iteration, so it cannot go on forever
+                    self.done = !has_more || entries.is_empty();
+                    self.entries = entries.into_iter();
+                }
+                Err(err) => {
+                    self.done = true;
+
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use crate::{
+        Error, error::GenericError,
+    };
+    use super::*;
+
+    #[test]
+    fn it_iterate_over_pages() {
+        let requested_pages = RefCell::new(Vec::new());
+        let mut paginator = Paginator::new(Some(2), |limit, skip| {
+            requested_pages.borrow_mut().push((limit, skip));
+
+            let entries: Vec<usize> = (skip..(skip + limit as usize).min(5)).collect();
+
+            Ok((entries, skip + (limit as usize) < 5))
+        });
+
+        // Make sure that pages are only fetched when needed
+        assert!(requested_pages.borrow().is_empty());
+        assert_eq!(paginator.next().unwrap().unwrap(), 0);
+        assert_eq!(requested_pages.borrow().len(), 1);
+
+        let entries: Vec<usize> = paginator.map(|entry| entry.unwrap()).collect();
+
+        assert_eq!(entries, vec![1, 2, 3, 4]);
+        assert_eq!(*requested_pages.borrow(), vec![(2, 0), (2, 2), (2, 4)]);
+    }
+
+    #[test]
+    fn it_stop_iteration_on_error() {
+        let mut paginator = Paginator::new(None, |limit, _| -> Result<(Vec<u16>, bool)> {
+            assert_eq!(limit, DEFAULT_PAGE_SIZE);
+
+            Err(Error::new_client_error(Some("Page fetch error"), None::<GenericError>))
+        });
+
+        assert!(paginator.next().unwrap().is_err(), "Returned entry from failed page fetch");
+        assert!(paginator.next().is_none(), "Iteration continued after error");
+    }
+}
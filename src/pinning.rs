@@ -0,0 +1,356 @@
+// Note: without the `rustls` feature, no pin set can ever be created, since pins are only checked
+//  by the `rustls` certificate verifier
+#![cfg_attr(not(feature = "rustls"), allow(dead_code))]
+
+use std::{
+    error::Error as StdError,
+    fmt::{
+        self,
+        Display, Formatter,
+    },
+    io,
+    sync::{
+        Arc,
+        atomic::{
+            AtomicBool, Ordering,
+        },
+    },
+};
+#[cfg(feature = "rustls")]
+use std::{
+    convert::TryFrom,
+    time::SystemTime,
+};
+#[cfg(feature = "rustls")]
+use bitcoin_hashes::{
+    Hash, sha256,
+};
+
+use crate::{
+    Error, Result,
+    error::GenericError,
+};
+
+const DER_SEQUENCE: u8 = 0x30;
+
+/// Signature algorithms accepted when validating the server's certificate chain up to a pinned
+/// certificate. Same as the ones accepted by `rustls`.
+#[cfg(feature = "rustls")]
+static SUPPORTED_SIG_ALGS: &[&webpki::SignatureAlgorithm] = &[
+    &webpki::ECDSA_P256_SHA256,
+    &webpki::ECDSA_P256_SHA384,
+    &webpki::ECDSA_P384_SHA256,
+    &webpki::ECDSA_P384_SHA384,
+    &webpki::ED25519,
+    &webpki::RSA_PSS_2048_8192_SHA256_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA384_LEGACY_KEY,
+    &webpki::RSA_PSS_2048_8192_SHA512_LEGACY_KEY,
+    &webpki::RSA_PKCS1_2048_8192_SHA256,
+    &webpki::RSA_PKCS1_2048_8192_SHA384,
+    &webpki::RSA_PKCS1_2048_8192_SHA512,
+    &webpki::RSA_PKCS1_3072_8192_SHA384,
+];
+
+/// Message of the error reported when the server's certificate chain does not match the pins.
+pub(crate) const PIN_MISMATCH_MESSAGE: &str = "Server certificate does not match any pinned public key";
+
+/// Error reported by the certificate verifier when the server's certificate chain does not match
+/// the pins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PinMismatch;
+
+impl Display for PinMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(PIN_MISMATCH_MESSAGE)
+    }
+}
+
+impl StdError for PinMismatch {}
+
+/// Outcome of the pin check performed by the certificate verifier of a single TLS connection.
+///
+/// > **Note**: the version of `rustls` used for WebSocket connections cannot carry a typed error
+/// > out of the certificate verifier, so a pin mismatch is recorded here instead.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PinCheck(Arc<AtomicBool>);
+
+impl PinCheck {
+    pub(crate) fn record_mismatch(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub(crate) fn is_mismatch(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Set of SHA-256 hashes of the DER encoded SubjectPublicKeyInfo (SPKI) of the certificates that
+/// the Catenis API server is allowed to present.
+#[derive(Debug, Clone)]
+pub(crate) struct PinSet(Vec<[u8; 32]>);
+
+impl PinSet {
+    /// Create a new pin set from base64 encoded SHA-256 SPKI hashes. Each pin may optionally be
+    /// prefixed with `sha256/` (or `sha256//`).
+    ///
+    /// > **Note**: this fails if the `rustls` feature is not enabled, so the TLS settings of a
+    /// > client using `native-tls` are never silently replaced.
+    pub(crate) fn from_pins(pins: &[&str]) -> Result<Self> {
+        if cfg!(not(feature = "rustls")) {
+            return Err(Error::new_client_error(
+                Some("Public key pinning not enabled: the `rustls` feature must be enabled"),
+                None::<GenericError>
+            ));
+        }
+
+        if pins.is_empty() {
+            return Err(Error::new_client_error(Some("Invalid public key pins: no pin specified"), None::<GenericError>));
+        }
+
+        let hashes = pins.iter()
+            .map(|pin| {
+                let b64 = pin.trim().trim_start_matches("sha256/").trim_start_matches('/');
+                let hash = base64::decode(b64)
+                    .map_err(|err| Error::new_client_error(Some(&format!("Invalid public key pin: {}", pin)), Some(err)))?;
+
+                if hash.len() != 32 {
+                    return Err(Error::new_client_error(Some(&format!("Invalid public key pin: {}", pin)), None::<GenericError>));
+                }
+
+                let mut bytes = [0_u8; 32];
+                bytes.copy_from_slice(&hash);
+
+                Ok(bytes)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PinSet(hashes))
+    }
+
+    /// Check whether the public key of the (DER encoded) end-entity certificate presented by the
+    /// server, or of any certificate that the chain from it to a trust anchor goes through
+    /// (including the trust anchor itself), matches any of the pins.
+    ///
+    /// The chain is built and validated by `webpki`, taking as trust anchors only the presented
+    /// intermediate certificates and the trust anchors whose public key is pinned, so unrelated
+    /// certificates sent along by the server are never taken into account.
+    #[cfg(feature = "rustls")]
+    pub(crate) fn check_chain(&self, end_entity: &[u8], intermediates: &[&[u8]], trust_anchors: &[webpki::TrustAnchor], now: SystemTime)
+            -> std::result::Result<(), PinMismatch> {
+        if self.is_pinned(&webpki::TrustAnchor::try_from_cert_der(end_entity).map_err(|_| PinMismatch)?) {
+            return Ok(());
+        }
+
+        let pinned_anchors: Vec<_> = intermediates.iter()
+            .filter_map(|der| webpki::TrustAnchor::try_from_cert_der(der).ok())
+            .chain(trust_anchors.iter().map(|ta| webpki::TrustAnchor {
+                subject: ta.subject,
+                spki: ta.spki,
+                name_constraints: ta.name_constraints,
+            }))
+            .filter(|ta| self.is_pinned(ta))
+            .collect();
+
+        if pinned_anchors.is_empty() {
+            return Err(PinMismatch);
+        }
+
+        let cert = webpki::EndEntityCert::try_from(end_entity).map_err(|_| PinMismatch)?;
+        let time = webpki::Time::try_from(now).map_err(|_| PinMismatch)?;
+
+        cert.verify_is_valid_tls_server_cert(
+            SUPPORTED_SIG_ALGS,
+            &webpki::TlsServerTrustAnchors(&pinned_anchors),
+            intermediates,
+            time,
+        )
+            .map_err(|_| PinMismatch)
+    }
+
+    /// Indicates whether the public key of a trust anchor (or of a certificate parsed as such)
+    /// matches any of the pins.
+    #[cfg(feature = "rustls")]
+    fn is_pinned(&self, trust_anchor: &webpki::TrustAnchor) -> bool {
+        self.0.contains(&spki_hash(trust_anchor.spki))
+    }
+}
+
+/// SHA-256 hash of a DER encoded SubjectPublicKeyInfo, given its contents (i.e. without its
+/// enclosing SEQUENCE header), as provided by `webpki`.
+#[cfg(feature = "rustls")]
+fn spki_hash(spki: &[u8]) -> [u8; 32] {
+    let mut encoded_spki = vec![DER_SEQUENCE];
+    let len = spki.len();
+
+    if len < 0x80 {
+        encoded_spki.push(len as u8);
+    } else {
+        let len_bytes: Vec<u8> = len.to_be_bytes().iter().copied().skip_while(|byte| *byte == 0).collect();
+
+        encoded_spki.push(0x80 | len_bytes.len() as u8);
+        encoded_spki.extend(len_bytes);
+    }
+
+    encoded_spki.extend_from_slice(spki);
+
+    sha256::Hash::hash(&encoded_spki).into_inner()
+}
+
+/// Indicates whether an error (reported while setting up an HTTP connection) has been caused by
+/// the server's certificate chain not matching the pins.
+pub(crate) fn is_pin_mismatch_cause(err: &(dyn StdError + 'static)) -> bool {
+    let mut next = Some(err);
+
+    while let Some(err) = next {
+        if err.is::<PinMismatch>() {
+            return true;
+        }
+
+        // Note: `rustls` does not expose the error returned by the certificate verifier as the
+        //  source of the invalid certificate error that wraps it
+        #[cfg(feature = "rustls")]
+        if let Some(reqwest_rustls::Error::InvalidCertificate(reqwest_rustls::CertificateError::Other(cause))) = err.downcast_ref() {
+            if cause.is::<PinMismatch>() {
+                return true;
+            }
+        }
+
+        // Note: the source of an I/O error does not include its custom (inner) error
+        next = match err.downcast_ref::<io::Error>().and_then(io::Error::get_ref) {
+            Some(inner) => Some(inner),
+            None => err.source(),
+        };
+    }
+
+    false
+}
+
+#[cfg(all(test, feature = "rustls"))]
+mod tests {
+    use super::*;
+
+    const SERVER_CERT_PIN: &str = "wkoiJEI3H1+FkhR2xwIdrQJbgRaMPty2Y+asZ3//BNs=";
+    const CA_CERT_PIN: &str = "WFXP2PS2PCUkPpP8EMsWdZwpzbROpAC0cO6zMn0ifT8=";
+
+    const OTHER_PIN: &str = "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=";
+
+    fn cert_der(pem: &[u8]) -> Vec<u8> {
+        let pem = std::str::from_utf8(pem).unwrap();
+        let b64: String = pem.lines()
+            .filter(|line| !line.starts_with("-----"))
+            .collect();
+
+        base64::decode(b64).unwrap()
+    }
+
+    fn server_cert_der() -> Vec<u8> {
+        cert_der(include_bytes!("test_helper/certs/server.pem"))
+    }
+
+    fn ca_cert_der() -> Vec<u8> {
+        cert_der(include_bytes!("test_helper/certs/ca.pem"))
+    }
+
+    #[test]
+    fn it_create_pin_set() {
+        let pin_set = PinSet::from_pins(&[SERVER_CERT_PIN, &(String::from("sha256//") + CA_CERT_PIN)]).unwrap();
+
+        assert_eq!(pin_set.0.len(), 2);
+    }
+
+    #[test]
+    fn it_fail_create_pin_set_invalid_pin() {
+        let result = PinSet::from_pins(&["AAEC"]);
+
+        assert!(result.is_err(), "Created pin set with invalid pin");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid public key pin: AAEC");
+
+        assert!(PinSet::from_pins(&[]).is_err(), "Created empty pin set");
+    }
+
+    #[test]
+    fn it_compute_spki_hash() {
+        let ca_cert = ca_cert_der();
+        let trust_anchor = webpki::TrustAnchor::try_from_cert_der(&ca_cert).unwrap();
+
+        assert_eq!(base64::encode(spki_hash(trust_anchor.spki)), CA_CERT_PIN);
+    }
+
+    #[test]
+    fn it_match_pinned_certificate() {
+        let cert = server_cert_der();
+        let pin_set = PinSet::from_pins(&[OTHER_PIN, SERVER_CERT_PIN]).unwrap();
+
+        assert!(pin_set.check_chain(&cert, &[], &[], SystemTime::now()).is_ok(), "Certificate does not match pins");
+    }
+
+    #[test]
+    fn it_match_pinned_intermediate_certificate() {
+        let cert = server_cert_der();
+        let ca_cert = ca_cert_der();
+        let pin_set = PinSet::from_pins(&[CA_CERT_PIN]).unwrap();
+
+        assert!(pin_set.check_chain(&cert, &[&ca_cert], &[], SystemTime::now()).is_ok(), "Certificate chain does not match pins");
+    }
+
+    #[test]
+    fn it_match_pinned_root_certificate() {
+        let cert = server_cert_der();
+        let ca_cert = ca_cert_der();
+        let pin_set = PinSet::from_pins(&[CA_CERT_PIN]).unwrap();
+        let trust_anchor = webpki::TrustAnchor::try_from_cert_der(&ca_cert).unwrap();
+
+        assert!(pin_set.check_chain(&cert, &[], &[trust_anchor], SystemTime::now()).is_ok(), "Trust anchor does not match pins");
+    }
+
+    #[test]
+    fn it_reject_unpinned_certificate() {
+        let cert = server_cert_der();
+        let ca_cert = ca_cert_der();
+        let pin_set = PinSet::from_pins(&[OTHER_PIN]).unwrap();
+        let trust_anchor = webpki::TrustAnchor::try_from_cert_der(&ca_cert).unwrap();
+
+        let result = pin_set.check_chain(&cert, &[&ca_cert], &[trust_anchor], SystemTime::now());
+
+        assert_eq!(result, Err(PinMismatch), "Accepted certificate not matching pins");
+        assert_eq!(PinMismatch.to_string(), "Server certificate does not match any pinned public key");
+    }
+
+    #[test]
+    fn it_ignore_unrelated_presented_certificate() {
+        // A pinned certificate that is not part of the chain of the end-entity certificate must not count
+        let ca_cert = ca_cert_der();
+        let server_cert = server_cert_der();
+        let pin_set = PinSet::from_pins(&[SERVER_CERT_PIN]).unwrap();
+
+        let result = pin_set.check_chain(&ca_cert, &[&server_cert], &[], SystemTime::now());
+
+        assert_eq!(result, Err(PinMismatch), "Accepted certificate chain not matching pins");
+    }
+
+    #[test]
+    fn it_record_pin_check() {
+        let pin_check = PinCheck::default();
+
+        assert!(!pin_check.is_mismatch());
+
+        pin_check.clone().record_mismatch();
+
+        assert!(pin_check.is_mismatch());
+    }
+
+    #[test]
+    fn it_detect_pin_mismatch_cause() {
+        let rustls_err = reqwest_rustls::Error::InvalidCertificate(reqwest_rustls::CertificateError::Other(Arc::new(PinMismatch)));
+        let wrapped_err = io::Error::other(io::Error::new(io::ErrorKind::InvalidData, rustls_err));
+
+        assert!(is_pin_mismatch_cause(&wrapped_err));
+        assert!(is_pin_mismatch_cause(&io::Error::other(PinMismatch)));
+
+        // An error that merely mentions a pin mismatch is not caused by one
+        assert!(!is_pin_mismatch_cause(&io::Error::other(PIN_MISMATCH_MESSAGE)));
+        assert!(!is_pin_mismatch_cause(&io::Error::other(reqwest_rustls::Error::InvalidCertificate(
+            reqwest_rustls::CertificateError::BadSignature
+        ))));
+    }
+}
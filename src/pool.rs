@@ -0,0 +1,239 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+};
+
+use crate::{
+    CatenisClient, ClientOptions, DeviceCredentials, Result,
+};
+
+/// Pool of Catenis API clients for many virtual devices, all sharing the same HTTP client and
+/// configuration.
+///
+/// Each virtual device in the pool is accessed through a per-device client, obtained via
+/// [`as_device()`](DeviceClientPool::as_device), that keeps its own signing key cached. The
+/// underlying HTTP client (and thus its connection pool), the option settings, the rate
+/// limiter, the circuit breaker, the interceptors and the metrics sink are shared by all of them.
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     ClientOptions, DeviceClientPool, Environment, Result,
+///     api::*,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let mut pool = DeviceClientPool::new(&[
+///     ClientOptions::Environment(Environment::Sandbox),
+/// ])?;
+///
+/// pool.add_device((
+///     "drc3XdxNtzoucpw9xiRp",
+///     concat!(
+///         "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///         "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///     ),
+/// ).into());
+/// pool.add_device((
+///     "d8YpQ7jgPBJEkBrnvp58",
+///     concat!(
+///         "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d6",
+///         "2d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4"
+///     ),
+/// ).into());
+///
+/// if let Some(ctn_client) = pool.as_device("d8YpQ7jgPBJEkBrnvp58") {
+///     let result = ctn_client.log_message(
+///         Message::Whole(String::from("My message")),
+///         None,
+///     )?;
+///
+///     println!("ID of logged message: {}", result.message_id.unwrap());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeviceClientPool {
+    template: CatenisClient,
+    devices: HashMap<String, CatenisClient>,
+}
+
+impl DeviceClientPool {
+    /// Instantiate a new empty pool whose clients use the given option settings.
+    pub fn new<'a, I>(opts: I) -> Result<Self>
+        where
+            I: IntoIterator,
+            <I as IntoIterator>::Item: Borrow<ClientOptions<'a>>
+    {
+        Ok(Self::from_client(CatenisClient::new_with_options(None, opts)?))
+    }
+
+    /// Instantiate a new empty pool whose clients share everything with the given client, except
+    /// its virtual device credentials.
+    ///
+    /// Use this to have interceptors or a metrics sink registered for all the clients in the pool.
+    pub fn from_client(client: CatenisClient) -> Self {
+        DeviceClientPool {
+            template: client,
+            devices: HashMap::new(),
+        }
+    }
+
+    /// Add a virtual device to the pool.
+    ///
+    /// If the pool already holds a virtual device with the same ID, its credentials are
+    /// replaced.
+    pub fn add_device(&mut self, device_credentials: DeviceCredentials) {
+        let device_id = device_credentials.device_id.clone();
+
+        self.devices.insert(device_id, self.template.for_device(device_credentials));
+    }
+
+    /// Remove a virtual device from the pool, returning whether it was in the pool.
+    pub fn remove_device(&mut self, device_id: &str) -> bool {
+        self.devices.remove(device_id).is_some()
+    }
+
+    /// Indicates whether the pool holds a given virtual device.
+    pub fn contains_device(&self, device_id: &str) -> bool {
+        self.devices.contains_key(device_id)
+    }
+
+    /// The IDs of the virtual devices in the pool, in arbitrary order.
+    pub fn device_ids(&self) -> impl Iterator<Item = &str> {
+        self.devices.keys().map(String::as_str)
+    }
+
+    /// Number of virtual devices in the pool.
+    pub fn len(&self) -> usize {
+        self.devices.len()
+    }
+
+    /// Indicates whether the pool holds no virtual devices.
+    pub fn is_empty(&self) -> bool {
+        self.devices.is_empty()
+    }
+
+    /// Retrieve the client for a given virtual device, or `None` if that device is not in the
+    /// pool.
+    pub fn as_device(&self, device_id: &str) -> Option<&CatenisClient> {
+        self.devices.get(device_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ApiVersion, RequestSigner,
+        api::*,
+        test_helper::{
+            self,
+            http_server::{
+                HttpServer, HttpServerMode, HttpBody, PartialHttpRequest, HttpHeader,
+            },
+        },
+    };
+    use super::*;
+
+    const DEVICE1: (&str, &str) = (
+        "drc3XdxNtzoucpw9xiRp",
+        "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+    );
+    const DEVICE2: (&str, &str) = (
+        "d8YpQ7jgPBJEkBrnvp58",
+        "267a687115b9752f2eec5be849b570b29133528f928868d811bad5e48e97a1d62d432bab44803586b2ac35002ec6f0eeaa98bec79b64f2f69b9cb0935b4df2c4",
+    );
+
+    #[test]
+    fn it_manage_pool_devices() {
+        let mut pool = DeviceClientPool::new(&[ClientOptions::Version(ApiVersion(0, 10))]).unwrap();
+
+        assert!(pool.is_empty());
+
+        pool.add_device(DEVICE1.into());
+        pool.add_device(DEVICE2.into());
+        pool.add_device(DEVICE2.into());
+
+        assert_eq!(pool.len(), 2);
+        assert!(pool.contains_device(DEVICE1.0));
+
+        let mut device_ids: Vec<&str> = pool.device_ids().collect();
+        device_ids.sort_unstable();
+
+        assert_eq!(device_ids, vec![DEVICE2.0, DEVICE1.0]);
+        assert!(pool.as_device("d8YpQ7jgPBJEkBrnvp58").is_some());
+        assert!(pool.as_device("unknown").is_none(), "Returned client for unknown device");
+
+        assert!(pool.remove_device(DEVICE1.0));
+        assert!(!pool.remove_device(DEVICE1.0), "Removed device not in pool");
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn it_sign_request_as_pool_device() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let body = r#"{"message":"Test message","options":{"encoding":"utf8"}}"#;
+        let path = "/api/0.10/messages/log";
+        let res_body = r#"{
+  "status": "success",
+  "data": {
+    "messageId": "oX2mJHwFWp752beHbNDK"
+  }
+}"#;
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(res_body).unwrap(),
+            ),
+            "localhost"
+        );
+        let host = format!("localhost:{}", http_server.get_port());
+
+        // Expect request to be signed with the second device's credentials
+        let signature = RequestSigner::new(DEVICE2.into()).sign("POST", path, &host, body.as_bytes()).unwrap();
+
+        let http_server = http_server.with_expected_request(PartialHttpRequest {
+            method: Some(String::from("POST")),
+            path: Some(String::from(path)),
+            headers: Some(
+                vec![
+                    (String::from("x-bcot-timestamp"), Some(HttpHeader {
+                        field: String::from("x-bcot-timestamp"),
+                        value: signature.timestamp,
+                    })),
+                    (String::from("authorization"), Some(HttpHeader {
+                        field: String::from("authorization"),
+                        value: signature.authorization,
+                    })),
+                ].into_iter().collect()
+            ),
+            body: Some(String::from(body)),
+        });
+        http_server.start();
+
+        let mut pool = DeviceClientPool::new(&[
+            ClientOptions::Host(&host),
+            ClientOptions::Secure(false),
+            ClientOptions::Version(ApiVersion(0, 10)),
+        ]).unwrap();
+
+        pool.add_device(DEVICE1.into());
+        pool.add_device(DEVICE2.into());
+
+        let result = pool.as_device(DEVICE2.0).unwrap().log_message(
+            Message::Whole(String::from("Test message")),
+            Some(LogMessageOptions {
+                encoding: Some(Encoding::UTF8),
+                encrypt: None,
+                off_chain: None,
+                storage: None,
+                async_: None,
+            }),
+        ).unwrap();
+
+        assert_eq!(result.message_id, Some(String::from("oX2mJHwFWp752beHbNDK")));
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    sync::{
+        Arc, Mutex, PoisonError,
+    },
+    time::{
+        Duration, Instant,
+    },
+};
+use serde::Deserialize;
+
+use crate::{
+    Error, Result,
+    error::GenericError,
+};
+
+/// Limit on the rate at which requests are sent to the Catenis API server.
+///
+/// Requests are throttled on the client side using a token bucket: up to `burst` requests can be
+/// sent at once, and the bucket is then refilled at `requests_per_second`. The limit is shared by
+/// all clones of a client, so it also applies to requests made concurrently from several threads
+/// or tasks.
+///
+/// When the server responds with a `429 Too Many Requests` HTTP status code, the rate is
+/// automatically halved (down to a sixteenth of the configured rate), and no further requests are
+/// sent until the delay requested via the `Retry-After` HTTP header, if any, has elapsed. The rate
+/// then grows back to the configured value as requests succeed.
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     CatenisClient, ClientOptions, RateLimit, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let ctn_client = CatenisClient::new_with_options(
+///     Some((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into()),
+///     &[
+///         ClientOptions::RateLimit(RateLimit {
+///             requests_per_second: 10.0,
+///             burst: 20,
+///         }),
+///     ],
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimit {
+    /// Sustained number of requests that can be sent per second.
+    pub requests_per_second: f64,
+    /// Maximum number of requests that can be sent at once.
+    pub burst: u32,
+}
+
+/// Fraction of the configured rate below which the rate is not reduced any further.
+const MIN_RATE_FACTOR: f64 = 1.0 / 16.0;
+/// Fraction of the configured rate by which the rate grows back for each successful request.
+const RECOVERY_RATE_FACTOR: f64 = 1.0 / 10.0;
+
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    max_rate: f64,
+    rate: f64,
+    /// Available tokens. It becomes negative when tokens have been reserved ahead of time.
+    tokens: f64,
+    last_refill: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl TokenBucket {
+    fn new(limit: &RateLimit, now: Instant) -> Self {
+        let capacity = f64::from(limit.burst);
+
+        TokenBucket {
+            capacity,
+            max_rate: limit.requests_per_second,
+            rate: limit.requests_per_second,
+            tokens: capacity,
+            last_refill: now,
+            paused_until: None,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Take a token, returning how long to wait before the request can be sent.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+        self.tokens -= 1.0;
+
+        let wait = if self.tokens < 0.0 {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        } else {
+            Duration::from_secs(0)
+        };
+
+        match self.paused_until {
+            Some(until) if until > now => wait.max(until - now),
+            _ => wait,
+        }
+    }
+
+    fn throttle(&mut self, retry_after: Option<Duration>, now: Instant) {
+        self.refill(now);
+
+        self.rate = (self.rate / 2.0).max(self.max_rate * MIN_RATE_FACTOR);
+        self.tokens = self.tokens.min(0.0);
+
+        let pause = retry_after.unwrap_or_else(|| Duration::from_secs_f64(1.0 / self.rate));
+        self.paused_until = Some(self.paused_until.map_or(now + pause, |until| until.max(now + pause)));
+    }
+
+    fn recover(&mut self, now: Instant) {
+        if self.rate < self.max_rate {
+            self.refill(now);
+            self.rate = (self.rate + self.max_rate * RECOVERY_RATE_FACTOR).min(self.max_rate);
+        }
+    }
+}
+
+/// Optional rate limiter of a client, shared by all its clones.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimiter(Option<Arc<Mutex<TokenBucket>>>);
+
+impl RateLimiter {
+    pub(crate) fn new(limit: &RateLimit) -> Result<Self> {
+        if !(limit.requests_per_second > 0.0 && limit.requests_per_second.is_finite()) || limit.burst == 0 {
+            return Err(Error::new_client_error(Some("Invalid rate limit"), None::<GenericError>));
+        }
+
+        Ok(RateLimiter(Some(Arc::new(Mutex::new(TokenBucket::new(limit, Instant::now()))))))
+    }
+
+    /// Reserve the right to send a request, returning how long to wait before sending it, if
+    /// needed.
+    pub(crate) fn reserve(&self) -> Option<Duration> {
+        let wait = self.with_bucket(|bucket| bucket.reserve(Instant::now()))?;
+
+        if wait > Duration::from_secs(0) {
+            Some(wait)
+        } else {
+            None
+        }
+    }
+
+    /// Slow down after the server has reported that too many requests are being sent.
+    pub(crate) fn throttle(&self, retry_after: Option<Duration>) {
+        self.with_bucket(|bucket| bucket.throttle(retry_after, Instant::now()));
+    }
+
+    /// Speed up (back to the configured rate) after a request has succeeded.
+    pub(crate) fn recover(&self) {
+        self.with_bucket(|bucket| bucket.recover(Instant::now()));
+    }
+
+    fn with_bucket<T, F: FnOnce(&mut TokenBucket) -> T>(&self, f: F) -> Option<T> {
+        self.0.as_ref()
+            .map(|bucket| f(&mut bucket.lock().unwrap_or_else(PoisonError::into_inner)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit() -> RateLimit {
+        RateLimit {
+            requests_per_second: 2.0,
+            burst: 2,
+        }
+    }
+
+    #[test]
+    fn it_allow_burst() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(&rate_limit(), now);
+
+        assert_eq!(bucket.reserve(now), Duration::from_secs(0));
+        assert_eq!(bucket.reserve(now), Duration::from_secs(0));
+        assert_eq!(bucket.reserve(now), Duration::from_millis(500));
+        assert_eq!(bucket.reserve(now), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn it_refill_bucket() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(&rate_limit(), now);
+
+        bucket.reserve(now);
+        bucket.reserve(now);
+
+        assert_eq!(bucket.reserve(now + Duration::from_millis(500)), Duration::from_secs(0));
+
+        // Bucket does not fill up beyond its capacity
+        let later = now + Duration::from_secs(60);
+
+        assert_eq!(bucket.reserve(later), Duration::from_secs(0));
+        assert_eq!(bucket.reserve(later), Duration::from_secs(0));
+        assert_eq!(bucket.reserve(later), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn it_throttle_and_recover() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(&rate_limit(), now);
+
+        bucket.throttle(Some(Duration::from_secs(3)), now);
+
+        assert_eq!(bucket.rate, 1.0);
+        assert_eq!(bucket.reserve(now), Duration::from_secs(3));
+
+        // Rate does not go below its minimum
+        for _ in 0..10 {
+            bucket.throttle(None, now);
+        }
+
+        assert_eq!(bucket.rate, 2.0 * MIN_RATE_FACTOR);
+
+        for _ in 0..20 {
+            bucket.recover(now);
+        }
+
+        assert_eq!(bucket.rate, 2.0);
+    }
+
+    #[test]
+    fn it_share_rate_limiter_among_clones() {
+        let limiter = RateLimiter::new(&RateLimit {
+            requests_per_second: 1.0,
+            burst: 1,
+        }).unwrap();
+        let limiter_clone = limiter.clone();
+
+        assert_eq!(limiter.reserve(), None);
+        assert!(limiter_clone.reserve().is_some(), "Rate limiter not shared among clones");
+    }
+
+    #[test]
+    fn it_reject_invalid_rate_limit() {
+        let result = RateLimiter::new(&RateLimit {
+            requests_per_second: 0.0,
+            burst: 1,
+        });
+
+        assert!(result.is_err(), "Accepted invalid rate limit");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Invalid rate limit");
+
+        assert!(RateLimiter::new(&RateLimit { requests_per_second: 1.0, burst: 0 }).is_err());
+        assert!(RateLimiter::default().reserve().is_none());
+    }
+}
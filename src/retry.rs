@@ -0,0 +1,342 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{
+        BuildHasher, Hasher,
+    },
+    time::{
+        Duration, SystemTime, UNIX_EPOCH,
+    },
+};
+use reqwest::{
+    header::{
+        HeaderMap, RETRY_AFTER,
+    },
+    Method, StatusCode,
+};
+use time::{
+    PrimitiveDateTime,
+};
+use serde::Deserialize;
+
+use crate::config;
+#[cfg(test)]
+use crate::test_helper;
+
+/// Outcome of a single attempt to send an HTTP request.
+pub(crate) enum SendAttempt<R> {
+    /// The request succeeded.
+    Done(R),
+    /// The request failed and should be retried after the given delay.
+    Retry(Duration),
+    /// The request was rejected due to clock skew, and should be signed anew and sent again
+    /// right away.
+    Resign,
+    /// The request could not reach the endpoint in use, and should be sent again right away to
+    /// the next endpoint.
+    Failover,
+}
+
+/// Policy used for automatically retrying failed Catenis API requests.
+///
+/// A request is retried when it fails to connect to the Catenis API server, or when the server
+/// responds with either a `429 Too Many Requests` or a `5xx` HTTP status code. The delay between
+/// attempts grows exponentially, and it is replaced by the value of the `Retry-After` HTTP header
+/// if one is returned.
+///
+/// Non-idempotent (`POST`) requests, like the ones that log or send messages, or issue or
+/// transfer assets, are only retried on HTTP error responses when the server explicitly asks for
+/// it — that is, on a `429 Too Many Requests` or `503 Service Unavailable` HTTP status code with a
+/// `Retry-After` HTTP header —, since the server may have already carried out the operation.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use catenis_api_client::{
+///     CatenisClient, ClientOptions, RetryPolicy, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let ctn_client = CatenisClient::new_with_options(
+///     Some((
+///         "drc3XdxNtzoucpw9xiRp",
+///         concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         ),
+///     ).into()),
+///     &[
+///         ClientOptions::Retry(RetryPolicy {
+///             max_attempts: 5,
+///             initial_backoff: Duration::from_secs(1),
+///             ..RetryPolicy::default()
+///         }),
+///     ],
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts (including the first one) to send a request. A value of `1`
+    /// disables retries. Default value: **`3`**.
+    pub max_attempts: u32,
+    /// Delay before the first retry. Default value: **`500 ms`**.
+    #[serde(rename = "initial_backoff_ms", deserialize_with = "config::deserialize_millis")]
+    pub initial_backoff: Duration,
+    /// Upper limit for the delay between attempts. Default value: **`30 s`**.
+    ///
+    /// > **Note**: if the `Retry-After` HTTP header returned by the server requests a longer
+    /// > delay, the request is not retried.
+    #[serde(rename = "max_backoff_ms", deserialize_with = "config::deserialize_millis")]
+    pub max_backoff: Duration,
+    /// Indicates whether a random jitter should be applied to the delay between attempts.
+    /// Default value: **`true`**.
+    pub jitter: bool,
+    /// Indicates whether non-idempotent (`POST`) requests should be retried — and failed over
+    /// to an alternative endpoint — on any retryable HTTP error response, at the risk of the same
+    /// operation being carried out twice. Default value: **`false`**.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that does not retry any request.
+    pub fn no_retry() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Delay before retrying a request that failed with an HTTP error response, or `None` if it
+    /// should not be retried.
+    pub(crate) fn delay_for_response(&self, attempt: u32, method: &Method, status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+        if attempt >= self.max_attempts || !Self::is_retryable_status(status) || !self.may_resend(method, status, headers) {
+            return None;
+        }
+
+        if let Some(retry_after) = Self::parse_retry_after(headers) {
+            if retry_after > self.max_backoff {
+                // Server asks us to wait longer than allowed. Give up
+                None
+            } else {
+                Some(retry_after)
+            }
+        } else {
+            Some(self.backoff(attempt))
+        }
+    }
+
+    /// Delay before retrying a request that failed to be sent, or `None` if it should not be
+    /// retried.
+    pub(crate) fn delay_for_error(&self, attempt: u32, err: &reqwest::Error) -> Option<Duration> {
+        if attempt < self.max_attempts && err.is_connect() {
+            Some(self.backoff(attempt))
+        } else {
+            None
+        }
+    }
+
+    /// Indicates whether a request that got an HTTP error response may be sent again, either to
+    /// the same or to an alternative endpoint.
+    pub(crate) fn may_resend(&self, method: &Method, status: StatusCode, headers: &HeaderMap) -> bool {
+        method.is_idempotent() || self.retry_non_idempotent
+            || (matches!(status, StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+                && headers.contains_key(RETRY_AFTER))
+    }
+
+    fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(31);
+        let backoff = self.initial_backoff
+            .checked_mul(1_u32 << exp)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            // Use "equal jitter": half of the delay is fixed, the other half is random
+            let half = backoff / 2;
+
+            half + Duration::from_nanos(random_u64() % (half.as_nanos() as u64 + 1))
+        } else {
+            backoff
+        }
+    }
+
+    pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+        let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+        if let Ok(secs) = value.parse::<u64>() {
+            Some(Duration::from_secs(secs))
+        } else if let Ok(date) = PrimitiveDateTime::parse(value, "%a, %d %b %Y %H:%M:%S GMT") {
+            let delay = date.assume_utc() - now!();
+
+            if delay.is_positive() {
+                Some(Duration::from_secs(delay.whole_seconds() as u64))
+            } else {
+                Some(Duration::from_secs(0))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos());
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use super::*;
+
+    #[test]
+    fn it_create_default_retry_policy() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(policy, RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            retry_non_idempotent: false,
+        });
+    }
+
+    #[test]
+    fn it_not_retry_with_no_retry_policy() {
+        let policy = RetryPolicy::no_retry();
+
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn it_compute_exponential_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1000),
+            jitter: false,
+            retry_non_idempotent: false,
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(40), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn it_compute_backoff_with_jitter() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1000),
+            jitter: true,
+            retry_non_idempotent: false,
+        };
+
+        for _ in 0..20 {
+            let backoff = policy.backoff(3);
+
+            assert!(backoff >= Duration::from_millis(200) && backoff <= Duration::from_millis(400), "Backoff out of expected range: {:?}", backoff);
+        }
+    }
+
+    #[test]
+    fn it_retry_only_retryable_status() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..RetryPolicy::default()
+        };
+
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &HeaderMap::new()), Some(Duration::from_millis(500)));
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new()), Some(Duration::from_millis(500)));
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::BAD_REQUEST, &HeaderMap::new()), None);
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::UNAUTHORIZED, &HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn it_retry_non_idempotent_only_when_asked_by_server() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..RetryPolicy::default()
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("2"));
+
+        assert_eq!(policy.delay_for_response(1, &Method::POST, StatusCode::INTERNAL_SERVER_ERROR, &HeaderMap::new()), None);
+        assert_eq!(policy.delay_for_response(1, &Method::POST, StatusCode::SERVICE_UNAVAILABLE, &HeaderMap::new()), None);
+        assert_eq!(policy.delay_for_response(1, &Method::POST, StatusCode::INTERNAL_SERVER_ERROR, &headers), None);
+        assert_eq!(policy.delay_for_response(1, &Method::POST, StatusCode::SERVICE_UNAVAILABLE, &headers), Some(Duration::from_secs(2)));
+        assert_eq!(policy.delay_for_response(1, &Method::POST, StatusCode::TOO_MANY_REQUESTS, &headers), Some(Duration::from_secs(2)));
+
+        let policy = RetryPolicy {
+            jitter: false,
+            retry_non_idempotent: true,
+            ..RetryPolicy::default()
+        };
+
+        assert_eq!(policy.delay_for_response(1, &Method::POST, StatusCode::INTERNAL_SERVER_ERROR, &HeaderMap::new()), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn it_stop_retrying_after_max_attempts() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.delay_for_response(2, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &HeaderMap::new()).is_some());
+        assert_eq!(policy.delay_for_response(3, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn it_respect_retry_after_seconds() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::TOO_MANY_REQUESTS, &headers), Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn it_respect_retry_after_date() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("Tue, 01 Dec 2020 06:00:12 GMT"));
+
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &headers), Some(Duration::from_secs(12)));
+    }
+
+    #[test]
+    fn it_give_up_on_too_long_retry_after() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3600"));
+
+        assert_eq!(policy.delay_for_response(1, &Method::GET, StatusCode::SERVICE_UNAVAILABLE, &headers), None);
+    }
+}
@@ -0,0 +1,222 @@
+use reqwest::header::{
+    AUTHORIZATION, HOST,
+};
+use time::Date;
+
+use crate::{
+    DeviceCredentials, Error, Result, X_BCOT_TIMESTAMP,
+    base_client::{
+        SignRequest, SigningKey,
+    },
+    error::GenericError,
+};
+#[cfg(test)]
+use crate::test_helper;
+
+/// Signer of HTTP requests to the Catenis API using the `CTN1-HMAC-SHA256` scheme.
+///
+/// This is the same signing algorithm used by [`CatenisClient`](crate::CatenisClient), made
+/// available for HTTP requests that are not sent through this library, like the ones issued with
+/// [hyper](https://crates.io/crates/hyper) or any other HTTP client.
+///
+/// The signing key, which is derived from the device's API access secret, is cached and reused
+/// for as long as it is valid (7 days).
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     RequestSigner, Result,
+/// };
+///
+/// # fn main() -> Result<()> {
+/// let mut signer = RequestSigner::new((
+///     "drc3XdxNtzoucpw9xiRp",
+///     concat!(
+///         "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///         "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///     ),
+/// ).into());
+///
+/// let mut req = http::Request::post("https://catenis.io/api/0.12/messages/log")
+///     .header("content-type", "application/json; charset=utf-8")
+///     .body(bytes::Bytes::from_static(br#"{"message":"My message"}"#))
+///     .unwrap();
+///
+/// // Adds the 'host', 'x-bcot-timestamp' and 'authorization' HTTP headers
+/// signer.sign_http_request(&mut req)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestSigner {
+    device_credentials: DeviceCredentials,
+    sign_date: Option<Date>,
+    signing_key: Option<SigningKey>,
+}
+
+/// Values of the HTTP headers that should be added to a signed HTTP request.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignatureHeaders {
+    /// Value of the `x-bcot-timestamp` HTTP header.
+    pub timestamp: String,
+    /// Value of the `authorization` HTTP header.
+    pub authorization: String,
+}
+
+impl SignRequest for RequestSigner {
+    fn get_device_id_ref(&self) -> Result<&String> {
+        Ok(&self.device_credentials.device_id)
+    }
+
+    fn get_api_access_secret_ref(&self) -> Result<&str> {
+        Ok(self.device_credentials.api_access_secret.expose_secret())
+    }
+
+    fn get_sign_date_ref(&self) -> &Option<Date> {
+        &self.sign_date
+    }
+
+    fn get_sign_date_mut_ref(&mut self) -> &mut Option<Date> {
+        &mut self.sign_date
+    }
+
+    fn get_signing_key_ref(&self) -> &Option<SigningKey> {
+        &self.signing_key
+    }
+
+    fn get_signing_key_mut_ref(&mut self) -> &mut Option<SigningKey> {
+        &mut self.signing_key
+    }
+}
+
+impl RequestSigner {
+    /// Instantiate a new request signer for a given virtual device.
+    pub fn new(device_credentials: DeviceCredentials) -> Self {
+        RequestSigner {
+            device_credentials,
+            sign_date: None,
+            signing_key: None,
+        }
+    }
+
+    /// Sign an HTTP request given its constituent parts.
+    ///
+    /// The `host` should include the port, if not the default one (e.g. `"localhost:3000"`), as
+    /// it is sent in the `host` HTTP header.
+    pub fn sign(&mut self, method: &str, path_with_query: &str, host: &str, body: &[u8]) -> Result<SignatureHeaders> {
+        let (timestamp, authorization) = self.sign_request_parts(method, path_with_query, host, body, &now!())?;
+
+        Ok(SignatureHeaders {
+            timestamp,
+            authorization,
+        })
+    }
+
+    /// Sign an [`http::Request`], adding the required HTTP headers to it.
+    ///
+    /// If the request has no `host` HTTP header, one is added with the host (and port) of its URI.
+    pub fn sign_http_request<B: AsRef<[u8]>>(&mut self, req: &mut http::Request<B>) -> Result<()> {
+        if !req.headers().contains_key(HOST) {
+            // Add missing 'host' header to HTTP request
+            let host = match (req.uri().host(), req.uri().port_u16()) {
+                (Some(host), Some(port)) => format!("{}:{}", host, port),
+                (Some(host), None) => String::from(host),
+                (None, _) => {
+                    return Err(Error::new_client_error(Some("Inconsistent HTTP request: URI missing host"), None::<GenericError>));
+                }
+            };
+
+            req.headers_mut().insert(HOST, host.parse()?);
+        }
+
+        let host = String::from(req.headers().get(HOST).unwrap().to_str()?);
+        let method = req.method().to_string();
+        let path_with_query = String::from(req.uri().path_and_query().map_or("/", |path| path.as_str()));
+
+        let headers = self.sign(&method, &path_with_query, &host, req.body().as_ref())?;
+
+        req.headers_mut().insert(X_BCOT_TIMESTAMP, headers.timestamp.parse()?);
+        req.headers_mut().insert(AUTHORIZATION, headers.authorization.parse()?);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use super::*;
+
+    fn signer() -> RequestSigner {
+        RequestSigner::new((
+            "drc3XdxNtzoucpw9xiRp",
+            "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+        ).into())
+    }
+
+    #[test]
+    fn it_sign_request_parts() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let headers = signer().sign(
+            "POST",
+            "/api/0.10/messages/log",
+            "localhost:3000",
+            br#"{"message":"Test message","options":{"encoding":"utf8"}}"#,
+        ).unwrap();
+
+        assert_eq!(headers, SignatureHeaders {
+            timestamp: String::from("20201201T060000Z"),
+            authorization: String::from("CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4"),
+        });
+    }
+
+    #[test]
+    fn it_sign_http_request() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let mut req = http::Request::post("http://localhost:3000/api/0.10/messages/log")
+            .body(Bytes::from_static(br#"{"message":"Test message","options":{"encoding":"utf8"}}"#))
+            .unwrap();
+
+        signer().sign_http_request(&mut req).unwrap();
+
+        assert_eq!(req.headers().get(HOST).unwrap(), "localhost:3000");
+        assert_eq!(req.headers().get(X_BCOT_TIMESTAMP).unwrap(), "20201201T060000Z");
+        assert_eq!(req.headers().get(AUTHORIZATION).unwrap(), "CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4");
+    }
+
+    #[test]
+    fn it_reuse_signing_key() {
+        let mut signer = signer();
+
+        {
+            let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+            signer.sign("GET", "/api/0.10/messages/oNXszDazhuq4utktSMMi", "localhost:3000", b"").unwrap();
+        }
+
+        let signing_key = signer.signing_key.clone();
+
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-07).with_time(time::time!(06:00:00)).assume_utc());
+        let headers = signer.sign("GET", "/api/0.10/messages/oNXszDazhuq4utktSMMi", "localhost:3000", b"").unwrap();
+
+        assert_eq!(signer.signing_key, signing_key, "Signing key unexpectedly renewed");
+        assert!(headers.authorization.contains("/20201201/ctn1_request,"), "Unexpected signature scope: {}", headers.authorization);
+    }
+
+    #[test]
+    fn it_fail_sign_http_request_missing_host() {
+        let mut req = http::Request::get("/api/0.10/messages/oNXszDazhuq4utktSMMi")
+            .body(Bytes::new())
+            .unwrap();
+
+        let result = signer().sign_http_request(&mut req);
+
+        assert!(result.is_err(), "Signed HTTP request without host");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Inconsistent HTTP request: URI missing host");
+    }
+}
@@ -0,0 +1,228 @@
+use std::sync::{
+    Arc, PoisonError, RwLock,
+};
+use time::{
+    Date, OffsetDateTime,
+};
+
+use crate::{
+    DeviceCredentials, Error, Result,
+    base_client::{
+        SignRequest, SigningKey,
+    },
+    credentials::SharedCredentials,
+    error::GenericError,
+};
+
+#[derive(Debug, Default)]
+struct SigningCache {
+    device_credentials: Option<DeviceCredentials>,
+    /// Generation of the shared credentials that `device_credentials` corresponds to.
+    credentials_generation: u64,
+    sign_date: Option<Date>,
+    signing_key: Option<SigningKey>,
+}
+
+impl SignRequest for SigningCache {
+    fn get_device_id_ref(&self) -> Result<&String> {
+        if let Some(credentials) = &self.device_credentials {
+            Ok(&credentials.device_id)
+        } else {
+            Err(Error::new_client_error(Some("Missing virtual device credentials"), None::<GenericError>))
+        }
+    }
+
+    fn get_api_access_secret_ref(&self) -> Result<&str> {
+        if let Some(credentials) = &self.device_credentials {
+            Ok(credentials.api_access_secret.expose_secret())
+        } else {
+            Err(Error::new_client_error(Some("Missing virtual device credentials"), None::<GenericError>))
+        }
+    }
+
+    fn get_sign_date_ref(&self) -> &Option<Date> {
+        &self.sign_date
+    }
+
+    fn get_sign_date_mut_ref(&mut self) -> &mut Option<Date> {
+        &mut self.sign_date
+    }
+
+    fn get_signing_key_ref(&self) -> &Option<SigningKey> {
+        &self.signing_key
+    }
+
+    fn get_signing_key_mut_ref(&mut self) -> &mut Option<SigningKey> {
+        &mut self.signing_key
+    }
+}
+
+/// Virtual device credentials of a client, along with the signing key derived from them. It is
+/// shared by all clones of the client (and the notification channels created from them), so
+/// requests can be signed through a shared reference to the client.
+#[derive(Debug, Clone)]
+pub(crate) struct SigningState {
+    credentials: SharedCredentials,
+    cache: Arc<RwLock<SigningCache>>,
+}
+
+impl SigningState {
+    pub(crate) fn new(device_credentials: Option<DeviceCredentials>) -> Self {
+        SigningState {
+            credentials: SharedCredentials::new(device_credentials.clone()),
+            cache: Arc::new(RwLock::new(SigningCache {
+                device_credentials,
+                ..SigningCache::default()
+            })),
+        }
+    }
+
+    pub(crate) fn credentials(&self) -> &SharedCredentials {
+        &self.credentials
+    }
+
+    /// Compute the CTN1-HMAC-SHA256 signature of an HTTP request, returning the values of the
+    /// `x-bcot-timestamp` and `authorization` HTTP headers that should be added to it.
+    pub(crate) fn sign(&self, method: &str, path_with_query: &str, host: &str, payload: &[u8], now: &OffsetDateTime) -> Result<(String, String)> {
+        if self.needs_refresh(now) {
+            self.credentials.refresh()?;
+        }
+
+        self.sign_with_current_credentials(method, path_with_query, host, payload, now)
+    }
+
+    /// Same as `sign()`, but retrieving the credentials anew from the provider, if required, in a
+    /// blocking task, so the asynchronous runtime is not blocked meanwhile.
+    #[cfg(feature = "async")]
+    pub(crate) async fn sign_async(&self, method: &str, path_with_query: &str, host: &str, payload: &[u8], now: &OffsetDateTime) -> Result<(String, String)> {
+        if self.needs_refresh(now) {
+            let credentials = self.credentials.clone();
+
+            tokio::task::spawn_blocking(move || credentials.refresh()).await
+                .map_err(|err| Error::new_client_error(Some("Failed to retrieve virtual device credentials"), Some(err)))??;
+        }
+
+        self.sign_with_current_credentials(method, path_with_query, host, payload, now)
+    }
+
+    /// Indicates whether the credentials should be retrieved anew from the provider, which is
+    /// the case whenever a new signing key is needed.
+    ///
+    /// > **Note**: the provider is called without holding any lock, so other requests can still be
+    /// > signed meanwhile.
+    fn needs_refresh(&self, now: &OffsetDateTime) -> bool {
+        self.credentials.has_provider()
+            && self.cache.read().unwrap_or_else(PoisonError::into_inner).needs_new_signing_key(now)
+    }
+
+    fn sign_with_current_credentials(&self, method: &str, path_with_query: &str, host: &str, payload: &[u8], now: &OffsetDateTime) -> Result<(String, String)> {
+        {
+            let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+
+            if cache.credentials_generation == self.credentials.generation() && !cache.needs_new_signing_key(now) {
+                // Current signing key is still valid
+                return cache.sign_request_parts_current_key(method, path_with_query, host, payload, now);
+            }
+        }
+
+        let mut cache = self.cache.write().unwrap_or_else(PoisonError::into_inner);
+
+        // Note: check again, since the credentials may have changed while no lock was held
+        if self.credentials.generation() != cache.credentials_generation {
+            // Credentials have changed. Make sure that a new signing key is generated
+            let (credentials, generation) = self.credentials.get();
+
+            cache.device_credentials = credentials;
+            cache.credentials_generation = generation;
+            cache.sign_date = None;
+            cache.signing_key = None;
+        }
+
+        cache.sign_request_parts(method, path_with_query, host, payload, now)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn signing_key(&self) -> Option<SigningKey> {
+        self.cache.read().unwrap_or_else(PoisonError::into_inner).signing_key.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn it_share_signing_key_among_clones() {
+        let now = time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc();
+        let state = SigningState::new(Some((
+            "drc3XdxNtzoucpw9xiRp",
+            "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+        ).into()));
+
+        let handles: Vec<_> = (0..4).map(|_| {
+            let state = state.clone();
+
+            thread::spawn(move || state.sign("POST", "/api/0.10/messages/log", "localhost:3000", br#"{"message":"Test message","options":{"encoding":"utf8"}}"#, &now).unwrap())
+        }).collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), (
+                String::from("20201201T060000Z"),
+                String::from("CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4"),
+            ));
+        }
+
+        assert!(state.signing_key().is_some(), "Signing key not shared among clones");
+    }
+
+    #[test]
+    fn it_refresh_credentials_without_locking_cache() {
+        let now = time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc();
+        let state = SigningState::new(None);
+        let cache = state.cache.clone();
+
+        state.credentials().set_provider(Arc::new(move || -> Result<DeviceCredentials> {
+            assert!(cache.try_write().is_ok(), "Signing cache locked while retrieving credentials");
+
+            Ok((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into())
+        })).unwrap();
+
+        assert_eq!(state.sign("POST", "/api/0.10/messages/log", "localhost:3000", br#"{"message":"Test message","options":{"encoding":"utf8"}}"#, &now).unwrap(), (
+            String::from("20201201T060000Z"),
+            String::from("CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4"),
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn it_sign_async_with_provider() {
+        let now = time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc();
+        let state = SigningState::new(None);
+
+        state.credentials().set_provider(Arc::new(|| -> Result<DeviceCredentials> {
+            Ok((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into())
+        })).unwrap();
+
+        assert_eq!(state.sign_async("POST", "/api/0.10/messages/log", "localhost:3000", br#"{"message":"Test message","options":{"encoding":"utf8"}}"#, &now).await.unwrap(), (
+            String::from("20201201T060000Z"),
+            String::from("CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4"),
+        ));
+    }
+
+    #[test]
+    fn it_fail_sign_missing_credentials() {
+        let now = time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc();
+
+        let result = SigningState::new(None).sign("GET", "/api/0.10/messages/oNXszDazhuq4utktSMMi", "localhost:3000", b"", &now);
+
+        assert!(result.is_err(), "Signed request without credentials");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Missing virtual device credentials");
+    }
+}
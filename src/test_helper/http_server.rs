@@ -10,12 +10,13 @@ use std::{
     error,
     str::FromStr,
     io::Read,
+    time::Duration,
 };
 use serde::{
     Deserialize, Serialize,
 };
 use tiny_http::{
-    Server, Request, Response, StatusCode, Header
+    Server, Request, Response, StatusCode, Header, SslConfig,
 };
 use flate2::{
     Compression,
@@ -26,6 +27,9 @@ use flate2::{
 
 static HTTP_SERVER_PORT: AtomicU16 = AtomicU16::new(4001);
 
+/// Certificate of the CA that issued the certificate used by the HTTP server when TLS is enabled.
+pub const TEST_CA_CERT: &[u8] = include_bytes!("certs/ca.pem");
+
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 pub struct HttpHeader {
     pub field: String,
@@ -195,6 +199,8 @@ pub enum HttpServerMode {
     Echo,
     Error(u16, Option<HttpBody>),
     Success(HttpBody),
+    Sequence(Vec<HttpServerMode>),
+    Delay(Duration, Box<HttpServerMode>),
 }
 
 #[derive(Debug, Clone)]
@@ -203,6 +209,8 @@ pub struct HttpServer {
     host: String,
     port: u16,
     expected_req: Option<PartialHttpRequest>,
+    res_headers: Vec<Header>,
+    use_tls: bool,
 }
 
 impl HttpServer {
@@ -223,6 +231,8 @@ impl HttpServer {
             host,
             port,
             expected_req: None,
+            res_headers: Vec::new(),
+            use_tls: false,
         }
     }
 
@@ -232,6 +242,18 @@ impl HttpServer {
         self
     }
 
+    pub fn with_response_header(mut self, field: &str, value: &str) -> Self {
+        self.res_headers.push(Header::from_str(format!("{}:{}", field, value).as_str()).unwrap());
+
+        self
+    }
+
+    pub fn with_tls(mut self) -> Self {
+        self.use_tls = true;
+
+        self
+    }
+
     pub fn get_port(&self) -> u16 {
         self.port
     }
@@ -239,161 +261,191 @@ impl HttpServer {
     pub fn start(&self) {
         let http_server = self.clone();
 
-        let server = Arc::new(Server::http(&self.host).unwrap());
+        let server = Arc::new(if self.use_tls {
+            Server::https(&self.host, SslConfig {
+                certificate: include_bytes!("certs/server.pem").to_vec(),
+                private_key: include_bytes!("certs/server-key.pem").to_vec(),
+            }).unwrap()
+        } else {
+            Server::http(&self.host).unwrap()
+        });
         let server2 = server.clone();
 
         thread::spawn(move || {
-            match &http_server.mode {
-                HttpServerMode::Echo => {
-                    // Wait for request
-                    let mut req = server2.recv().unwrap();
-
-                    let http_req = HttpRequest::from_request(&mut req);
-                    let json = serde_json::to_string(&http_req).unwrap();
+            http_server.serve(&server2, &http_server.mode);
+        });
+    }
 
-                    // Send response with serialized (JSON) received request
-                    req.respond(Response::from_string(json)).unwrap();
-                },
-                HttpServerMode::Error(status_code, err_body) => {
-                    if let Some(body) = err_body {
-                        // Wait for request
-                        let req = server2.recv().unwrap();
-
-                        // Prepare response to be send back
-
-                        // Encode body if required
-                        let mut content_encoding_header = None;
-                        let enc_body_content = match &body.content_encoding {
-                            Some(ContentEncoding::Deflate) => {
-                                // Compress body using deflate algorithm
-                                let mut enc = ZlibEncoder::new(body.data.as_slice(), Compression::default());
-                                let mut enc_body = Vec::new();
-                                enc.read_to_end(&mut enc_body).unwrap();
-
-                                // Prepare Content-Encoding header
-                                content_encoding_header = Some(Header::from_str("Content-Encoding:deflate").unwrap());
-
-                                Some(enc_body)
-                            },
-                            Some(ContentEncoding::Gzip) => {
-                                // Compress body using gzip algorithm
-                                let mut enc = GzEncoder::new(body.data.as_slice(), Compression::default());
-                                let mut enc_body = Vec::new();
-                                enc.read_to_end(&mut enc_body).unwrap();
-
-                                // Prepare Content-Encoding header
-                                content_encoding_header = Some(Header::from_str("Content-Encoding:gzip").unwrap());
-
-                                Some(enc_body)
-                            },
-                            _ => None,
-                        };
-
-                        let mut res = Response::from_data(
-                            if let Some(enc_body) = &enc_body_content {
-                                enc_body.as_slice()
-                            } else {
-                                body.data.as_slice()
-                            }
-                        );
-                        res = res.with_status_code(StatusCode(*status_code));
+    fn add_response_headers<R: Read>(&self, mut res: Response<R>) -> Response<R> {
+        for header in &self.res_headers {
+            res.add_header(header.clone());
+        }
 
-                        if let Some(content_type) = &body.content_type {
-                            res = res.with_header(Header::from_str(format!("Content-Type:{}", content_type).as_str()).unwrap());
-                        }
+        res
+    }
 
-                        if let Some(header) = content_encoding_header {
-                            res = res.with_header(header);
-                        }
+    fn serve(&self, server: &Server, mode: &HttpServerMode) {
+        match mode {
+            HttpServerMode::Echo => {
+                // Wait for request
+                let mut req = server.recv().unwrap();
 
-                        // Send error response
-                        req.respond(res).unwrap();
-                    } else {
-                        // Wait for request
-                        let req = server2.recv().unwrap();
+                let http_req = HttpRequest::from_request(&mut req);
+                let json = serde_json::to_string(&http_req).unwrap();
 
-                        // Send error response
-                        req.respond(Response::empty(StatusCode(*status_code))).unwrap();
-                    }
-                },
-                HttpServerMode::Success(body) => {
+                // Send response with serialized (JSON) received request
+                req.respond(self.add_response_headers(Response::from_string(json))).unwrap();
+            },
+            HttpServerMode::Error(status_code, err_body) => {
+                if let Some(body) = err_body {
                     // Wait for request
-                    let mut req = server2.recv().unwrap();
+                    let req = server.recv().unwrap();
 
                     // Prepare response to be send back
-                    let mut error_res = None;
-
-                    if let Some(expected_req) = &http_server.expected_req {
-                        // Validate request
-                        let http_req = HttpRequest::from_request(&mut req);
-
-                        if http_req != *expected_req {
-                            // Received request does not match expected request.
-                            //  Prepare error response
-                            error_res = Some(
-                                Response::from_data(format!(
-                                    "Invalid request.\n Expected: {}\n Received: {}",
-                                    serde_json::to_string(expected_req).unwrap(),
-                                    serde_json::to_string(&http_req).unwrap(),
-                                )).with_status_code(StatusCode::from(400))
-                            );
+
+                    // Encode body if required
+                    let mut content_encoding_header = None;
+                    let enc_body_content = match &body.content_encoding {
+                        Some(ContentEncoding::Deflate) => {
+                            // Compress body using deflate algorithm
+                            let mut enc = ZlibEncoder::new(body.data.as_slice(), Compression::default());
+                            let mut enc_body = Vec::new();
+                            enc.read_to_end(&mut enc_body).unwrap();
+
+                            // Prepare Content-Encoding header
+                            content_encoding_header = Some(Header::from_str("Content-Encoding:deflate").unwrap());
+
+                            Some(enc_body)
+                        },
+                        Some(ContentEncoding::Gzip) => {
+                            // Compress body using gzip algorithm
+                            let mut enc = GzEncoder::new(body.data.as_slice(), Compression::default());
+                            let mut enc_body = Vec::new();
+                            enc.read_to_end(&mut enc_body).unwrap();
+
+                            // Prepare Content-Encoding header
+                            content_encoding_header = Some(Header::from_str("Content-Encoding:gzip").unwrap());
+
+                            Some(enc_body)
+                        },
+                        _ => None,
+                    };
+
+                    let mut res = Response::from_data(
+                        if let Some(enc_body) = &enc_body_content {
+                            enc_body.as_slice()
+                        } else {
+                            body.data.as_slice()
                         }
+                    );
+                    res = res.with_status_code(StatusCode(*status_code));
+
+                    if let Some(content_type) = &body.content_type {
+                        res = res.with_header(Header::from_str(format!("Content-Type:{}", content_type).as_str()).unwrap());
                     }
 
-                    if let Some(res) = error_res {
-                        // Send error response
-                        req.respond(res).unwrap();
-                    } else {
-                        // Encode body if required
-                        let mut content_encoding_header = None;
-                        let enc_body_content = match &body.content_encoding {
-                            Some(ContentEncoding::Deflate) => {
-                                // Compress body using gzip algorithm
-                                let mut enc = ZlibEncoder::new(body.data.as_slice(), Compression::default());
-                                let mut enc_body = Vec::new();
-                                enc.read_to_end(&mut enc_body).unwrap();
-
-                                // Prepare Content-Encoding header
-                                content_encoding_header = Some(Header::from_str("Content-Encoding:deflate").unwrap());
-
-                                Some(enc_body)
-                            },
-                            Some(ContentEncoding::Gzip) => {
-                                // Compress body using deflate algorithm
-                                let mut enc = GzEncoder::new(body.data.as_slice(), Compression::default());
-                                let mut enc_body = Vec::new();
-                                enc.read_to_end(&mut enc_body).unwrap();
-
-                                // Prepare Content-Encoding header
-                                content_encoding_header = Some(Header::from_str("Content-Encoding:gzip").unwrap());
-
-                                Some(enc_body)
-                            },
-                            _ => None,
-                        };
-
-                        let mut res = Response::from_data(
-                            if let Some(enc_body) = &enc_body_content {
-                                enc_body.as_slice()
-                            } else {
-                                body.data.as_slice()
-                            }
+                    if let Some(header) = content_encoding_header {
+                        res = res.with_header(header);
+                    }
+
+                    // Send error response
+                    req.respond(self.add_response_headers(res)).unwrap();
+                } else {
+                    // Wait for request
+                    let req = server.recv().unwrap();
+
+                    // Send error response
+                    req.respond(self.add_response_headers(Response::empty(StatusCode(*status_code)))).unwrap();
+                }
+            },
+            HttpServerMode::Success(body) => {
+                // Wait for request
+                let mut req = server.recv().unwrap();
+
+                // Prepare response to be send back
+                let mut error_res = None;
+
+                if let Some(expected_req) = &self.expected_req {
+                    // Validate request
+                    let http_req = HttpRequest::from_request(&mut req);
+
+                    if http_req != *expected_req {
+                        // Received request does not match expected request.
+                        //  Prepare error response
+                        error_res = Some(
+                            Response::from_data(format!(
+                                "Invalid request.\n Expected: {}\n Received: {}",
+                                serde_json::to_string(expected_req).unwrap(),
+                                serde_json::to_string(&http_req).unwrap(),
+                            )).with_status_code(StatusCode::from(400))
                         );
+                    }
+                }
 
-                        if let Some(content_type) = &body.content_type {
-                            res = res.with_header(Header::from_str(format!("Content-Type:{}", content_type).as_str()).unwrap());
+                if let Some(res) = error_res {
+                    // Send error response
+                    req.respond(self.add_response_headers(res)).unwrap();
+                } else {
+                    // Encode body if required
+                    let mut content_encoding_header = None;
+                    let enc_body_content = match &body.content_encoding {
+                        Some(ContentEncoding::Deflate) => {
+                            // Compress body using gzip algorithm
+                            let mut enc = ZlibEncoder::new(body.data.as_slice(), Compression::default());
+                            let mut enc_body = Vec::new();
+                            enc.read_to_end(&mut enc_body).unwrap();
+
+                            // Prepare Content-Encoding header
+                            content_encoding_header = Some(Header::from_str("Content-Encoding:deflate").unwrap());
+
+                            Some(enc_body)
+                        },
+                        Some(ContentEncoding::Gzip) => {
+                            // Compress body using deflate algorithm
+                            let mut enc = GzEncoder::new(body.data.as_slice(), Compression::default());
+                            let mut enc_body = Vec::new();
+                            enc.read_to_end(&mut enc_body).unwrap();
+
+                            // Prepare Content-Encoding header
+                            content_encoding_header = Some(Header::from_str("Content-Encoding:gzip").unwrap());
+
+                            Some(enc_body)
+                        },
+                        _ => None,
+                    };
+
+                    let mut res = Response::from_data(
+                        if let Some(enc_body) = &enc_body_content {
+                            enc_body.as_slice()
+                        } else {
+                            body.data.as_slice()
                         }
+                    );
 
-                        if let Some(header) = content_encoding_header {
-                            res = res.with_header(header);
-                        }
+                    if let Some(content_type) = &body.content_type {
+                        res = res.with_header(Header::from_str(format!("Content-Type:{}", content_type).as_str()).unwrap());
+                    }
 
-                        // Send success response
-                        req.respond(res).unwrap();
+                    if let Some(header) = content_encoding_header {
+                        res = res.with_header(header);
                     }
-                },
-            };
-        });
+
+                    // Send success response
+                    req.respond(self.add_response_headers(res)).unwrap();
+                }
+            },
+            HttpServerMode::Sequence(modes) => {
+                // Serve one request for each mode in turn
+                for mode in modes {
+                    self.serve(server, mode);
+                }
+            },
+            HttpServerMode::Delay(delay, mode) => {
+                // Wait before serving the request
+                thread::sleep(*delay);
+                self.serve(server, mode);
+            },
+        };
     }
 }
 
@@ -1045,7 +1097,7 @@ mod tests {
                 },
                 HttpHeader {
                     field: String::from("accept-encoding"),
-                    value: String::from("gzip"),
+                    value: String::from("gzip, br, deflate"),
                 },
             ].into_iter()).collect(),
             body: String::from(""),
@@ -1092,7 +1144,7 @@ mod tests {
                 },
                 HttpHeader {
                     field: String::from("accept-encoding"),
-                    value: String::from("gzip"),
+                    value: String::from("gzip, br, deflate"),
                 },
                 HttpHeader {
                     field: String::from("content-length"),
@@ -1149,6 +1201,7 @@ mod tests {
         // Send (any) HTTP request and get the response
         let  http_client = reqwest::blocking::ClientBuilder::new()
             .gzip(false)
+            .deflate(false)
             .build()
             .unwrap();
 
@@ -1184,6 +1237,7 @@ mod tests {
         // Send (any) HTTP request and get the response
         let  http_client = reqwest::blocking::ClientBuilder::new()
             .gzip(false)
+            .deflate(false)
             .build()
             .unwrap();
 
@@ -1269,6 +1323,7 @@ mod tests {
         // Send (any) HTTP request and get the response
         let  http_client = reqwest::blocking::ClientBuilder::new()
             .gzip(false)
+            .deflate(false)
             .build()
             .unwrap();
 
@@ -1303,6 +1358,7 @@ mod tests {
         // Send (any) HTTP request and get the response
         let  http_client = reqwest::blocking::ClientBuilder::new()
             .gzip(false)
+            .deflate(false)
             .build()
             .unwrap();
 
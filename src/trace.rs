@@ -0,0 +1,324 @@
+use std::{
+    ops::Deref,
+    time::Instant,
+};
+#[cfg(feature = "tracing")]
+use std::fmt::{
+    self, Debug, Formatter,
+};
+#[cfg(feature = "tracing")]
+use reqwest::header::{
+    AUTHORIZATION, PROXY_AUTHORIZATION,
+    HeaderMap,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    interceptor::HttpRequest,
+    metrics::{
+        ApiCallMetrics, MetricsRecorder,
+    },
+};
+
+/// Tracks the HTTP request(s) sent for an API method call, recording its endpoint path, payload
+/// size, HTTP status, latency and retry count onto the API method's span, and reporting them to
+/// the client's metrics sink.
+///
+/// > **Note**: latency and retry count are recorded, and the metrics reported, when this object
+/// > is dropped, so it is done regardless of how the call ends.
+pub(crate) struct CallTrace {
+    api_method: &'static str,
+    metrics: MetricsRecorder,
+    started: Instant,
+    attempts: u32,
+    status: Option<StatusCode>,
+    success: bool,
+}
+
+impl CallTrace {
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn start<R: HttpRequest>(api_method: &'static str, req: &R, metrics: &MetricsRecorder) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("endpoint", req.url().path());
+            span.record("request_size", req.body_bytes().map_or(0, <[u8]>::len));
+        }
+
+        CallTrace {
+            api_method,
+            metrics: metrics.clone(),
+            started: Instant::now(),
+            attempts: 0,
+            status: None,
+            success: false,
+        }
+    }
+
+    /// Register a new attempt to send the request.
+    ///
+    /// > **Note**: the value of sensitive headers is redacted, and the request body is never
+    /// > logged.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn attempt<R: HttpRequest>(&mut self, req: &R, attempt: u32) {
+        self.attempts = attempt;
+
+        trace_event!(
+            debug,
+            method = %req.method(),
+            url = %req.url(),
+            attempt,
+            headers = ?RedactedHeaders(req.headers()),
+            "Sending Catenis API request"
+        );
+    }
+
+    /// Register the HTTP status of the response received for the current attempt.
+    pub(crate) fn response(&mut self, status: StatusCode) {
+        self.status = Some(status);
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", status.as_u16());
+    }
+
+    /// Indicate that the call has succeeded.
+    pub(crate) fn succeeded(&mut self) {
+        self.success = true;
+    }
+}
+
+impl Drop for CallTrace {
+    fn drop(&mut self) {
+        let latency = self.started.elapsed();
+        let retries = self.attempts.saturating_sub(1);
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("latency_ms", latency.as_millis() as u64);
+            span.record("retries", retries);
+        }
+
+        self.metrics.record_api_call(&ApiCallMetrics {
+            api_method: self.api_method,
+            status: self.status.map(|status| status.as_u16()),
+            success: self.success,
+            latency,
+            retries,
+        });
+    }
+}
+
+/// Response received for an API method call, along with the call's trace.
+///
+/// > **Note**: the call is only deemed successful once the response body has been read and
+/// > parsed, so the trace goes along with the response until then.
+pub(crate) struct TracedResponse<R> {
+    response: R,
+    call_trace: CallTrace,
+}
+
+impl<R> TracedResponse<R> {
+    pub(crate) fn new(response: R, call_trace: CallTrace) -> Self {
+        TracedResponse {
+            response,
+            call_trace,
+        }
+    }
+
+    /// Get the response and the trace of its call.
+    pub(crate) fn into_parts(self) -> (R, CallTrace) {
+        (self.response, self.call_trace)
+    }
+}
+
+impl<R> Deref for TracedResponse<R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
+    }
+}
+
+/// Record the size of the body of the response received for an API method call.
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn record_response_size(size: usize) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("response_size", size);
+}
+
+/// Debug representation of HTTP headers with the value of sensitive headers redacted.
+#[cfg(feature = "tracing")]
+struct RedactedHeaders<'a>(&'a HeaderMap);
+
+#[cfg(feature = "tracing")]
+impl<'a> Debug for RedactedHeaders<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(name, value)| {
+                if name == AUTHORIZATION || name == PROXY_AUTHORIZATION {
+                    (name.as_str(), "[redacted]")
+                } else {
+                    (name.as_str(), value.to_str().unwrap_or("[non-ascii]"))
+                }
+            }))
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{
+            Arc, Mutex,
+            atomic::{
+                AtomicU64, Ordering,
+            },
+        },
+    };
+    use tracing::{
+        field::{
+            Field, Visit,
+        },
+        span, Event, Metadata, Subscriber,
+    };
+    use tracing_core::span::Current;
+    use reqwest::header::HeaderValue;
+    use crate::{
+        CatenisClient, ClientOptions,
+        api::Message,
+        test_helper::http_server::{
+            HttpServer, HttpServerMode, HttpBody,
+        },
+    };
+    use super::*;
+
+    /// Subscriber that records, as text, all span fields and events.
+    #[derive(Default)]
+    struct RecordingSubscriber {
+        next_id: AtomicU64,
+        spans: Mutex<HashMap<u64, &'static Metadata<'static>>>,
+        entered: Mutex<Vec<u64>>,
+        records: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct RecordVisitor(String);
+
+    impl Visit for RecordVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0 += &format!(" {}={:?}", field.name(), value);
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+            let mut visitor = RecordVisitor(format!("new_span {}", attrs.metadata().name()));
+            attrs.record(&mut visitor);
+            self.records.lock().unwrap().push(visitor.0);
+
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+            self.spans.lock().unwrap().insert(id, attrs.metadata());
+
+            span::Id::from_u64(id)
+        }
+
+        fn record(&self, _span: &span::Id, values: &span::Record<'_>) {
+            let mut visitor = RecordVisitor(String::from("record"));
+            values.record(&mut visitor);
+            self.records.lock().unwrap().push(visitor.0);
+        }
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut visitor = RecordVisitor(String::from("event"));
+            event.record(&mut visitor);
+            self.records.lock().unwrap().push(visitor.0);
+        }
+
+        fn enter(&self, span: &span::Id) {
+            self.entered.lock().unwrap().push(span.into_u64());
+        }
+
+        fn exit(&self, _span: &span::Id) {
+            self.entered.lock().unwrap().pop();
+        }
+
+        fn current_span(&self) -> Current {
+            match self.entered.lock().unwrap().last() {
+                Some(id) => Current::new(span::Id::from_u64(*id), self.spans.lock().unwrap()[id]),
+                None => Current::none(),
+            }
+        }
+    }
+
+    #[test]
+    fn it_redact_sensitive_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201130/ctn1_request, Signature=abcdef"));
+        headers.insert("x-bcot-timestamp", HeaderValue::from_static("20201130T120000Z"));
+
+        assert_eq!(
+            format!("{:?}", RedactedHeaders(&headers)),
+            r#"{"authorization": "[redacted]", "x-bcot-timestamp": "20201130T120000Z"}"#
+        );
+    }
+
+    #[test]
+    fn it_trace_api_method_call() {
+        // Start HTTP server in success simulation mode
+        let http_server = HttpServer::new(
+            HttpServerMode::Success(
+                HttpBody::from_json(r#"{"status":"success","data":{"messageId":"mdx8vuCGWdb2TFeWFZd6"}}"#).unwrap(),
+            ),
+            "localhost"
+        );
+        http_server.start();
+
+        let server_port = http_server.get_port();
+
+        // Instantiate Catenis API client
+        let ctn_client = CatenisClient::new_with_options(
+            Some((
+                "drc3XdxNtzoucpw9xiRp",
+                "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3",
+            ).into()),
+            &[
+                ClientOptions::Host(&format!("localhost:{}", server_port)),
+                ClientOptions::Secure(false),
+                ClientOptions::UseCompression(false),
+            ],
+        ).unwrap();
+
+        let subscriber = RecordingSubscriber::default();
+        let records = subscriber.records.clone();
+
+        let result = tracing::subscriber::with_default(subscriber, || {
+            ctn_client.log_message(
+                Message::Whole(String::from("Top secret message")),
+                None,
+            )
+        });
+
+        assert!(result.is_ok(), "Returned error from calling API method");
+
+        let records = records.lock().unwrap();
+
+        assert!(records[0].starts_with("new_span log_message"), "Unexpected API method span: {}", records[0]);
+        assert!(records.contains(&String::from("record endpoint=\"/api/0.12/messages/log\"")), "Missing endpoint record: {:?}", records);
+        assert!(records.contains(&String::from("record request_size=32")), "Missing request size record: {:?}", records);
+        assert!(records.contains(&String::from("record status=200")), "Missing status record: {:?}", records);
+        assert!(records.contains(&String::from("record response_size=64")), "Missing response size record: {:?}", records);
+        assert!(records.contains(&String::from("record retries=0")), "Missing retries record: {:?}", records);
+        assert!(records.iter().any(|record| record.starts_with("record latency_ms=")), "Missing latency record: {:?}", records);
+        assert!(records.iter().any(|record| record.contains("\"authorization\": \"[redacted]\"")), "Missing request event: {:?}", records);
+
+        // Make sure that neither credentials nor the message contents have been leaked
+        assert!(!records.iter().any(|record| record.contains("Top secret") || record.contains("Signature=")), "Sensitive data leaked: {:?}", records);
+    }
+}
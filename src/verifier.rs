@@ -0,0 +1,322 @@
+use std::{
+    error, fmt,
+    result,
+};
+use reqwest::header::{
+    AUTHORIZATION, HOST,
+};
+use time::{
+    Date, Duration, OffsetDateTime, PrimitiveDateTime,
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    UtcDateTime, X_BCOT_TIMESTAMP,
+    base_client::{
+        self, SIGNATURE_VALIDITY_DAYS, TIME_VARIATION_SECS,
+    },
+};
+#[cfg(test)]
+use crate::test_helper;
+
+/// Result of verifying the signature of an HTTP request.
+pub type VerificationResult = result::Result<VerifiedRequest, VerificationError>;
+
+/// Verifier of HTTP requests signed using the `CTN1-HMAC-SHA256` scheme, like the ones sent by
+/// [`CatenisClient`](crate::CatenisClient) or signed with [`RequestSigner`](crate::RequestSigner).
+///
+/// It can be used to implement local stand-ins of the Catenis API server or gateways that need
+/// to authenticate requests from Catenis virtual devices. The API access secret of the device
+/// that signed a request is retrieved through the callback passed when instantiating the
+/// verifier.
+///
+/// A request is only accepted if its timestamp is within 5 seconds of the current time, and the
+/// date of its signature's scope is no more than 7 days before its timestamp.
+///
+/// # Example
+///
+/// ```no_run
+/// use catenis_api_client::{
+///     SignatureVerifier, VerificationError,
+/// };
+///
+/// let verifier = SignatureVerifier::new(|device_id: &str| {
+///     if device_id == "drc3XdxNtzoucpw9xiRp" {
+///         Some(String::from(concat!(
+///             "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0",
+///             "d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3"
+///         )))
+///     } else {
+///         None
+///     }
+/// });
+///
+/// # let req = http::Request::get("http://localhost:3000/api/0.12/messages/oNXszDazhuq4utktSMMi").body(Vec::<u8>::new()).unwrap();
+/// match verifier.verify_http_request(&req) {
+///     Ok(verified) => println!("Request signed by device {}", verified.device_id),
+///     Err(VerificationError::UnknownDevice(device_id)) => println!("Unknown device: {}", device_id),
+///     Err(err) => println!("Invalid request signature: {}", err),
+/// }
+/// ```
+pub struct SignatureVerifier<F> {
+    secret_lookup: F,
+}
+
+/// Information about an HTTP request whose signature has been successfully verified.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VerifiedRequest {
+    /// ID of the virtual device that signed the request.
+    pub device_id: String,
+    /// The request's timestamp.
+    pub timestamp: UtcDateTime,
+}
+
+/// Reason for an HTTP request's signature not being verified.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum VerificationError {
+    /// A required HTTP header is missing, or its value is not valid text.
+    MissingHeader(&'static str),
+    /// The `authorization` HTTP header is not in the expected format.
+    MalformedAuthorization,
+    /// The `x-bcot-timestamp` HTTP header is not in the expected format.
+    MalformedTimestamp,
+    /// The secret lookup callback returned no secret for the device with the given ID.
+    UnknownDevice(String),
+    /// The request's timestamp is too far from the current time.
+    TimestampOutOfRange,
+    /// The date of the signature's scope is after the request's timestamp, or too far before it.
+    ScopeOutOfRange,
+    /// The signature does not match the request.
+    SignatureMismatch,
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationError::MissingHeader(name) => write!(f, "Missing or invalid '{}' HTTP header", name),
+            VerificationError::MalformedAuthorization => write!(f, "Malformed authorization header"),
+            VerificationError::MalformedTimestamp => write!(f, "Malformed timestamp header"),
+            VerificationError::UnknownDevice(device_id) => write!(f, "Unknown device: {}", device_id),
+            VerificationError::TimestampOutOfRange => write!(f, "Request timestamp out of range"),
+            VerificationError::ScopeOutOfRange => write!(f, "Signature scope out of range"),
+            VerificationError::SignatureMismatch => write!(f, "Signature does not match"),
+        }
+    }
+}
+
+impl error::Error for VerificationError {}
+
+/// Parts of a parsed `authorization` HTTP header.
+struct Authorization<'a> {
+    device_id: &'a str,
+    sign_date: Date,
+    scope: &'a str,
+    signature: &'a str,
+}
+
+impl<'a> Authorization<'a> {
+    // Expected format: 'CTN1-HMAC-SHA256 Credential=<device ID>/<sign date>/ctn1_request,Signature=<signature>'
+    fn parse(value: &'a str) -> Option<Self> {
+        let (credential, signature) = value.strip_prefix("CTN1-HMAC-SHA256 Credential=")?.split_once(',')?;
+        let signature = signature.trim_start().strip_prefix("Signature=")?;
+        let (device_id, scope) = credential.split_once('/')?;
+        let (date, service) = scope.split_once('/')?;
+
+        if device_id.is_empty() || service != "ctn1_request" || signature.len() != 64
+                || !signature.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        Some(Authorization {
+            device_id,
+            sign_date: Date::parse(date, "%Y%m%d").ok()?,
+            scope,
+            signature,
+        })
+    }
+}
+
+impl<F> SignatureVerifier<F>
+    where
+        F: Fn(&str) -> Option<String>
+{
+    /// Instantiate a new signature verifier.
+    ///
+    /// The `secret_lookup` callback receives a device ID, and should return that device's API
+    /// access secret, or `None` if the device is not known.
+    pub fn new(secret_lookup: F) -> Self {
+        SignatureVerifier {
+            secret_lookup,
+        }
+    }
+
+    /// Verify the signature of an HTTP request given its constituent parts and the values of its
+    /// `x-bcot-timestamp` and `authorization` HTTP headers.
+    pub fn verify(&self, method: &str, path_with_query: &str, host: &str, timestamp: &str, authorization: &str, body: &[u8]) -> VerificationResult {
+        self.verify_at(method, path_with_query, host, timestamp, authorization, body, now!())
+    }
+
+    /// Verify the signature of an [`http::Request`].
+    pub fn verify_http_request<B: AsRef<[u8]>>(&self, req: &http::Request<B>) -> VerificationResult {
+        let header = |name: &'static str| req.headers().get(name)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(VerificationError::MissingHeader(name));
+
+        let path_with_query = req.uri().path_and_query().map_or("/", |path| path.as_str());
+
+        self.verify(
+            req.method().as_str(),
+            path_with_query,
+            header(HOST.as_str())?,
+            header(X_BCOT_TIMESTAMP)?,
+            header(AUTHORIZATION.as_str())?,
+            req.body().as_ref(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_at(&self, method: &str, path_with_query: &str, host: &str, timestamp: &str, authorization: &str, body: &[u8], now: OffsetDateTime) -> VerificationResult {
+        let auth = Authorization::parse(authorization).ok_or(VerificationError::MalformedAuthorization)?;
+        let req_time = PrimitiveDateTime::parse(timestamp, "%Y%m%dT%H%M%SZ")
+            .map_err(|_| VerificationError::MalformedTimestamp)?
+            .assume_utc();
+
+        if (now - req_time).abs() > Duration::seconds(TIME_VARIATION_SECS as i64) {
+            return Err(VerificationError::TimestampOutOfRange);
+        }
+
+        if auth.sign_date > req_time.date() || auth.sign_date < req_time.date() - Duration::days(SIGNATURE_VALIDITY_DAYS as i64) {
+            return Err(VerificationError::ScopeOutOfRange);
+        }
+
+        let secret = Zeroizing::new((self.secret_lookup)(auth.device_id)
+            .ok_or_else(|| VerificationError::UnknownDevice(String::from(auth.device_id)))?);
+        let signing_key = base_client::derive_signing_key(&secret, auth.sign_date);
+        let signature = base_client::compute_signature(signing_key.as_bytes(), auth.scope, timestamp, method, path_with_query, host, body);
+
+        if !constant_time_eq(signature.as_bytes(), auth.signature.to_ascii_lowercase().as_bytes()) {
+            return Err(VerificationError::SignatureMismatch);
+        }
+
+        Ok(VerifiedRequest {
+            device_id: String::from(auth.device_id),
+            timestamp: req_time.into(),
+        })
+    }
+}
+
+/// Compare two byte strings in a time that does not depend on their contents.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RequestSigner;
+    use super::*;
+
+    const DEVICE_ID: &str = "drc3XdxNtzoucpw9xiRp";
+    const API_ACCESS_SECRET: &str = "4c1749c8e86f65e0a73e5fb19f2aa9e74a716bc22d7956bf3072b4bc3fbfe2a0d138ad0d4bcfee251e4e5f54d6e92b8fd4eb36958a7aeaeeb51e8d2fcc4552c3";
+    const AUTHORIZATION_VALUE: &str = "CTN1-HMAC-SHA256 Credential=drc3XdxNtzoucpw9xiRp/20201201/ctn1_request,Signature=af2b41b1786b812809cc01291fd324880f48017b96332192566006d2fd7eefb4";
+    const BODY: &[u8] = br#"{"message":"Test message","options":{"encoding":"utf8"}}"#;
+
+    fn verifier() -> SignatureVerifier<impl Fn(&str) -> Option<String>> {
+        SignatureVerifier::new(|device_id: &str| {
+            if device_id == DEVICE_ID { Some(String::from(API_ACCESS_SECRET)) } else { None }
+        })
+    }
+
+    fn verify_at(authorization: &str, body: &[u8], now: OffsetDateTime) -> VerificationResult {
+        verifier().verify_at("POST", "/api/0.10/messages/log", "localhost:3000", "20201201T060000Z", authorization, body, now)
+    }
+
+    #[test]
+    fn it_verify_signature() {
+        let result = verify_at(AUTHORIZATION_VALUE, BODY, time::date!(2020-12-01).with_time(time::time!(06:00:04)).assume_utc());
+
+        assert_eq!(result, Ok(VerifiedRequest {
+            device_id: String::from(DEVICE_ID),
+            timestamp: time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc().into(),
+        }));
+    }
+
+    #[test]
+    fn it_verify_signed_http_request() {
+        // Set custom "system" time
+        let _custom_time = test_helper::time::CustomTime::set(&time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        let mut req = http::Request::get("http://localhost:3000/api/0.10/messages?action=send&limit=10")
+            .body(Vec::new())
+            .unwrap();
+
+        RequestSigner::new((DEVICE_ID, API_ACCESS_SECRET).into()).sign_http_request(&mut req).unwrap();
+
+        assert!(verifier().verify_http_request(&req).is_ok(), "Failed to verify signed HTTP request");
+
+        // Tamper with request
+        *req.uri_mut() = "http://localhost:3000/api/0.10/messages?action=send&limit=20".parse().unwrap();
+
+        assert_eq!(verifier().verify_http_request(&req), Err(VerificationError::SignatureMismatch));
+    }
+
+    #[test]
+    fn it_reject_mismatched_signature() {
+        let result = verify_at(AUTHORIZATION_VALUE, b"{}", time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        assert_eq!(result, Err(VerificationError::SignatureMismatch));
+    }
+
+    #[test]
+    fn it_reject_timestamp_out_of_range() {
+        let result = verify_at(AUTHORIZATION_VALUE, BODY, time::date!(2020-12-01).with_time(time::time!(06:00:06)).assume_utc());
+
+        assert_eq!(result, Err(VerificationError::TimestampOutOfRange));
+
+        let result = verify_at(AUTHORIZATION_VALUE, BODY, time::date!(2020-12-01).with_time(time::time!(05:59:54)).assume_utc());
+
+        assert_eq!(result, Err(VerificationError::TimestampOutOfRange));
+    }
+
+    #[test]
+    fn it_reject_scope_out_of_range() {
+        let now = time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc();
+
+        let result = verify_at(&AUTHORIZATION_VALUE.replace("20201201", "20201123"), BODY, now);
+
+        assert_eq!(result, Err(VerificationError::ScopeOutOfRange));
+
+        let result = verify_at(&AUTHORIZATION_VALUE.replace("20201201", "20201202"), BODY, now);
+
+        assert_eq!(result, Err(VerificationError::ScopeOutOfRange));
+
+        // Scope within validity period, but signature computed for a different scope
+        let result = verify_at(&AUTHORIZATION_VALUE.replace("20201201", "20201124"), BODY, now);
+
+        assert_eq!(result, Err(VerificationError::SignatureMismatch));
+    }
+
+    #[test]
+    fn it_reject_unknown_device() {
+        let result = verify_at(&AUTHORIZATION_VALUE.replace(DEVICE_ID, "d8YpQ7jgPBJEkBrnvp58"), BODY, time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc());
+
+        assert_eq!(result, Err(VerificationError::UnknownDevice(String::from("d8YpQ7jgPBJEkBrnvp58"))));
+    }
+
+    #[test]
+    fn it_reject_malformed_headers() {
+        let now = time::date!(2020-12-01).with_time(time::time!(06:00:00)).assume_utc();
+
+        assert_eq!(verify_at("Basic ZHJjM1hkeE50em91Y3B3OXhpUnA6c2VjcmV0", BODY, now), Err(VerificationError::MalformedAuthorization));
+        assert_eq!(verify_at(&AUTHORIZATION_VALUE.replace("/ctn1_request", "/other"), BODY, now), Err(VerificationError::MalformedAuthorization));
+        assert_eq!(verify_at(&AUTHORIZATION_VALUE[..AUTHORIZATION_VALUE.len() - 1], BODY, now), Err(VerificationError::MalformedAuthorization));
+
+        let result = verifier().verify_at("POST", "/api/0.10/messages/log", "localhost:3000", "2020-12-01T06:00:00Z", AUTHORIZATION_VALUE, BODY, now);
+
+        assert_eq!(result, Err(VerificationError::MalformedTimestamp));
+
+        let req = http::Request::get("http://localhost:3000/api/0.10/messages").body(Vec::new()).unwrap();
+
+        assert_eq!(verifier().verify_http_request(&req), Err(VerificationError::MissingHeader("host")));
+        assert_eq!(VerificationError::MissingHeader("host").to_string(), "Missing or invalid 'host' HTTP header");
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    ApiVersion, Error, Result,
+};
+
+/// Version of the Catenis API that introduced non-fungible assets.
+const NON_FUNGIBLE_ASSETS_API_VERSION: ApiVersion = ApiVersion(0, 12);
+
+/// Define the [`ApiMethod`] enumeration along with the client method name of each variant.
+macro_rules! api_methods {
+    ($($variant:ident => $name:literal,)*) => {
+        /// Catenis API methods called by the client, as identified by the client method that calls
+        /// them.
+        ///
+        /// Used to check whether a given method is available in a version of the Catenis API (see
+        /// [`ApiVersion::supports_method`]).
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        pub enum ApiMethod {
+            $(
+                #[doc = concat!("Called by the [`", $name, "()`](crate::CatenisClient::", $name, ") client method.")]
+                $variant,
+            )*
+        }
+
+        impl ApiMethod {
+            /// All the API methods.
+            pub const ALL: &'static [ApiMethod] = &[
+                $(ApiMethod::$variant,)*
+            ];
+
+            /// Name of the client method that calls this API method (e.g. `"export_asset"`).
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(ApiMethod::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+api_methods! {
+    LogMessage => "log_message",
+    SendMessage => "send_message",
+    ReadMessage => "read_message",
+    RetrieveMessageContainer => "retrieve_message_container",
+    RetrieveMessageOrigin => "retrieve_message_origin",
+    RetrieveMessageProgress => "retrieve_message_progress",
+    ListMessages => "list_messages",
+    IssueAsset => "issue_asset",
+    ReissueAsset => "reissue_asset",
+    TransferAsset => "transfer_asset",
+    RetrieveAssetInfo => "retrieve_asset_info",
+    GetAssetBalance => "get_asset_balance",
+    ListOwnedAssets => "list_owned_assets",
+    ListIssuedAssets => "list_issued_assets",
+    RetrieveAssetIssuanceHistory => "retrieve_asset_issuance_history",
+    ListAssetHolders => "list_asset_holders",
+    ExportAsset => "export_asset",
+    MigrateAsset => "migrate_asset",
+    AssetExportOutcome => "asset_export_outcome",
+    AssetMigrationOutcome => "asset_migration_outcome",
+    ListExportedAssets => "list_exported_assets",
+    ListAssetMigrations => "list_asset_migrations",
+    IssueNonFungibleAsset => "issue_non_fungible_asset",
+    ReissueNonFungibleAsset => "reissue_non_fungible_asset",
+    RetrieveNonFungibleAssetIssuanceProgress => "retrieve_non_fungible_asset_issuance_progress",
+    RetrieveNonFungibleToken => "retrieve_non_fungible_token",
+    RetrieveNonFungibleTokenRetrievalProgress => "retrieve_non_fungible_token_retrieval_progress",
+    TransferNonFungibleToken => "transfer_non_fungible_token",
+    RetrieveNonFungibleTokenTransferProgress => "retrieve_non_fungible_token_transfer_progress",
+    SetPermissionRights => "set_permission_rights",
+    RetrievePermissionRights => "retrieve_permission_rights",
+    CheckEffectivePermissionRight => "check_effective_permission_right",
+    ListPermissionEvents => "list_permission_events",
+    RetrieveDeviceIdentificationInfo => "retrieve_device_identification_info",
+    ListNotificationEvents => "list_notification_events",
+    CallApi => "call_api",
+}
+
+impl ApiMethod {
+    /// Version of the Catenis API that introduced this method.
+    pub(crate) fn introduced_in(self) -> ApiVersion {
+        match self {
+            // Asset export (to foreign blockchains)
+            ApiMethod::ExportAsset
+                | ApiMethod::MigrateAsset
+                | ApiMethod::AssetExportOutcome
+                | ApiMethod::AssetMigrationOutcome
+                | ApiMethod::ListExportedAssets
+                | ApiMethod::ListAssetMigrations => ApiVersion(0, 11),
+            // Non-fungible assets
+            ApiMethod::IssueNonFungibleAsset
+                | ApiMethod::ReissueNonFungibleAsset
+                | ApiMethod::RetrieveNonFungibleAssetIssuanceProgress
+                | ApiMethod::RetrieveNonFungibleToken
+                | ApiMethod::RetrieveNonFungibleTokenRetrievalProgress
+                | ApiMethod::TransferNonFungibleToken
+                | ApiMethod::RetrieveNonFungibleTokenTransferProgress => NON_FUNGIBLE_ASSETS_API_VERSION,
+            // Available in all supported versions of the Catenis API (0.10 and later)
+            ApiMethod::LogMessage
+                | ApiMethod::SendMessage
+                | ApiMethod::ReadMessage
+                | ApiMethod::RetrieveMessageContainer
+                | ApiMethod::RetrieveMessageOrigin
+                | ApiMethod::RetrieveMessageProgress
+                | ApiMethod::ListMessages
+                | ApiMethod::IssueAsset
+                | ApiMethod::ReissueAsset
+                | ApiMethod::TransferAsset
+                | ApiMethod::RetrieveAssetInfo
+                | ApiMethod::GetAssetBalance
+                | ApiMethod::ListOwnedAssets
+                | ApiMethod::ListIssuedAssets
+                | ApiMethod::RetrieveAssetIssuanceHistory
+                | ApiMethod::ListAssetHolders
+                | ApiMethod::SetPermissionRights
+                | ApiMethod::RetrievePermissionRights
+                | ApiMethod::CheckEffectivePermissionRight
+                | ApiMethod::ListPermissionEvents
+                | ApiMethod::RetrieveDeviceIdentificationInfo
+                | ApiMethod::ListNotificationEvents
+                | ApiMethod::CallApi => ApiVersion(0, 10),
+        }
+    }
+}
+
+/// Check whether an API method is available in a given version of the Catenis API, failing with
+/// an unsupported API version error if not.
+pub(crate) fn check_method_support(api_method: ApiMethod, api_version: ApiVersion) -> Result<()> {
+    let introduced_in = api_method.introduced_in();
+
+    if api_version < introduced_in {
+        Err(Error::new_unsupported_api_version_error(api_method.name(), api_version, introduced_in))
+    } else {
+        Ok(())
+    }
+}
+
+/// Indicates whether a given version of the Catenis API has non-fungible assets, whose
+/// introduction (in version 0.12) changed the shape of the responses of some asset methods.
+pub(crate) fn has_non_fungible_assets(api_version: ApiVersion) -> bool {
+    api_version >= NON_FUNGIBLE_ASSETS_API_VERSION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_check_method_support() {
+        assert!(check_method_support(ApiMethod::LogMessage, ApiVersion(0, 10)).is_ok());
+        assert!(check_method_support(ApiMethod::ExportAsset, ApiVersion(0, 11)).is_ok());
+        assert!(check_method_support(ApiMethod::TransferNonFungibleToken, ApiVersion(0, 12)).is_ok());
+        assert!(check_method_support(ApiMethod::TransferNonFungibleToken, ApiVersion(1, 0)).is_ok());
+
+        let result = check_method_support(ApiMethod::TransferNonFungibleToken, ApiVersion(0, 11));
+
+        assert!(result.is_err(), "Accepted method unsupported in API version");
+        assert!(result.err().unwrap().is_unsupported_api_version());
+
+        let result = check_method_support(ApiMethod::ExportAsset, ApiVersion(0, 10));
+
+        assert!(result.is_err(), "Accepted method unsupported in API version");
+        assert_eq!(result.err().unwrap().to_string(), "Catenis client error: Method export_asset is unsupported in API version 0.10 (requires API version 0.11 or later); request not sent");
+    }
+
+    #[test]
+    fn it_check_support_of_every_method() {
+        let methods = [
+            (ApiMethod::LogMessage, "log_message", ApiVersion(0, 10)),
+            (ApiMethod::SendMessage, "send_message", ApiVersion(0, 10)),
+            (ApiMethod::ReadMessage, "read_message", ApiVersion(0, 10)),
+            (ApiMethod::RetrieveMessageContainer, "retrieve_message_container", ApiVersion(0, 10)),
+            (ApiMethod::RetrieveMessageOrigin, "retrieve_message_origin", ApiVersion(0, 10)),
+            (ApiMethod::RetrieveMessageProgress, "retrieve_message_progress", ApiVersion(0, 10)),
+            (ApiMethod::ListMessages, "list_messages", ApiVersion(0, 10)),
+            (ApiMethod::IssueAsset, "issue_asset", ApiVersion(0, 10)),
+            (ApiMethod::ReissueAsset, "reissue_asset", ApiVersion(0, 10)),
+            (ApiMethod::TransferAsset, "transfer_asset", ApiVersion(0, 10)),
+            (ApiMethod::RetrieveAssetInfo, "retrieve_asset_info", ApiVersion(0, 10)),
+            (ApiMethod::GetAssetBalance, "get_asset_balance", ApiVersion(0, 10)),
+            (ApiMethod::ListOwnedAssets, "list_owned_assets", ApiVersion(0, 10)),
+            (ApiMethod::ListIssuedAssets, "list_issued_assets", ApiVersion(0, 10)),
+            (ApiMethod::RetrieveAssetIssuanceHistory, "retrieve_asset_issuance_history", ApiVersion(0, 10)),
+            (ApiMethod::ListAssetHolders, "list_asset_holders", ApiVersion(0, 10)),
+            (ApiMethod::ExportAsset, "export_asset", ApiVersion(0, 11)),
+            (ApiMethod::MigrateAsset, "migrate_asset", ApiVersion(0, 11)),
+            (ApiMethod::AssetExportOutcome, "asset_export_outcome", ApiVersion(0, 11)),
+            (ApiMethod::AssetMigrationOutcome, "asset_migration_outcome", ApiVersion(0, 11)),
+            (ApiMethod::ListExportedAssets, "list_exported_assets", ApiVersion(0, 11)),
+            (ApiMethod::ListAssetMigrations, "list_asset_migrations", ApiVersion(0, 11)),
+            (ApiMethod::IssueNonFungibleAsset, "issue_non_fungible_asset", ApiVersion(0, 12)),
+            (ApiMethod::ReissueNonFungibleAsset, "reissue_non_fungible_asset", ApiVersion(0, 12)),
+            (ApiMethod::RetrieveNonFungibleAssetIssuanceProgress, "retrieve_non_fungible_asset_issuance_progress", ApiVersion(0, 12)),
+            (ApiMethod::RetrieveNonFungibleToken, "retrieve_non_fungible_token", ApiVersion(0, 12)),
+            (ApiMethod::RetrieveNonFungibleTokenRetrievalProgress, "retrieve_non_fungible_token_retrieval_progress", ApiVersion(0, 12)),
+            (ApiMethod::TransferNonFungibleToken, "transfer_non_fungible_token", ApiVersion(0, 12)),
+            (ApiMethod::RetrieveNonFungibleTokenTransferProgress, "retrieve_non_fungible_token_transfer_progress", ApiVersion(0, 12)),
+            (ApiMethod::SetPermissionRights, "set_permission_rights", ApiVersion(0, 10)),
+            (ApiMethod::RetrievePermissionRights, "retrieve_permission_rights", ApiVersion(0, 10)),
+            (ApiMethod::CheckEffectivePermissionRight, "check_effective_permission_right", ApiVersion(0, 10)),
+            (ApiMethod::ListPermissionEvents, "list_permission_events", ApiVersion(0, 10)),
+            (ApiMethod::RetrieveDeviceIdentificationInfo, "retrieve_device_identification_info", ApiVersion(0, 10)),
+            (ApiMethod::ListNotificationEvents, "list_notification_events", ApiVersion(0, 10)),
+            (ApiMethod::CallApi, "call_api", ApiVersion(0, 10)),
+        ];
+
+        // Make sure that the table covers every API method
+        assert_eq!(
+            methods.iter().map(|(method, _, _)| *method).collect::<Vec<_>>(),
+            ApiMethod::ALL.to_vec()
+        );
+
+        for (method, name, introduced_in) in methods.iter().copied() {
+            assert_eq!(method.name(), name);
+            assert_eq!(method.introduced_in(), introduced_in, "Unexpected API version for method {}", name);
+            assert!(check_method_support(method, introduced_in).is_ok(), "Method {} unsupported in API version {}", name, introduced_in);
+            assert!(check_method_support(method, ApiVersion(1, 0)).is_ok(), "Method {} unsupported in API version 1.0", name);
+
+            if introduced_in > ApiVersion(0, 10) {
+                let prior_version = ApiVersion(introduced_in.0, introduced_in.1 - 1);
+                let result = check_method_support(method, prior_version);
+
+                assert!(result.is_err(), "Method {} supported in API version {}", name, prior_version);
+                assert!(result.err().unwrap().is_unsupported_api_version());
+            }
+        }
+    }
+
+    #[test]
+    fn it_check_non_fungible_assets_support() {
+        assert!(!has_non_fungible_assets(ApiVersion(0, 10)));
+        assert!(!has_non_fungible_assets(ApiVersion(0, 11)));
+        assert!(has_non_fungible_assets(ApiVersion(0, 12)));
+        assert!(has_non_fungible_assets(ApiVersion(1, 0)));
+    }
+}